@@ -0,0 +1,92 @@
+use bevy::color::palettes;
+use bevy::prelude::*;
+
+use bevy_ui_mod_alerts::{
+    Alert, AlertAction, AlertActionEvent, AlertActions, AlertMarker, AlertsPlugin,
+};
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins);
+    app.add_plugins(AlertsPlugin::new());
+    app.init_resource::<SyncAttempts>();
+    app.add_systems(Startup, init);
+    app.add_systems(Update, (start_sync, retry_sync));
+
+    app.run();
+}
+
+/// Every other sync fails, so that there is something to retry.
+#[derive(Default, Resource)]
+struct SyncAttempts(u32);
+
+impl SyncAttempts {
+    fn sync(&mut self, commands: &mut Commands) {
+        self.0 += 1;
+        if self.0 % 2 == 1 {
+            commands.spawn((
+                Alert::bundle(format!("Sync #{} failed: connection reset", self.0)),
+                AlertActions(vec![
+                    AlertAction::new("retry", "Retry"),
+                    AlertAction::new("details", "Open log").keep_open(),
+                ]),
+                AlertMarker,
+            ));
+        } else {
+            commands.spawn((Alert::bundle(format!("Sync #{} done", self.0)), AlertMarker));
+        }
+    }
+}
+
+fn init(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), IsDefaultUiCamera));
+    commands
+        .spawn((
+            Name::new("Banner"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                background_color: Color::Srgba(palettes::css::ANTIQUE_WHITE).into(),
+                ..Default::default()
+            },
+        ))
+        .with_children(|builder| {
+            builder.spawn(TextBundle::from_section(
+                "Press Space to sync",
+                TextStyle {
+                    font_size: 48.,
+                    color: Color::BLACK,
+                    ..Default::default()
+                },
+            ));
+        });
+}
+
+fn start_sync(
+    mut commands: Commands,
+    inputs: Res<ButtonInput<KeyCode>>,
+    mut attempts: ResMut<SyncAttempts>,
+) {
+    if inputs.just_pressed(KeyCode::Space) {
+        attempts.sync(&mut commands);
+    }
+}
+
+fn retry_sync(
+    mut commands: Commands,
+    mut actions: EventReader<AlertActionEvent>,
+    mut attempts: ResMut<SyncAttempts>,
+) {
+    for action in actions.read() {
+        match action.action_id.as_str() {
+            "retry" => attempts.sync(&mut commands),
+            "details" => info!("The sync log would open here"),
+            _ => {}
+        }
+    }
+}
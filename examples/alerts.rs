@@ -41,7 +41,7 @@ fn init(mut commands: Commands) {
         ))
         .with_children(|builder| {
             builder.spawn(TextBundle::from_section(
-                "Press Space to fire an alert (or try F)",
+                "Press Space to fire an alert (or try F or L)",
                 TextStyle {
                     font_size: 48.,
                     color: Color::BLACK,
@@ -59,6 +59,8 @@ fn make_messages(inputs: Res<ButtonInput<KeyCode>>) -> Vec<String> {
             "F! F! F! F! F! Very very long message! Very very long! So long! Super long message!"
                 .to_string(),
         ]
+    } else if inputs.just_pressed(KeyCode::KeyL) {
+        vec!["Sync failed:\n- 2 files conflicted\n- 1 file was too large".to_string()]
     } else {
         vec![]
     }
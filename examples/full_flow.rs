@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use bevy::color::palettes;
+use bevy::prelude::*;
+use bevy_mod_try_system::TrySystemExt;
+
+use bevy_ui_mod_alerts::{
+    AlertDismissReason, AlertElements, AlertLifetime, AlertsPlugin, MaxAlerts,
+};
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins);
+    app.add_plugins(AlertsPlugin::<NetworkAlert>::default());
+
+    let mut elements = AlertElements::<NetworkAlert>::corner_popup(60.);
+    elements.header.background_color = Color::Srgba(palettes::css::SEA_GREEN).into();
+    app.insert_resource(elements);
+
+    app.add_systems(Startup, init);
+    app.add_systems(
+        Update,
+        (
+            connect.pipe_err(AlertsPlugin::<NetworkAlert>::custom_alert),
+            tweak_alerts,
+        ),
+    );
+    app.observe(log_dismissals);
+
+    app.run();
+}
+
+#[derive(Component, Default, Reflect)]
+struct NetworkAlert;
+
+fn init(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), IsDefaultUiCamera));
+    commands
+        .spawn((
+            Name::new("Banner"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                background_color: Color::Srgba(palettes::css::ANTIQUE_WHITE).into(),
+                ..Default::default()
+            },
+        ))
+        .with_children(|builder| {
+            builder.spawn(TextBundle::from_section(
+                "Press Space to fail a connection attempt\n\
+                 Press 1 or 2 to change how many alerts fit, L to toggle long lifetimes",
+                TextStyle {
+                    font_size: 32.,
+                    color: Color::BLACK,
+                    ..Default::default()
+                },
+            ));
+        });
+}
+
+fn connect(
+    inputs: Res<ButtonInput<KeyCode>>,
+    mut attempt: Local<usize>,
+) -> Result<(), Vec<String>> {
+    if !inputs.just_pressed(KeyCode::Space) {
+        return Ok(());
+    }
+    *attempt += 1;
+    Err(vec![format!("Connection attempt {} timed out", *attempt)])
+}
+
+fn tweak_alerts(
+    inputs: Res<ButtonInput<KeyCode>>,
+    mut max_alerts: ResMut<MaxAlerts<NetworkAlert>>,
+    mut lifetime: ResMut<AlertLifetime<NetworkAlert>>,
+) {
+    if inputs.just_pressed(KeyCode::Digit1) {
+        *max_alerts = MaxAlerts::new(1);
+    } else if inputs.just_pressed(KeyCode::Digit2) {
+        *max_alerts = MaxAlerts::new(2);
+    }
+    if inputs.just_pressed(KeyCode::KeyL) {
+        let long = lifetime.get() < Duration::from_secs(30);
+        lifetime.set(Duration::from_secs(if long { 30 } else { 5 }));
+    }
+}
+
+fn log_dismissals(
+    trigger: Trigger<OnAdd, AlertDismissReason>,
+    reasons: Query<&AlertDismissReason>,
+) {
+    if let Ok(reason) = reasons.get(trigger.entity()) {
+        info!("Alert {:?} is leaving: {reason:?}", trigger.entity());
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::color::palettes;
+use bevy::prelude::*;
+
+use bevy_ui_mod_alerts::{Alert, AlertIcon, AlertMarker, AlertsPlugin};
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins);
+    app.add_plugins(AlertsPlugin::new());
+    app.add_systems(Startup, init);
+    app.add_systems(Update, fire_alerts);
+
+    app.run();
+}
+
+#[derive(Resource)]
+struct WarningIcon(Handle<Image>);
+
+fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((Camera2dBundle::default(), IsDefaultUiCamera));
+    commands.insert_resource(WarningIcon(asset_server.load("icons/warning.png")));
+    commands
+        .spawn((
+            Name::new("Banner"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                background_color: Color::Srgba(palettes::css::ANTIQUE_WHITE).into(),
+                ..Default::default()
+            },
+        ))
+        .with_children(|builder| {
+            builder.spawn(TextBundle::from_section(
+                "Press Space for an alert with an icon (or N for one without)",
+                TextStyle {
+                    font_size: 36.,
+                    color: Color::BLACK,
+                    ..Default::default()
+                },
+            ));
+        });
+}
+
+fn fire_alerts(mut commands: Commands, inputs: Res<ButtonInput<KeyCode>>, icon: Res<WarningIcon>) {
+    if inputs.just_pressed(KeyCode::Space) {
+        commands.spawn((
+            Alert::bundle("Low disk space"),
+            AlertIcon(icon.0.clone()),
+            AlertMarker,
+        ));
+    } else if inputs.just_pressed(KeyCode::KeyN) {
+        commands.spawn((Alert::bundle("Saved"), AlertMarker));
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+/// A run condition that is true while any alert of this kind is in the UI, including alerts that are
+/// fading out.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{any_alerts_visible, AlertMarker, AlertsPlugin};
+///
+/// fn show_tutorial_popup() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new());
+/// app.add_systems(
+///     Update,
+///     show_tutorial_popup.run_if(not(any_alerts_visible::<AlertMarker>)),
+/// );
+/// ```
+pub fn any_alerts_visible<M: Component + TypePath>(
+    alerts: Query<(), (With<AlertManaged<M>>, With<AlertUi>)>,
+) -> bool {
+    !alerts.is_empty()
+}
+
+/// A run condition that is true while no alert of this kind is in the UI.
+pub fn no_alerts_visible<M: Component + TypePath>(
+    alerts: Query<(), (With<AlertManaged<M>>, With<AlertUi>)>,
+) -> bool {
+    alerts.is_empty()
+}
+
+/// A run condition that is true while any alert of this kind is waiting for a slot.
+pub fn alerts_pending<M: Component + TypePath>(
+    alerts: Query<(), (With<AlertManaged<M>>, With<AlertPending>)>,
+) -> bool {
+    !alerts.is_empty()
+}
+
+/// A run condition that is true while any alert of this kind at `level` or above is in the UI.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{any_alert_of_severity, AlertLevel, AlertMarker, AlertsPlugin};
+///
+/// fn prompt_auto_save() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new());
+/// app.add_systems(
+///     Update,
+///     prompt_auto_save.run_if(not(any_alert_of_severity::<AlertMarker>(AlertLevel::Error))),
+/// );
+/// ```
+pub fn any_alert_of_severity<M: Component + TypePath>(
+    level: AlertLevel,
+) -> impl FnMut(Query<Option<&AlertLevel>, (With<AlertManaged<M>>, With<AlertUi>)>) -> bool + Clone
+{
+    move |alerts: Query<Option<&AlertLevel>, (With<AlertManaged<M>>, With<AlertUi>)>| {
+        alerts
+            .iter()
+            .any(|alert_level| alert_level.copied().unwrap_or_default() >= level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_run_conditions() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(1));
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            250,
+        )));
+        let conditions = |app: &mut App| {
+            let world = app.world_mut();
+            (
+                world.run_system_once(any_alerts_visible::<AlertMarker>),
+                world.run_system_once(no_alerts_visible::<AlertMarker>),
+                world.run_system_once(alerts_pending::<AlertMarker>),
+                world.run_system_once(any_alert_of_severity::<AlertMarker>(AlertLevel::Warning)),
+            )
+        };
+        assert_eq!(conditions(&mut app), (false, true, false, false));
+
+        app.world_mut().spawn((Alert::bundle("first"), AlertMarker));
+        app.world_mut()
+            .spawn((Alert::with_level(AlertLevel::Error, "second"), AlertMarker));
+        app.update();
+        assert_eq!(conditions(&mut app), (true, false, true, false));
+
+        // the first alert expires and is despawned, then the second takes its place
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(conditions(&mut app), (true, false, false, true));
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(conditions(&mut app), (false, true, false, false));
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn diagnose_config(
+        visible: Query<(), (With<AlertManaged<M>>, With<AlertUi>)>,
+        windows: Query<&Window, With<PrimaryWindow>>,
+        alert_nodes: Res<AlertElements<M>>,
+        auto_restyle: Res<AutoRestyle<M>>,
+        max_alerts: Res<MaxAlerts<M>>,
+        mut diagnostics: ResMut<AlertDiagnostics>,
+    ) {
+        let marker = M::short_type_path();
+        if alert_nodes.is_changed()
+            && !alert_nodes.is_added()
+            && !auto_restyle.enabled
+            && !visible.is_empty()
+        {
+            diagnostics.warn_once(format!(
+                "AlertElements<{marker}> changed while alerts were visible, but \
+                 AutoRestyle<{marker}> is disabled, so only alerts spawned from now on use the \
+                 new elements."
+            ));
+        }
+
+        let Ok(window) = windows.get_single() else {
+            return;
+        };
+        let capacity = AlertDiagnostics::root_capacity(&alert_nodes, window.height());
+        if let Some(capacity) = capacity.filter(|capacity| **max_alerts > *capacity) {
+            diagnostics.warn_once(format!(
+                "MaxAlerts<{marker}> is {} but the alerts root only fits about {capacity} cards \
+                 in this window. Lower MaxAlerts or make the cards smaller.",
+                **max_alerts
+            ));
+        }
+    }
+}
+
+/// Detects common misconfigurations and logs a warning the first time each one is seen.
+///
+/// This checks for alerts that no `AlertsPlugin` handles, `AlertElements` changes that will not
+/// apply to visible alerts, and a `MaxAlerts` that the root cannot fit. It is enabled in debug
+/// builds and with the `diagnostics` feature.
+#[derive(Debug, Default, Resource)]
+pub struct AlertDiagnostics {
+    pub(crate) managed: Vec<bevy::ecs::component::ComponentId>,
+    warnings: Vec<String>,
+}
+
+impl AlertDiagnostics {
+    /// Every warning that has been logged so far.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn warn_once(&mut self, warning: String) {
+        if self.warnings.contains(&warning) {
+            return;
+        }
+        warn!("{warning}");
+        self.warnings.push(warning);
+    }
+
+    /// Roughly how many cards of minimum height fit in the root when the window is `height` tall.
+    fn root_capacity<M>(alert_nodes: &AlertElements<M>, height: f32) -> Option<usize> {
+        let resolve = |val: Val| match val {
+            Val::Px(px) => Some(px),
+            Val::Percent(percent) => Some(percent / 100. * height),
+            _ => None,
+        };
+        let root = &alert_nodes.root().style;
+        let card = &alert_nodes.alert().style;
+        let root_height = resolve(root.max_height).unwrap_or(height);
+        let gap = resolve(root.row_gap).unwrap_or(0.);
+        let card_height = resolve(card.min_height).filter(|card_height| *card_height > 0.)?;
+        Some(((root_height + gap) / (card_height + gap)).floor() as usize)
+    }
+
+    pub(crate) fn diagnose_unmanaged_alerts(
+        alerts: Query<EntityRef, With<Alert>>,
+        mut diagnostics: ResMut<Self>,
+    ) {
+        // every plugin adopts its alerts in `PostUpdate`, so any left over by now are unhandled
+        let unmanaged = alerts.iter().any(|alert| {
+            !diagnostics
+                .managed
+                .iter()
+                .any(|managed| alert.contains_id(*managed))
+        });
+        if unmanaged {
+            diagnostics.warn_once(
+                "An Alert is not handled by any AlertsPlugin. Add an AlertsPlugin for its marker \
+                 component, or spawn it with a marker that has one."
+                    .to_string(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_config_diagnostics() {
+        use bevy::window::WindowResolution;
+
+        let warnings = |app: &App| app.world().resource::<AlertDiagnostics>().warnings().len();
+        let mut app = manual_app();
+        app.insert_resource(AutoRestyle::<AlertMarker>::new(false));
+        app.world_mut()
+            .spawn((Alert::bundle("visible"), AlertMarker));
+        app.update();
+        assert_eq!(warnings(&app), 0);
+
+        // an alert whose marker has no plugin
+        app.world_mut().spawn((Alert::bundle("stray"), MyAlert));
+        app.update();
+        app.update();
+        assert_eq!(warnings(&app), 1);
+
+        // restyling without AutoRestyle, twice
+        app.insert_resource(AlertElements::<AlertMarker>::new());
+        app.update();
+        app.insert_resource(AlertElements::<AlertMarker>::new());
+        app.update();
+        assert_eq!(warnings(&app), 2);
+
+        // a window too short for three cards
+        app.world_mut().spawn((
+            Window {
+                resolution: WindowResolution::new(1000., 400.),
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(warnings(&app), 3);
+    }
+}
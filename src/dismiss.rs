@@ -0,0 +1,571 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    pub(crate) fn dismiss_orphaned_alerts(
+        mut commands: Commands,
+        alerts: Query<(Entity, &AlertSource, Has<AlertUi>), (With<AlertManaged<M>>, With<Alert>)>,
+        entities: &Entities,
+    ) {
+        for (entity, source, has_ui) in &alerts {
+            if !source.dismiss_with_source || entities.contains(source.entity) {
+                continue;
+            }
+            if !has_ui {
+                // the alert was never shown, so there is nothing to transition out
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+            commands.add(DismissAlert::<M>::new(
+                entity,
+                AlertDismissReason::SourceGone,
+            ));
+        }
+    }
+
+    pub(crate) fn clear_alerts(mut commands: Commands, mut clears: EventReader<ClearAlerts<M>>) {
+        for clear in clears.read() {
+            let dismiss = DismissAlerts::<M>::new().cancel_pending();
+            commands.add(if clear.instant {
+                dismiss.instant()
+            } else {
+                dismiss
+            });
+        }
+    }
+}
+
+/// A command asking one displayed alert of this kind to leave, subject to
+/// `AlertDismissReason::arbitrate`.
+///
+/// While the stack is frozen, a dismissal by the user removes the alert immediately, since its
+/// fade-out could not play until the stack thaws.
+pub struct DismissAlert<M> {
+    entity: Entity,
+    reason: AlertDismissReason,
+    toward: Option<Vec2>,
+    marker: PhantomData<M>,
+}
+
+impl<M> DismissAlert<M> {
+    pub fn new(entity: Entity, reason: AlertDismissReason) -> Self {
+        Self {
+            entity,
+            reason,
+            toward: None,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Closes the card toward this point, relative to its center, with
+    /// `AlertTransition::FadeOutToward` instead of sliding it out.
+    pub fn toward(mut self, anchor: Vec2) -> Self {
+        self.toward = Some(anchor);
+        self
+    }
+}
+
+impl<M> Command for DismissAlert<M>
+where
+    M: Component + TypePath,
+{
+    fn apply(self, world: &mut World) {
+        let frozen = world.contains_resource::<AlertsFrozen<M>>();
+        let Some(mut alert) = world.get_entity_mut(self.entity) else {
+            return;
+        };
+        if !alert.contains::<AlertManaged<M>>() || !alert.contains::<AlertUi>() {
+            return;
+        }
+        let sticky = alert
+            .get::<CriticalAlert>()
+            .is_some_and(|critical| critical.sticky)
+            || alert.contains::<PersistentAlert>();
+        let current = alert.get::<AlertDismissReason>().copied();
+        match self.reason.arbitrate(current, sticky, frozen) {
+            DismissalDecision::Ignore => {}
+            _ if frozen => {
+                alert.insert(self.reason);
+                alert.despawn_recursive();
+            }
+            DismissalDecision::Start => {
+                let transition = self
+                    .toward
+                    .map_or(AlertTransition::FadeOut, AlertTransition::FadeOutToward);
+                alert
+                    .remove::<TransitionTimer>()
+                    .insert((transition, self.reason));
+            }
+            DismissalDecision::Retag => {
+                alert.insert(self.reason);
+            }
+        }
+    }
+}
+
+/// A command that clears every displayed alert of this kind with `AlertDismissReason::Cleared`,
+/// sticky ones included. Pending alerts stay queued unless `cancel_pending` is set. A frozen stack
+/// ignores clears.
+pub struct DismissAlerts<M> {
+    instant: bool,
+    cancel_pending: bool,
+    marker: PhantomData<M>,
+}
+
+impl<M> DismissAlerts<M> {
+    pub fn new() -> Self {
+        Self {
+            instant: false,
+            cancel_pending: false,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Skips the fade-out and despawns the displayed alerts along with their UI.
+    pub fn instant(mut self) -> Self {
+        self.instant = true;
+        self
+    }
+
+    /// Also cancels the alerts still waiting for a slot, with `AlertDismissReason::Cancelled`.
+    pub fn cancel_pending(mut self) -> Self {
+        self.cancel_pending = true;
+        self
+    }
+}
+
+impl<M> Default for DismissAlerts<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Command for DismissAlerts<M>
+where
+    M: Component + TypePath,
+{
+    fn apply(self, world: &mut World) {
+        if world.contains_resource::<AlertsFrozen<M>>() {
+            return;
+        }
+        if self.cancel_pending {
+            let mut pending =
+                world.query_filtered::<Entity, (With<AlertManaged<M>>, With<AlertPending>)>();
+            let pending = pending.iter(world).collect::<Vec<_>>();
+            for entity in pending {
+                let mut alert = world.entity_mut(entity);
+                alert.insert(AlertDismissReason::Cancelled);
+                alert.despawn_recursive();
+            }
+        }
+        let mut alerts = world.query_filtered::<Entity, (With<AlertManaged<M>>, With<AlertUi>)>();
+        let alerts = alerts.iter(world).collect::<Vec<_>>();
+        for entity in alerts {
+            if self.instant {
+                let mut alert = world.entity_mut(entity);
+                alert.insert(AlertDismissReason::Cleared);
+                alert.despawn_recursive();
+            } else {
+                DismissAlert::<M>::new(entity, AlertDismissReason::Cleared).apply(world);
+            }
+        }
+    }
+}
+
+/// An event that queues `DismissAlerts` with `cancel_pending`, and `instant` when set, such as
+/// on a scene change. Clears sent while the stack is frozen are dropped.
+#[derive(Event)]
+pub struct ClearAlerts<M: Component = AlertMarker> {
+    pub instant: bool,
+    marker: PhantomData<M>,
+}
+
+impl<M> ClearAlerts<M>
+where
+    M: Component,
+{
+    pub fn new() -> Self {
+        Self {
+            instant: false,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Skips the fade-out and despawns the displayed alerts along with their UI.
+    pub fn instant() -> Self {
+        Self {
+            instant: true,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M> Default for ClearAlerts<M>
+where
+    M: Component,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Why an alert started transitioning out of the UI.
+///
+/// This is inserted alongside `AlertTransition::FadeOut`, or just before a pending alert is
+/// cancelled, so an `OnRemove` observer can read it as the alert is despawned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub enum AlertDismissReason {
+    /// The alert outlived its `AlertLifetime`.
+    Expired,
+    /// The user pressed the dismiss button.
+    Dismissed,
+    /// The entity referenced by the alert's `AlertSource` was despawned.
+    SourceGone,
+    /// Every item of the alert's `AlertGroup` was resolved.
+    Resolved,
+    /// The alert was cancelled with `PendingAlerts` before it was ever displayed.
+    Cancelled,
+    /// The alert was folded into the combined card shown while over an `AlertEntityBudget`.
+    Compacted,
+    /// Every displayed alert of its kind was cleared with `DismissAlerts`.
+    Cleared,
+    /// The alert's level fell below the `AlertVisibilityFilter` of its kind.
+    Filtered,
+}
+
+/// The outcome of asking an alert to leave, as decided by `AlertDismissReason::arbitrate`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DismissalDecision {
+    /// The alert starts fading out.
+    Start,
+    /// The alert is already leaving and keeps its progress, but now for the new reason.
+    Retag,
+    /// The request is dropped.
+    Ignore,
+}
+
+impl AlertDismissReason {
+    /// How strongly a reason claims an alert: the user's own dismissal wins over a programmatic
+    /// clear, which wins over eviction, which wins over expiry and the other automatic reasons.
+    pub fn precedence(self) -> u8 {
+        match self {
+            Self::Dismissed => 3,
+            Self::Cleared | Self::Cancelled | Self::Filtered => 2,
+            Self::Compacted => 1,
+            Self::Expired | Self::SourceGone | Self::Resolved => 0,
+        }
+    }
+
+    /// Decides what asking an alert to leave for this reason does. Every way an alert can leave
+    /// goes through here.
+    ///
+    /// `current` is the reason the alert is already leaving for, if any. Sticky alerts only
+    /// leave when cleared or dismissed, and a frozen stack only lets the user dismiss alerts.
+    pub fn arbitrate(self, current: Option<Self>, sticky: bool, frozen: bool) -> DismissalDecision {
+        if frozen && self != Self::Dismissed {
+            return DismissalDecision::Ignore;
+        }
+        if sticky && self.precedence() < Self::Cleared.precedence() {
+            return DismissalDecision::Ignore;
+        }
+        match current {
+            None => DismissalDecision::Start,
+            Some(current) if self.precedence() > current.precedence() => DismissalDecision::Retag,
+            Some(_) => DismissalDecision::Ignore,
+        }
+    }
+}
+
+/// Ties an alert to the entity that caused it, e.g. "Turret #7 jammed".
+///
+/// If `dismiss_with_source` is set, the alert fades out as soon as the source entity no longer
+/// exists, and queued alerts for that source are discarded before they are ever shown.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct AlertSource {
+    pub entity: Entity,
+    pub dismiss_with_source: bool,
+}
+
+impl AlertSource {
+    /// Ties an alert to `entity` without affecting its lifetime.
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            dismiss_with_source: false,
+        }
+    }
+
+    /// Ties an alert to `entity` and dismisses it when `entity` is despawned.
+    pub fn dismiss_with(entity: Entity) -> Self {
+        Self {
+            entity,
+            dismiss_with_source: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_dismiss_with_source() {
+        let mut app = manual_app();
+        let turret = app.world_mut().spawn_empty().id();
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Turret #7 jammed"),
+                AlertMarker,
+                AlertSource::dismiss_with(turret),
+            ))
+            .id();
+        app.update();
+        app.update();
+        assert!(app.world().get::<AlertUi>(alert).is_some());
+        assert!(app.world().get::<AlertDismissReason>(alert).is_none());
+
+        app.world_mut().despawn(turret);
+        app.update();
+        assert!(matches!(
+            app.world().get::<AlertTransition>(alert),
+            Some(AlertTransition::FadeOut)
+        ));
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(alert),
+            Some(&AlertDismissReason::SourceGone)
+        );
+    }
+
+    #[test]
+    fn test_dismissal_precedence() {
+        use AlertDismissReason::*;
+        use DismissalDecision::*;
+
+        // (reason, already leaving for, sticky, frozen) => decision
+        let matrix = [
+            ((Expired, None, false, false), Start),
+            ((Expired, None, true, false), Ignore),
+            ((Expired, None, false, true), Ignore),
+            ((Expired, Some(Dismissed), false, false), Ignore),
+            ((SourceGone, Some(Expired), false, false), Ignore),
+            ((Compacted, None, true, false), Ignore),
+            ((Compacted, Some(Expired), false, false), Retag),
+            ((Compacted, Some(Cleared), false, false), Ignore),
+            ((Cleared, None, true, false), Start),
+            ((Cleared, None, false, true), Ignore),
+            ((Cleared, Some(Expired), false, false), Retag),
+            ((Cleared, Some(Dismissed), false, false), Ignore),
+            ((Dismissed, None, true, false), Start),
+            ((Dismissed, None, true, true), Start),
+            ((Dismissed, Some(Cleared), false, false), Retag),
+            ((Dismissed, Some(Dismissed), false, false), Ignore),
+        ];
+        for ((reason, current, sticky, frozen), decision) in matrix {
+            assert_eq!(
+                reason.arbitrate(current, sticky, frozen),
+                decision,
+                "{reason:?} over {current:?}, sticky: {sticky}, frozen: {frozen}"
+            );
+        }
+
+        #[derive(Default, Resource)]
+        struct Departures(Vec<(Entity, AlertDismissReason)>);
+
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        app.init_resource::<Departures>();
+        app.observe(
+            |trigger: Trigger<OnRemove, AlertDismissReason>,
+             reasons: Query<&AlertDismissReason>,
+             mut departures: ResMut<Departures>| {
+                let reason = *reasons.get(trigger.entity()).unwrap();
+                departures.0.push((trigger.entity(), reason));
+            },
+        );
+        let sticky = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Sticky"),
+                AlertMarker,
+                CriticalAlert::default(),
+            ))
+            .id();
+        let normal = app
+            .world_mut()
+            .spawn((Alert::bundle("Normal"), AlertMarker))
+            .id();
+        app.update();
+
+        // a frozen stack ignores clears, but the user can still dismiss alerts right away
+        let snapshot = AlertStackSnapshot::<AlertMarker>::freeze(app.world_mut());
+        DismissAlerts::<AlertMarker>::new().apply(app.world_mut());
+        assert!(app.world().get::<AlertDismissReason>(sticky).is_none());
+        assert!(app.world().get::<AlertDismissReason>(normal).is_none());
+        let button = app
+            .world()
+            .get::<AlertParts>(normal)
+            .unwrap()
+            .dismiss_button();
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert!(app.world().get_entity(normal).is_none());
+        assert_eq!(
+            app.world().resource::<Departures>().0,
+            [(normal, Dismissed)]
+        );
+        snapshot.thaw(app.world_mut());
+
+        // sticky alerts outlive their lifetime, but not a clear
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(app.world().get::<AlertDismissReason>(sticky).is_none());
+        DismissAlerts::<AlertMarker>::new().apply(app.world_mut());
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(sticky),
+            Some(&Cleared)
+        );
+
+        // dismissing mid-fade takes over the reason without restarting the fade
+        let button = app
+            .world()
+            .get::<AlertParts>(sticky)
+            .unwrap()
+            .dismiss_button();
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(sticky),
+            Some(&Dismissed)
+        );
+        app.update();
+        assert!(app.world().get_entity(sticky).is_none());
+        assert_eq!(
+            app.world().resource::<Departures>().0,
+            [(normal, Dismissed), (sticky, Dismissed)]
+        );
+    }
+
+    #[test]
+    fn test_dismiss_alert_command() {
+        let mut app = manual_app();
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("Objective failed"), AlertMarker))
+            .id();
+        let other = app
+            .world_mut()
+            .spawn((Alert::bundle("Objective added"), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+        // still sliding in
+        assert!(app.world().get::<TransitionTimer>(alert).is_some());
+
+        let gone = app.world_mut().spawn_empty().id();
+        app.world_mut().despawn(gone);
+        let not_an_alert = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                commands.dismiss_alert(alert);
+                commands.dismiss_alert(gone);
+                commands.dismiss_alert(not_an_alert);
+            });
+        assert!(matches!(
+            app.world().get::<AlertTransition>(alert),
+            Some(AlertTransition::FadeOut)
+        ));
+        assert!(app.world().get::<TransitionTimer>(alert).is_none());
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(alert),
+            Some(&AlertDismissReason::Dismissed)
+        );
+        assert!(app
+            .world()
+            .get::<AlertDismissReason>(not_an_alert)
+            .is_none());
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!(app.world().get_entity(alert).is_none());
+        assert!(app.world().get::<AlertUi>(other).is_some());
+    }
+
+    #[test]
+    fn test_clear_alerts() {
+        let mut app = manual_app();
+        **app.world_mut().resource_mut::<MaxAlerts<AlertMarker>>() = 3;
+        for _ in 0..10 {
+            app.world_mut()
+                .spawn((Alert::bundle("Picked up"), AlertMarker));
+        }
+        app.update();
+        let root = app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .unwrap();
+
+        app.world_mut()
+            .send_event(ClearAlerts::<AlertMarker>::instant());
+        app.update();
+        let mut alerts = app.world_mut().query_filtered::<(), With<Alert>>();
+        assert_eq!(alerts.iter(app.world()).count(), 0);
+        assert!(app.world().get_entity(root).is_none());
+        assert_eq!(
+            app.world()
+                .resource::<CurrentAlertRoot<AlertMarker>>()
+                .get(),
+            None
+        );
+
+        // without `instant`, displayed alerts fade out first
+        for _ in 0..10 {
+            app.world_mut()
+                .spawn((Alert::bundle("Picked up"), AlertMarker));
+        }
+        app.update();
+        app.world_mut()
+            .send_event(ClearAlerts::<AlertMarker>::new());
+        app.update();
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<&AlertDismissReason, With<Alert>>();
+        let reasons = alerts.iter(app.world()).copied().collect::<Vec<_>>();
+        assert_eq!(reasons, [AlertDismissReason::Cleared; 3]);
+        for _ in 0..3 {
+            app.update();
+        }
+        let mut alerts = app.world_mut().query_filtered::<(), With<Alert>>();
+        assert_eq!(alerts.iter(app.world()).count(), 0);
+        assert_eq!(
+            app.world()
+                .resource::<CurrentAlertRoot<AlertMarker>>()
+                .get(),
+            None
+        );
+
+        // the command clears displayed alerts right away, and leaves the queue alone
+        for _ in 0..5 {
+            app.world_mut()
+                .spawn((Alert::bundle("Picked up"), AlertMarker));
+        }
+        app.update();
+        DismissAlerts::<AlertMarker>::new()
+            .instant()
+            .apply(app.world_mut());
+        let mut displayed = app.world_mut().query_filtered::<(), With<AlertUi>>();
+        assert_eq!(displayed.iter(app.world()).count(), 0);
+        let mut pending = app.world_mut().query_filtered::<(), With<AlertPending>>();
+        assert_eq!(pending.iter(app.world()).count(), 2);
+    }
+}
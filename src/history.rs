@@ -0,0 +1,638 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn record_alert_history(
+        mut commands: Commands,
+        shown: Query<
+            (
+                Entity,
+                &Alert,
+                Option<&AlertKey>,
+                Option<&AlertOccurrences>,
+                Has<AlertRecorded>,
+            ),
+            (With<AlertManaged<M>>, Added<AlertUi>),
+        >,
+        mut history: ResMut<AlertHistory<M>>,
+        time: Res<Time>,
+    ) {
+        let now = time.elapsed();
+        history.now = now;
+        for (entity, alert, key, occurrences, recorded) in &shown {
+            let occurrences = match (recorded, occurrences) {
+                (true, _) => 0,
+                (false, occurrences) => occurrences.map_or(1, |occurrences| occurrences.0),
+            };
+            history.record(key.map_or(alert.message(), |key| &key.0), now, occurrences);
+            commands.entity(entity).insert(AlertRecorded);
+        }
+    }
+
+    pub(crate) fn mirror_alerts_to_log(
+        alerts: Query<(&Alert, Option<&AlertLevel>), Added<AlertManaged<M>>>,
+        mirror: Res<AlertLogMirror<M>>,
+    ) {
+        for (alert, level) in &alerts {
+            let level = level.copied().unwrap_or_default();
+            if mirror.mirrors(level) {
+                log_alert(
+                    level,
+                    format_args!("[{}] {}", M::short_type_path(), alert.message()),
+                );
+            }
+        }
+    }
+
+    /// Logs the teardown of mirrored alerts that were shown or given a reason to leave, which
+    /// leaves out the duplicates merged into a live alert.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn mirror_removed_alerts_to_log(
+        trigger: Trigger<OnRemove, Alert>,
+        alerts: Query<
+            (
+                &Alert,
+                Option<&AlertLevel>,
+                Option<&AlertDismissReason>,
+                Has<AlertUi>,
+            ),
+            With<AlertManaged<M>>,
+        >,
+        mirror: Res<AlertLogMirror<M>>,
+    ) {
+        let Ok((alert, level, reason, shown)) = alerts.get(trigger.entity()) else {
+            return;
+        };
+        let level = level.copied().unwrap_or_default();
+        if !mirror.mirrors(level) || !(shown || reason.is_some()) {
+            return;
+        }
+        match reason {
+            Some(reason) => log_alert(
+                level,
+                format_args!(
+                    "[{}] {} (removed: {reason:?})",
+                    M::short_type_path(),
+                    alert.message()
+                ),
+            ),
+            None => log_alert(
+                level,
+                format_args!("[{}] {} (removed)", M::short_type_path(), alert.message()),
+            ),
+        }
+    }
+
+    /// Adds the `AlertInteractions` of alerts to the `AlertHistory` as they are despawned.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn record_alert_interactions(
+        trigger: Trigger<OnRemove, Alert>,
+        alerts: Query<
+            (
+                &Alert,
+                Option<&AlertKey>,
+                Option<&AlertLevel>,
+                Option<&AlertDismissReason>,
+                &AlertInteractions,
+            ),
+            With<AlertManaged<M>>,
+        >,
+        mut history: ResMut<AlertHistory<M>>,
+        time: Res<Time>,
+    ) {
+        let Ok((alert, key, level, reason, interactions)) = alerts.get(trigger.entity()) else {
+            return;
+        };
+        let left_at = interactions.left_at.unwrap_or(time.elapsed());
+        history.record_interactions(AlertInteractionRecord {
+            message_or_key: key.map_or(alert.message(), |key| &key.0).to_string(),
+            level: level.copied().unwrap_or_default(),
+            first_hover: interactions.first_hover,
+            shown_for: left_at.saturating_sub(interactions.shown_at),
+            reason: reason.copied(),
+        });
+    }
+}
+
+/// How many times an alert surfaced: once as it was raised, and once more for every alert merged
+/// into it by `AlertKey` or `DedupAlerts`.
+///
+/// Unlike `AlertRepeatCount`, this is counted whether or not `DedupAlerts` is enabled.
+/// `AlertHistory::occurrences` adds it up across every time the alert was shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub struct AlertOccurrences(pub u32);
+
+/// When each alert of this kind was last shown, whether or not it was dismissed since.
+///
+/// Alerts are looked up by their `AlertKey`, or by their message when they have none. Showing an
+/// alert and refreshing a shown keyed alert both count as seeing it again. Past its capacity, the
+/// history forgets the alert that was seen the longest ago.
+#[derive(Debug, Resource)]
+pub struct AlertHistory<M> {
+    seen: HashMap<String, (Duration, u32)>,
+    interactions: VecDeque<AlertInteractionRecord>,
+    now: Duration,
+    capacity: usize,
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertHistory<M> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes how many alerts are remembered, forgetting the oldest ones past it.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    /// What the player did with the alerts that left the UI, oldest first, up to the capacity.
+    pub fn interactions(&self) -> impl Iterator<Item = &AlertInteractionRecord> {
+        self.interactions.iter()
+    }
+
+    /// How long, on average, shown alerts of this level stayed before the player dismissed them.
+    pub fn mean_time_to_dismiss(&self, level: AlertLevel) -> Option<Duration> {
+        let (total, count) = self
+            .interactions
+            .iter()
+            .filter(|record| {
+                record.level == level && record.reason == Some(AlertDismissReason::Dismissed)
+            })
+            .fold((Duration::ZERO, 0), |(total, count), record| {
+                (total + record.shown_for, count + 1)
+            });
+        (count > 0).then(|| total / count)
+    }
+
+    /// How long ago the alert with this key or message was last shown.
+    pub fn last_seen(&self, message_or_key: &str) -> Option<Duration> {
+        self.seen
+            .get(message_or_key)
+            .map(|(seen, _)| self.now.saturating_sub(*seen))
+    }
+
+    /// How many times the alert with this key or message surfaced while shown, counting its
+    /// `AlertOccurrences` from every time it was shown.
+    pub fn occurrences(&self, message_or_key: &str) -> u32 {
+        self.seen
+            .get(message_or_key)
+            .map_or(0, |(_, occurrences)| *occurrences)
+    }
+
+    /// Whether the alert with this key or message was shown at most `window` ago.
+    pub fn seen_within(&self, message_or_key: &str, window: Duration) -> bool {
+        self.last_seen(message_or_key)
+            .is_some_and(|last_seen| last_seen <= window)
+    }
+
+    /// Forgets every alert seen so far.
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.interactions.clear();
+    }
+
+    pub(crate) fn record_interactions(&mut self, record: AlertInteractionRecord) {
+        self.interactions.push_back(record);
+        self.evict();
+    }
+
+    pub(crate) fn record(&mut self, message_or_key: &str, now: Duration, occurrences: u32) {
+        self.now = self.now.max(now);
+        match self.seen.get_mut(message_or_key) {
+            Some(seen) => *seen = (now, seen.1 + occurrences),
+            None => {
+                self.seen
+                    .insert(message_or_key.to_string(), (now, occurrences));
+                self.evict();
+            }
+        }
+    }
+
+    fn evict(&mut self) {
+        while self.seen.len() > self.capacity {
+            let Some(oldest) = self
+                .seen
+                .iter()
+                .min_by_key(|(_, (seen, _))| *seen)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            self.seen.remove(&oldest);
+        }
+        while self.interactions.len() > self.capacity {
+            self.interactions.pop_front();
+        }
+    }
+}
+
+/// When a shown alert was first hovered and when it started to leave, as `Time::elapsed`. It is
+/// added to the `AlertHistory` as an `AlertInteractionRecord` once the alert is despawned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub struct AlertInteractions {
+    pub shown_at: Duration,
+    pub first_hover: Option<Duration>,
+    pub left_at: Option<Duration>,
+}
+
+impl AlertInteractions {
+    pub fn shown_at(now: Duration) -> Self {
+        Self {
+            shown_at: now,
+            first_hover: None,
+            left_at: None,
+        }
+    }
+}
+
+/// What the player did with one alert that left the UI, kept by the `AlertHistory`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlertInteractionRecord {
+    /// The `AlertKey` of the alert, or its message when it has none.
+    pub message_or_key: String,
+    pub level: AlertLevel,
+    /// How long after it was shown the alert was first hovered, if it ever was.
+    pub first_hover: Option<Duration>,
+    /// How long the alert was shown until it started to leave.
+    pub shown_for: Duration,
+    pub reason: Option<AlertDismissReason>,
+}
+
+impl AlertInteractionRecord {
+    pub fn hovered(&self) -> bool {
+        self.first_hover.is_some()
+    }
+}
+
+/// Marks alerts whose `AlertOccurrences` were added to the `AlertHistory`, so an alert that is
+/// queued again and shown anew is not counted twice.
+#[derive(Component)]
+pub(crate) struct AlertRecorded;
+
+impl<M> Default for AlertHistory<M> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::default(),
+            interactions: VecDeque::default(),
+            now: Duration::ZERO,
+            capacity: AlertDefaults::HISTORY_CAPACITY,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// The log target of the records written by `AlertLogMirror`.
+pub const ALERT_LOG_TARGET: &str = "bevy_ui_mod_alerts::mirror";
+
+/// Which alerts of this kind are also written to the log, so headless runs and crash logs include
+/// what the player saw. Disabled by default.
+///
+/// Alerts at `min_level` or above are logged once as they are raised, at the matching `tracing`
+/// level and prefixed with the marker's name, and again as they are despawned with their
+/// `AlertDismissReason`. Records use the `ALERT_LOG_TARGET` target, so a layer that turns log
+/// records into alerts can skip them instead of raising them again.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertLogMirror<M: TypePath> {
+    pub min_level: Option<AlertLevel>,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertLogMirror<M>
+where
+    M: TypePath,
+{
+    pub fn new(min_level: Option<AlertLevel>) -> Self {
+        Self {
+            min_level,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub(crate) fn mirrors(&self, level: AlertLevel) -> bool {
+        self.min_level.is_some_and(|min_level| level >= min_level)
+    }
+}
+
+pub(crate) fn log_alert(level: AlertLevel, record: std::fmt::Arguments) {
+    match level {
+        AlertLevel::Info => info!(target: ALERT_LOG_TARGET, "{record}"),
+        AlertLevel::Warning => warn!(target: ALERT_LOG_TARGET, "{record}"),
+        AlertLevel::Error => error!(target: ALERT_LOG_TARGET, "{record}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_alert_history() {
+        let mut app = manual_app();
+        app.world_mut()
+            .spawn((Alert::keyed("jump", "Press space to jump"), AlertMarker));
+        app.world_mut().spawn((Alert::bundle("Saved"), AlertMarker));
+        app.update();
+
+        let history = |app: &App| app.world().resource::<AlertHistory<AlertMarker>>();
+        assert_eq!(history(&app).last_seen("jump"), Some(Duration::ZERO));
+        assert_eq!(history(&app).last_seen("Saved"), Some(Duration::ZERO));
+        // keyed alerts are looked up by key
+        assert_eq!(history(&app).last_seen("Press space to jump"), None);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            history(&app).last_seen("jump"),
+            Some(Duration::from_secs(1))
+        );
+        assert!(history(&app).seen_within("jump", Duration::from_secs(1)));
+        assert!(!history(&app).seen_within("jump", Duration::from_millis(999)));
+        assert!(!history(&app).seen_within("never shown", Duration::MAX));
+
+        // surfacing the keyed alert again restarts its window
+        app.world_mut()
+            .spawn((Alert::keyed("jump", "Press space to jump!"), AlertMarker));
+        app.update();
+        assert!(history(&app).seen_within("jump", Duration::ZERO));
+        assert_eq!(
+            history(&app).last_seen("Saved"),
+            Some(Duration::from_millis(1250))
+        );
+
+        // dismissing does not forget it
+        let mut shown = app
+            .world_mut()
+            .query_filtered::<Entity, (With<AlertKey>, With<AlertUi>)>();
+        let shown = shown.single(app.world());
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| commands.dismiss_alert(shown));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(shown).is_none());
+        assert_eq!(
+            history(&app).last_seen("jump"),
+            Some(Duration::from_millis(750))
+        );
+    }
+
+    #[test]
+    fn test_alert_history_capacity() {
+        let mut app = manual_app();
+        app.insert_resource(AlertHistory::<AlertMarker>::with_capacity(2));
+        for message in ["Saved", "Loaded", "Synced"] {
+            app.world_mut().spawn((Alert::bundle(message), AlertMarker));
+            app.update();
+        }
+
+        let history = |app: &App| app.world().resource::<AlertHistory<AlertMarker>>();
+        // the alert seen the longest ago is forgotten first
+        assert_eq!(history(&app).last_seen("Saved"), None);
+        assert_eq!(
+            history(&app).last_seen("Loaded"),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(history(&app).last_seen("Synced"), Some(Duration::ZERO));
+
+        app.world_mut()
+            .resource_mut::<AlertHistory<AlertMarker>>()
+            .set_capacity(1);
+        assert_eq!(history(&app).last_seen("Loaded"), None);
+        assert!(history(&app).seen_within("Synced", Duration::ZERO));
+    }
+
+    #[test]
+    fn test_log_mirror() {
+        #[derive(Clone, Default)]
+        struct LogBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl std::io::Write for LogBuffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = LogBuffer::default();
+        let subscriber = bevy::log::tracing_subscriber::fmt()
+            .with_writer({
+                let buffer = buffer.clone();
+                move || buffer.clone()
+            })
+            .with_ansi(false)
+            .finish();
+        bevy::utils::tracing::subscriber::with_default(subscriber, || {
+            let mut app = manual_app();
+            app.insert_resource(AlertLogMirror::<AlertMarker>::new(Some(
+                AlertLevel::Warning,
+            )));
+            app.world_mut().spawn((Alert::bundle("saved"), AlertMarker));
+            app.world_mut().spawn((
+                Alert::with_level(AlertLevel::Warning, "low disk"),
+                AlertMarker,
+            ));
+            app.world_mut().spawn((
+                Alert::with_level(AlertLevel::Error, "save failed"),
+                AlertMarker,
+            ));
+            app.update();
+            DismissAlerts::<AlertMarker>::new()
+                .instant()
+                .apply(app.world_mut());
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let mut records = output
+            .lines()
+            .filter_map(|line| {
+                let (level, record) = line.split_once(&format!(" {ALERT_LOG_TARGET}: "))?;
+                let level = level.split_whitespace().last().unwrap();
+                Some((level, record.to_string()))
+            })
+            .collect::<Vec<_>>();
+        records.sort();
+        assert_eq!(
+            records,
+            [
+                ("ERROR", "[AlertMarker] save failed".to_string()),
+                (
+                    "ERROR",
+                    "[AlertMarker] save failed (removed: Cleared)".to_string()
+                ),
+                ("WARN", "[AlertMarker] low disk".to_string()),
+                (
+                    "WARN",
+                    "[AlertMarker] low disk (removed: Cleared)".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alert_occurrences() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(1));
+        let occurrences = |app: &App, alert: Entity| app.world().get::<AlertOccurrences>(alert);
+        let history = |app: &App, key: &str| {
+            app.world()
+                .resource::<AlertHistory<AlertMarker>>()
+                .occurrences(key)
+        };
+
+        // refreshed while pending, behind another alert
+        let shown = app
+            .world_mut()
+            .spawn((Alert::bundle("Saving…"), AlertMarker))
+            .id();
+        app.update();
+        let synced = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Synced 1 file"),
+                AlertKey::new("sync"),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        app.world_mut().spawn((
+            Alert::bundle("Synced 2 files"),
+            AlertKey::new("sync"),
+            AlertMarker,
+        ));
+        app.update();
+        assert_eq!(occurrences(&app, synced), Some(&AlertOccurrences(2)));
+        assert_eq!(history(&app, "sync"), 0);
+
+        // shown, then refreshed again while shown
+        app.world_mut()
+            .entity_mut(shown)
+            .insert(AlertDismissReason::Cleared)
+            .despawn_recursive();
+        app.update();
+        assert!(app.world().entity(synced).contains::<AlertUi>());
+        assert_eq!(history(&app, "sync"), 2);
+        app.world_mut().spawn((
+            Alert::bundle("Synced 3 files"),
+            AlertKey::new("sync"),
+            AlertMarker,
+        ));
+        app.update();
+        assert_eq!(occurrences(&app, synced), Some(&AlertOccurrences(3)));
+        assert_eq!(history(&app, "sync"), 3);
+
+        // coalesced by message, which is counted without an `AlertRepeatCount` badge too
+        app.insert_resource(DedupAlerts::<AlertMarker>::new(true));
+        let [full, _] = ["Disk full", "Disk full"].map(|message| {
+            app.world_mut()
+                .spawn((Alert::bundle(message), AlertMarker))
+                .id()
+        });
+        app.update();
+        assert_eq!(occurrences(&app, full), Some(&AlertOccurrences(2)));
+        assert_eq!(occurrences(&app, synced), Some(&AlertOccurrences(3)));
+
+        // a later alert with the same key adds to the history
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(3));
+        app.world_mut()
+            .entity_mut(synced)
+            .insert(AlertDismissReason::Cleared)
+            .despawn_recursive();
+        let again = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Synced 4 files"),
+                AlertKey::new("sync"),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        assert_eq!(occurrences(&app, again), Some(&AlertOccurrences(1)));
+        assert_eq!(history(&app, "sync"), 4);
+    }
+
+    #[test]
+    fn test_alert_interactions() {
+        let mut app = manual_app();
+        let saved = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Saved"),
+                AlertLifetimeOverride(Duration::from_millis(400)),
+                AlertMarker,
+            ))
+            .id();
+        let failed = app
+            .world_mut()
+            .spawn((
+                Alert::with_level(AlertLevel::Error, "Save failed"),
+                AlertMarker,
+            ))
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().get::<AlertInteractions>(failed),
+            Some(&AlertInteractions::shown_at(Duration::ZERO))
+        );
+
+        // hovered 750ms in, then dismissed 1s in
+        *app.world_mut().get_mut::<Interaction>(failed).unwrap() = Interaction::Hovered;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(failed).unwrap() = Interaction::None;
+        DismissAlert::<AlertMarker>::new(failed, AlertDismissReason::Dismissed)
+            .apply(app.world_mut());
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().get_entity(saved).is_none());
+        assert!(app.world().get_entity(failed).is_none());
+
+        let history = app.world().resource::<AlertHistory<AlertMarker>>();
+        let records = history.interactions().cloned().collect::<Vec<_>>();
+        assert_eq!(
+            records,
+            [
+                AlertInteractionRecord {
+                    message_or_key: "Saved".to_string(),
+                    level: AlertLevel::Info,
+                    first_hover: None,
+                    shown_for: Duration::from_millis(500),
+                    reason: Some(AlertDismissReason::Expired),
+                },
+                AlertInteractionRecord {
+                    message_or_key: "Save failed".to_string(),
+                    level: AlertLevel::Error,
+                    first_hover: Some(Duration::from_millis(750)),
+                    shown_for: Duration::from_secs(1),
+                    reason: Some(AlertDismissReason::Dismissed),
+                },
+            ]
+        );
+        assert!(!records[0].hovered() && records[1].hovered());
+        assert_eq!(
+            history.mean_time_to_dismiss(AlertLevel::Error),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(history.mean_time_to_dismiss(AlertLevel::Info), None);
+    }
+}
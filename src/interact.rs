@@ -0,0 +1,1507 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    pub(crate) fn handle_alert_button_bgs(
+        mut dismiss_buttons: Query<(&Interaction, &DismissButton, &mut BackgroundColor)>,
+        locked: Query<(), With<AlertInputLocked>>,
+    ) {
+        for (interaction, button, mut bg_color) in &mut dismiss_buttons {
+            let color = match interaction {
+                _ if locked.contains(button.alert) => AlertDefaults::BUTTON_LOCKED_COLOR,
+                Interaction::Pressed => AlertDefaults::BUTTON_PRESSED_COLOR,
+                Interaction::Hovered => AlertDefaults::BUTTON_HOVERED_COLOR,
+                Interaction::None => AlertDefaults::BUTTON_COLOR,
+            };
+            // keep the alpha, which fades with the card as it closes
+            bg_color.0 = color.with_alpha(bg_color.0.alpha());
+        }
+    }
+
+    pub(crate) fn handle_dismiss_alert_buttons(
+        mut commands: Commands,
+        dismiss_buttons: Query<(&Interaction, &DismissButton, &GlobalTransform)>,
+        cards: Query<&GlobalTransform>,
+        locked: Query<(), With<AlertInputLocked>>,
+    ) {
+        for (interaction, button, button_transform) in &dismiss_buttons {
+            if matches!(interaction, Interaction::Pressed) && !locked.contains(button.alert) {
+                // the card closes toward the button, wherever layout put it
+                let anchor = cards.get(button.alert).map_or(Vec2::ZERO, |card| {
+                    (button_transform.translation() - card.translation()).truncate()
+                });
+                commands.add(
+                    DismissAlert::<M>::new(button.alert, AlertDismissReason::Dismissed)
+                        .toward(anchor),
+                );
+            }
+        }
+    }
+
+    /// Swaps the dismiss affordance of cards, in place, to match the `AlertInputMode`.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn update_dismiss_affordances(
+        mut commands: Commands,
+        alerts: Query<Ref<AlertParts>, With<AlertManaged<M>>>,
+        mut buttons: Query<(&mut Style, &mut BorderColor, Option<&DismissAffordance>)>,
+        input_mode: Res<AlertInputMode<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        for parts in &alerts {
+            if !input_mode.is_changed() && !parts.is_added() {
+                continue;
+            }
+            let button = parts.dismiss_button();
+            let Ok((mut style, mut border, affordance)) = buttons.get_mut(button) else {
+                continue;
+            };
+            let showing = affordance.map_or(AlertInputDevice::MouseKeyboard, |shown| shown.0);
+            if showing.shows_hint() == input_mode.device.shows_hint() {
+                continue;
+            }
+            let mut entity = commands.entity(button);
+            entity.despawn_descendants();
+            if input_mode.device.shows_hint() {
+                let hint = alert_nodes.gamepad_dismiss();
+                *style = hint.style.clone();
+                *border = hint.border_color;
+                entity.with_children(|builder| {
+                    builder.spawn((
+                        Name::new("Dismiss Hint"),
+                        TextBundle::from_section(
+                            alert_nodes.gamepad_dismiss_label.clone(),
+                            alert_nodes.gamepad_dismiss_text().clone(),
+                        ),
+                    ));
+                });
+            } else {
+                *style = AlertUi::dismiss_button_style(alert_nodes.header());
+                *border = BorderColor::default();
+                entity.with_children(|builder| {
+                    builder.spawn(AlertUi::dismiss_text());
+                });
+            }
+            entity.insert(DismissAffordance(input_mode.device));
+        }
+    }
+
+    /// Dismisses the newest card when the hinted gamepad button is pressed.
+    ///
+    /// This is a deliberate action, so it is not held back by the `AlertInputLockout`.
+    pub(crate) fn dismiss_with_gamepad(
+        mut commands: Commands,
+        alerts: Query<
+            (Entity, Option<&AlertSequence>),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUi>,
+                Without<AlertDismissReason>,
+            ),
+        >,
+        gamepad_buttons: Option<Res<ButtonInput<GamepadButton>>>,
+        input_mode: Res<AlertInputMode<M>>,
+    ) {
+        if input_mode.device != AlertInputDevice::Gamepad {
+            return;
+        }
+        let Some(gamepad_buttons) = gamepad_buttons else {
+            return;
+        };
+        if !gamepad_buttons
+            .get_just_pressed()
+            .any(|button| button.button_type == input_mode.gamepad_dismiss)
+        {
+            return;
+        }
+        let newest = alerts
+            .iter()
+            .max_by_key(|(_, sequence)| sequence.map_or(0, |sequence| sequence.0));
+        if let Some((alert, _)) = newest {
+            commands.add(DismissAlert::<M>::new(alert, AlertDismissReason::Dismissed));
+        }
+    }
+
+    /// Locks newly shown alerts for the `AlertInputLockout` and unlocks them once it runs out.
+    pub(crate) fn tick_input_lockouts(
+        mut commands: Commands,
+        mut locked: Query<(Entity, &mut AlertInputLocked), With<AlertManaged<M>>>,
+        shown: Query<Entity, (With<AlertManaged<M>>, Added<AlertUi>)>,
+        lockout: Res<AlertInputLockout<M>>,
+        time: Res<Time>,
+    ) {
+        for (entity, mut lock) in &mut locked {
+            if lock.0.tick(time.delta()).finished() {
+                commands.entity(entity).remove::<AlertInputLocked>();
+            }
+        }
+        if lockout.duration.is_zero() {
+            return;
+        }
+        for entity in &shown {
+            commands.entity(entity).insert(AlertInputLocked(Timer::new(
+                lockout.duration,
+                TimerMode::Once,
+            )));
+        }
+    }
+
+    pub(crate) fn handle_details_buttons(
+        details_buttons: Query<(&Interaction, &DetailsButton), Changed<Interaction>>,
+        alerts: Query<&AlertDetails, (With<AlertManaged<M>>, Without<AlertInputLocked>)>,
+        mut requests: EventWriter<AlertDetailsRequested>,
+    ) {
+        for (interaction, button) in &details_buttons {
+            if !matches!(interaction, Interaction::Pressed) {
+                continue;
+            }
+            if let Ok(details) = alerts.get(button.alert) {
+                requests.send(AlertDetailsRequested {
+                    alert: button.alert,
+                    payload: details.0.clone(),
+                });
+            }
+        }
+    }
+
+    pub(crate) fn handle_action_buttons(
+        mut commands: Commands,
+        buttons: Query<(&Interaction, &ActionButton), Changed<Interaction>>,
+        alerts: Query<(), (With<AlertManaged<M>>, Without<AlertInputLocked>)>,
+        mut actions: EventWriter<AlertActionEvent>,
+    ) {
+        for (interaction, button) in &buttons {
+            if !matches!(interaction, Interaction::Pressed) || !alerts.contains(button.alert) {
+                continue;
+            }
+            actions.send(AlertActionEvent {
+                alert: button.alert,
+                action_id: button.action.id.clone(),
+            });
+            if button.action.dismisses {
+                commands.add(DismissAlert::<M>::new(
+                    button.alert,
+                    AlertDismissReason::Dismissed,
+                ));
+            }
+        }
+    }
+
+    /// Expands truncated alerts to their full message, or collapses them back to the preview.
+    pub(crate) fn handle_show_more_buttons(
+        mut commands: Commands,
+        buttons: Query<(&Interaction, &ShowMoreButton, &Children), Changed<Interaction>>,
+        alerts: Query<
+            (&Alert, &AlertParts, Has<AlertExpanded>),
+            (With<AlertManaged<M>>, Without<AlertInputLocked>),
+        >,
+        mut texts: Query<&mut Text>,
+        text_limits: Res<AlertTextLimits<M>>,
+    ) {
+        for (interaction, button, children) in &buttons {
+            if !matches!(interaction, Interaction::Pressed) {
+                continue;
+            }
+            let Ok((alert, parts, expanded)) = alerts.get(button.alert) else {
+                continue;
+            };
+            let (message, label) = if expanded {
+                commands.entity(button.alert).remove::<AlertExpanded>();
+                (
+                    text_limits.display_text(&alert.message),
+                    ShowMoreButton::LABEL,
+                )
+            } else {
+                commands.entity(button.alert).insert(AlertExpanded);
+                (alert.message.clone(), ShowMoreButton::EXPANDED_LABEL)
+            };
+            // the card grows or shrinks with the text on the next layout
+            if let Ok(mut text) = texts.get_mut(parts.text) {
+                text.sections[0].value = message;
+            }
+            for child in children.iter() {
+                if let Ok(mut text) = texts.get_mut(*child) {
+                    text.sections[0].value = label.to_string();
+                }
+            }
+        }
+    }
+
+    /// Records when shown alerts are first hovered and when they start to leave in their
+    /// `AlertInteractions`.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn track_alert_interactions(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                Option<&Interaction>,
+                Option<&mut AlertInteractions>,
+                Has<AlertDismissReason>,
+            ),
+            (With<AlertManaged<M>>, With<AlertUi>),
+        >,
+        time: Res<Time>,
+    ) {
+        let now = time.elapsed();
+        for (entity, interaction, interactions, leaving) in &mut alerts {
+            let Some(mut interactions) = interactions else {
+                let mut alert = commands.entity(entity);
+                alert.insert(AlertInteractions::shown_at(now));
+                // the card needs an `Interaction` to notice the pointer
+                if interaction.is_none() {
+                    alert.insert(Interaction::default());
+                }
+                continue;
+            };
+            if interactions.first_hover.is_none()
+                && matches!(
+                    interaction,
+                    Some(Interaction::Hovered | Interaction::Pressed)
+                )
+            {
+                interactions.first_hover = Some(now.saturating_sub(interactions.shown_at));
+            }
+            if leaving && interactions.left_at.is_none() {
+                interactions.left_at = Some(now);
+            }
+        }
+    }
+
+    /// Shows the full message of a hovered alert that was truncated and not expanded, and hides
+    /// it again once the pointer leaves or the alert is dismissed.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn show_alert_tooltips(
+        mut commands: Commands,
+        alerts: Query<
+            (
+                Entity,
+                &Alert,
+                Option<&Interaction>,
+                Option<&AlertRootKey>,
+                Has<AlertExpanded>,
+                Has<AlertDismissReason>,
+            ),
+            (With<AlertManaged<M>>, With<AlertUi>),
+        >,
+        tooltips: Query<(Entity, &AlertTooltip, &Parent)>,
+        nodes: Query<(&Node, &GlobalTransform)>,
+        roots: Res<AlertRoots<M>>,
+        text_limits: Res<AlertTextLimits<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        let mut hovered = HashMap::<Entity, (&Alert, Option<&AlertRootKey>)>::default();
+        for (entity, alert, interaction, key, expanded, dismissed) in &alerts {
+            // runs after the dismiss buttons, so a closing card loses its tooltip the same frame
+            if expanded || dismissed || !text_limits.is_truncated(&alert.message) {
+                continue;
+            }
+            match interaction {
+                // the card needs an `Interaction` to notice the pointer
+                None => {
+                    commands.entity(entity).insert(Interaction::default());
+                }
+                Some(Interaction::Hovered | Interaction::Pressed) => {
+                    hovered.insert(entity, (alert, key));
+                }
+                Some(Interaction::None) => {}
+            }
+        }
+        for (tooltip, AlertTooltip { alert }, root) in &tooltips {
+            // other kinds keep their own tooltips
+            if !roots.iter().any(|(_, own_root)| own_root == root.get()) {
+                continue;
+            }
+            if hovered.remove(alert).is_none() {
+                commands.entity(tooltip).despawn_recursive();
+            }
+        }
+
+        for (entity, (alert, key)) in hovered {
+            let Some(root) = roots.get(key.unwrap_or(&AlertRootKey::PRIMARY)) else {
+                continue;
+            };
+            let mut tooltip = alert_nodes.tooltip().clone();
+            // just below the card, in the coordinates of the root
+            if let (Ok((card, card_transform)), Ok((root_node, root_transform))) =
+                (nodes.get(entity), nodes.get(root))
+            {
+                let root_corner = root_transform.translation().truncate() - root_node.size() / 2.;
+                let card_corner = card_transform.translation().truncate() - card.size() / 2.;
+                let offset = card_corner - root_corner;
+                tooltip.style.position_type = PositionType::Absolute;
+                tooltip.style.left = Val::Px(offset.x);
+                tooltip.style.top = Val::Px(offset.y + card.size().y);
+            }
+            // never in the way of the buttons below it
+            tooltip.focus_policy = FocusPolicy::Pass;
+            tooltip.z_index = ZIndex::Local(1);
+            let tooltip = commands
+                .spawn((
+                    Name::new("Alert Tooltip"),
+                    AlertTooltip { alert: entity },
+                    tooltip,
+                ))
+                .with_children(|builder| {
+                    builder.spawn(TextBundle::from_section(
+                        alert.message.clone(),
+                        alert_nodes.tooltip_text().clone(),
+                    ));
+                })
+                .id();
+            commands.entity(root).add_child(tooltip);
+        }
+    }
+
+    #[cfg(feature = "clipboard")]
+    pub(crate) fn handle_copy_buttons(
+        mut commands: Commands,
+        mut copy_buttons: Query<
+            (Entity, &Interaction, &CopyButton, &Children),
+            Changed<Interaction>,
+        >,
+        mut texts: Query<&mut Text>,
+        alerts: Query<
+            (&Alert, Option<&AlertDetails>),
+            (With<AlertManaged<M>>, Without<AlertInputLocked>),
+        >,
+        mut clipboard: ResMut<AlertClipboard>,
+        factory: Res<MarkerFactory<M>>,
+    ) {
+        for (entity, interaction, button, children) in &mut copy_buttons {
+            if !matches!(interaction, Interaction::Pressed) {
+                continue;
+            }
+            let Ok((alert, details)) = alerts.get(button.alert) else {
+                continue;
+            };
+            let contents = match details {
+                Some(details) => format!("{}\n\n{}", alert.message, details.0),
+                None => alert.message.clone(),
+            };
+            match clipboard.provider.set_text(contents) {
+                Ok(()) => {
+                    for child in children.iter() {
+                        if let Ok(mut text) = texts.get_mut(*child) {
+                            text.sections[0].value = CopyButton::COPIED_LABEL.to_string();
+                        }
+                    }
+                    commands.entity(entity).insert(CopyFeedback(Timer::new(
+                        CopyButton::FEEDBACK_DURATION,
+                        TimerMode::Once,
+                    )));
+                }
+                Err(error) => {
+                    commands.spawn((
+                        Alert::bundle(format!("Could not copy to the clipboard: {error}")),
+                        factory.make(),
+                    ));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "clipboard")]
+    pub(crate) fn reset_copy_buttons(
+        mut commands: Commands,
+        mut copy_buttons: Query<(Entity, &mut CopyFeedback, &Children)>,
+        mut texts: Query<&mut Text>,
+        time: Res<Time>,
+    ) {
+        for (entity, mut feedback, children) in &mut copy_buttons {
+            if !feedback.0.tick(time.delta()).finished() {
+                continue;
+            }
+            for child in children.iter() {
+                if let Ok(mut text) = texts.get_mut(*child) {
+                    text.sections[0].value = CopyButton::LABEL.to_string();
+                }
+            }
+            commands.entity(entity).remove::<CopyFeedback>();
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn handle_collapse_badge(
+        mut commands: Commands,
+        badges: Query<(Entity, &Interaction), (With<AlertManaged<M>>, With<AlertCollapseBadge>)>,
+        mut roots: Query<
+            (Entity, &mut Visibility),
+            (With<AlertManaged<M>>, With<AlertStackCollapsed>),
+        >,
+        new_alerts: Query<(), (With<AlertManaged<M>>, Added<Alert>)>,
+        auto_collapse: Option<ResMut<AutoCollapse<M>>>,
+    ) {
+        let Some(mut auto_collapse) = auto_collapse else {
+            return;
+        };
+        let pressed = badges
+            .iter()
+            .any(|(_, interaction)| matches!(interaction, Interaction::Pressed));
+        let forced = auto_collapse.expand_on_alert && !new_alerts.is_empty();
+        if !pressed && !forced {
+            return;
+        }
+
+        for (badge, _) in &badges {
+            commands.entity(badge).despawn_recursive();
+        }
+        for (root, mut visibility) in &mut roots {
+            *visibility = Visibility::Inherited;
+            commands.entity(root).remove::<AlertStackCollapsed>();
+        }
+        auto_collapse.idle.reset();
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn scroll_alert_lifetimes(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                Option<&Interaction>,
+                Option<&mut AlertLifetimeOverride>,
+                Option<&Children>,
+            ),
+            (With<AlertManaged<M>>, With<AlertUi>),
+        >,
+        indicators: Query<(), With<AlertLifetimeIndicator>>,
+        wheel: Option<ResMut<Events<MouseWheel>>>,
+        scroll: Res<AlertLifetimeScroll<M>>,
+        lifetime: Res<AlertLifetime<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        let mut hovered = None;
+        for (entity, interaction, lifetime_override, children) in &mut alerts {
+            match interaction {
+                // the card needs an `Interaction` to notice the pointer
+                None => {
+                    commands.entity(entity).insert(Interaction::default());
+                }
+                Some(Interaction::Hovered | Interaction::Pressed) => {
+                    hovered = Some((entity, lifetime_override, children));
+                }
+                Some(Interaction::None) => {}
+            }
+        }
+        let (Some((alert, lifetime_override, children)), Some(mut wheel)) = (hovered, wheel) else {
+            return;
+        };
+        let notches = wheel
+            .drain()
+            .map(|event| event.y.signum() as i32)
+            .sum::<i32>();
+        if notches == 0 {
+            return;
+        }
+
+        let current = lifetime_override
+            .as_ref()
+            .map_or(**lifetime, |value| value.0);
+        let change = scroll.step * notches.unsigned_abs();
+        let adjusted = if notches > 0 {
+            current.saturating_add(change)
+        } else {
+            current.saturating_sub(change)
+        }
+        .clamp(scroll.min, scroll.max);
+        match lifetime_override {
+            Some(mut lifetime_override) => lifetime_override.0 = adjusted,
+            None => {
+                commands
+                    .entity(alert)
+                    .insert(AlertLifetimeOverride(adjusted));
+            }
+        }
+
+        for child in children.into_iter().flatten() {
+            if indicators.contains(*child) {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+        let seconds = adjusted.as_secs_f32() - current.as_secs_f32();
+        let label = format!("{:+}s", seconds.round());
+        commands.entity(alert).with_children(|builder| {
+            builder
+                .spawn((
+                    Name::new("Alert Lifetime Indicator"),
+                    AlertLifetimeIndicator::default(),
+                    alert_nodes.badge().clone(),
+                ))
+                .with_children(|builder| {
+                    builder.spawn(TextBundle::from_section(
+                        label,
+                        alert_nodes.badge_text().clone(),
+                    ));
+                });
+        });
+    }
+
+    pub(crate) fn fade_lifetime_indicators(
+        mut commands: Commands,
+        mut indicators: Query<(Entity, &mut AlertLifetimeIndicator, &Children)>,
+        mut texts: Query<&mut Text>,
+        alert_nodes: Res<AlertElements<M>>,
+        time: Res<Time>,
+    ) {
+        for (entity, mut indicator, children) in &mut indicators {
+            indicator.0.tick(time.delta());
+            if indicator.0.finished() {
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+            let alpha = alert_nodes.badge_text().color.alpha() * (1. - indicator.0.fraction());
+            let mut texts = texts.iter_many_mut(children);
+            while let Some(mut text) = texts.fetch_next() {
+                for section in &mut text.sections {
+                    section.style.color.set_alpha(alpha);
+                }
+            }
+        }
+    }
+}
+
+/// Lets the mouse wheel lengthen or shorten the life of the hovered alert of this kind.
+///
+/// Each notch up adds `step` to the alert's `AlertLifetimeOverride`, which starts from the current
+/// `AlertLifetime`, and each notch down takes it away, within `min..=max`. A short-lived indicator
+/// in the `AlertElements::badge` style shows the change. Wheel events are only consumed while an
+/// alert is hovered. This resource is not inserted by default.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertLifetimeScroll<M: TypePath> {
+    pub step: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertLifetimeScroll<M>
+where
+    M: TypePath,
+{
+    pub fn new(step: Duration, min: Duration, max: Duration) -> Self {
+        Self {
+            step,
+            min,
+            max: max.max(min),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M> Default for AlertLifetimeScroll<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self::new(
+            Duration::from_secs(5),
+            Duration::from_secs(1),
+            Duration::from_secs(60),
+        )
+    }
+}
+
+/// The indicator showing how scrolling changed an alert's lifetime. It fades and despawns itself.
+#[derive(Component)]
+pub struct AlertLifetimeIndicator(Timer);
+
+impl AlertLifetimeIndicator {
+    pub const DURATION: Duration = Duration::from_millis(600);
+}
+
+impl Default for AlertLifetimeIndicator {
+    fn default() -> Self {
+        Self(Timer::new(Self::DURATION, TimerMode::Once))
+    }
+}
+
+/// The input device the player is using, as far as the alerts' dismiss affordance is concerned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum AlertInputDevice {
+    #[default]
+    MouseKeyboard,
+    Gamepad,
+    Touch,
+}
+
+impl AlertInputDevice {
+    /// Whether cards show the gamepad hint instead of the dismiss button.
+    pub fn shows_hint(self) -> bool {
+        self == AlertInputDevice::Gamepad
+    }
+}
+
+/// The input device alerts of this kind are dismissed with. Update `device` from the game's own
+/// input detection.
+///
+/// Mouse and touch show the "X" dismiss button. A gamepad shows a hint labelled and styled by
+/// `AlertElements::gamepad_dismiss`, and pressing `gamepad_dismiss` dismisses the newest card.
+/// Cards already in the UI swap their affordance in place when this changes.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertInputMode<M: TypePath> {
+    pub device: AlertInputDevice,
+    pub gamepad_dismiss: GamepadButtonType,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertInputMode<M>
+where
+    M: TypePath,
+{
+    pub fn new(device: AlertInputDevice) -> Self {
+        Self {
+            device,
+            gamepad_dismiss: GamepadButtonType::East,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M> Default for AlertInputMode<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self::new(AlertInputDevice::MouseKeyboard)
+    }
+}
+
+/// The input device a dismiss button's affordance was last built for.
+#[derive(Component)]
+pub(crate) struct DismissAffordance(AlertInputDevice);
+
+/// How long a newly shown alert of this kind ignores pointer presses on its buttons, so a click
+/// already underway when it appears does not dismiss it unread.
+///
+/// Its dismiss button is dimmed meanwhile. Hovering and the gamepad dismiss are not held back. A
+/// zero `duration` disables the lockout.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertInputLockout<M: TypePath> {
+    pub duration: Duration,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertInputLockout<M>
+where
+    M: TypePath,
+{
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// A component on shown alerts that still ignore pointer presses. See `AlertInputLockout`.
+#[derive(Debug, Component)]
+pub struct AlertInputLocked(pub(crate) Timer);
+
+impl AlertInputLocked {
+    pub fn remaining(&self) -> Duration {
+        self.0.remaining()
+    }
+}
+
+/// An event sent when the "Details" button of an alert with `AlertDetails` is pressed.
+///
+/// The alert stays on screen.
+#[derive(Clone, Debug, Event)]
+pub struct AlertDetailsRequested {
+    pub alert: Entity,
+    pub payload: String,
+}
+
+/// A marker component for the button in the AlertUI node tree that requests an alert's details.
+#[derive(Component)]
+pub struct DetailsButton {
+    pub(crate) alert: Entity,
+}
+
+/// An event sent when a button of an alert's `AlertActions` is pressed.
+#[derive(Clone, Debug, Event)]
+pub struct AlertActionEvent {
+    pub alert: Entity,
+    pub action_id: String,
+}
+
+/// A marker component for the buttons in the footer of an alert with `AlertActions`.
+#[derive(Component)]
+pub struct ActionButton {
+    pub(crate) alert: Entity,
+    pub(crate) action: AlertAction,
+}
+
+impl ActionButton {
+    pub fn action(&self) -> &AlertAction {
+        &self.action
+    }
+}
+
+/// A marker component for the button in the body of a truncated alert that shows its full
+/// message. See `AlertTextLimits`.
+#[derive(Component)]
+pub struct ShowMoreButton {
+    pub(crate) alert: Entity,
+}
+
+impl ShowMoreButton {
+    pub const LABEL: &'static str = "Show more";
+    pub const EXPANDED_LABEL: &'static str = "Show less";
+}
+
+/// A marker for truncated alerts that show their full message after their "Show more" button was
+/// pressed. The lifetime of an expanded alert is paused so it can be read to the end.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertExpanded;
+
+/// The floating node that shows the full message of a truncated alert while it is hovered.
+///
+/// It is placed in the alert's root, below the card and above the other alerts, and lets the
+/// pointer through to the cards and buttons beneath it. Style it with `AlertElements::tooltip`.
+#[derive(Component)]
+pub struct AlertTooltip {
+    pub(crate) alert: Entity,
+}
+
+impl AlertTooltip {
+    pub fn alert(&self) -> Entity {
+        self.alert
+    }
+}
+
+/// A source of clipboard access for the alert copy buttons.
+///
+/// The default `SystemClipboard` uses the platform clipboard. Tests or platforms with their own
+/// clipboard integration can insert an `AlertClipboard` with a different provider. Alerts get no
+/// copy button while the provider is not available.
+#[cfg(feature = "clipboard")]
+pub trait ClipboardProvider: Send + Sync + 'static {
+    fn set_text(&mut self, text: String) -> Result<(), String>;
+
+    fn is_available(&self) -> bool {
+        true
+    }
+}
+
+/// The platform clipboard, provided by `arboard` on native targets. It is not available on wasm.
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Default)]
+pub struct SystemClipboard;
+
+#[cfg(feature = "clipboard")]
+impl ClipboardProvider for SystemClipboard {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn set_text(&mut self, text: String) -> Result<(), String> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|error| error.to_string())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn set_text(&mut self, _text: String) -> Result<(), String> {
+        Err("the clipboard is not supported on this platform".to_string())
+    }
+
+    fn is_available(&self) -> bool {
+        cfg!(not(target_arch = "wasm32"))
+    }
+}
+
+/// The `ClipboardProvider` used by the alert copy buttons.
+#[cfg(feature = "clipboard")]
+#[derive(Resource)]
+pub struct AlertClipboard {
+    pub(crate) provider: Box<dyn ClipboardProvider>,
+}
+
+#[cfg(feature = "clipboard")]
+impl AlertClipboard {
+    pub fn new(provider: impl ClipboardProvider) -> Self {
+        Self {
+            provider: Box::new(provider),
+        }
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl Default for AlertClipboard {
+    fn default() -> Self {
+        Self::new(SystemClipboard)
+    }
+}
+
+/// Whether every alert of this kind gets a copy button.
+///
+/// Individual alerts can opt in with `CopyableAlert` instead.
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertCopyButtons<M: TypePath> {
+    pub all: bool,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+#[cfg(feature = "clipboard")]
+impl<M> AlertCopyButtons<M>
+where
+    M: TypePath,
+{
+    pub fn new(all: bool) -> Self {
+        Self {
+            all,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl<M> Default for AlertCopyButtons<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+/// Which alerts get a copy button, settled once per frame from `AlertCopyButtons` and the
+/// `AlertClipboard`.
+#[cfg(feature = "clipboard")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CopyButtonPolicy {
+    Never,
+    Copyable,
+    All,
+}
+
+#[cfg(feature = "clipboard")]
+impl CopyButtonPolicy {
+    pub(crate) fn new<M: TypePath>(
+        buttons: &AlertCopyButtons<M>,
+        clipboard: &AlertClipboard,
+    ) -> Self {
+        match (clipboard.provider.is_available(), buttons.all) {
+            (false, _) => Self::Never,
+            (true, false) => Self::Copyable,
+            (true, true) => Self::All,
+        }
+    }
+
+    pub(crate) fn shows(self, copyable: bool) -> bool {
+        self == Self::All || (self == Self::Copyable && copyable)
+    }
+}
+
+/// A marker component for alerts that should render a copy button in their header.
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Default, Component)]
+pub struct CopyableAlert;
+
+/// A marker component for the button in the AlertUI node tree that copies the alert message.
+#[cfg(feature = "clipboard")]
+#[derive(Component)]
+pub struct CopyButton {
+    pub(crate) alert: Entity,
+}
+
+#[cfg(feature = "clipboard")]
+impl CopyButton {
+    pub const LABEL: &'static str = "Copy";
+    pub const COPIED_LABEL: &'static str = "✓";
+    pub const FEEDBACK_DURATION: Duration = Duration::from_millis(1200);
+}
+
+/// Restores the label of a `CopyButton` once the timer finishes.
+#[cfg(feature = "clipboard")]
+#[derive(Component)]
+pub(crate) struct CopyFeedback(Timer);
+
+/// A marker component for the button in the AlertUI node tree that dismisses the alert.
+#[derive(Component)]
+pub struct DismissButton {
+    pub(crate) alert: Entity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_details_button() {
+        let mut app = manual_app();
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Failed to load the level"),
+                AlertDetails("missing asset: levels/02.ron".to_string()),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+
+        let button = app
+            .world_mut()
+            .query_filtered::<Entity, With<DetailsButton>>()
+            .single(app.world());
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+
+        let events = app.world().resource::<Events<AlertDetailsRequested>>();
+        let requests = events
+            .get_reader()
+            .read(events)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].alert, alert);
+        assert_eq!(requests[0].payload, "missing asset: levels/02.ron");
+        assert!(!matches!(
+            app.world().get::<AlertTransition>(alert),
+            Some(AlertTransition::FadeOut)
+        ));
+    }
+
+    #[cfg(feature = "clipboard")]
+    #[test]
+    fn test_copy_button() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct MockClipboard(Arc<Mutex<Option<String>>>);
+
+        impl ClipboardProvider for MockClipboard {
+            fn set_text(&mut self, text: String) -> Result<(), String> {
+                *self.0.lock().unwrap() = Some(text);
+                Ok(())
+            }
+        }
+
+        let mut app = manual_app();
+        let clipboard = MockClipboard::default();
+        app.insert_resource(AlertClipboard::new(clipboard.clone()));
+        app.world_mut().spawn((
+            Alert::bundle("C:/saves/save_01.ron is corrupted"),
+            CopyableAlert,
+            AlertMarker,
+        ));
+        app.update();
+
+        let button = app
+            .world_mut()
+            .query_filtered::<Entity, With<CopyButton>>()
+            .single(app.world());
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+        assert_eq!(
+            clipboard.0.lock().unwrap().as_deref(),
+            Some("C:/saves/save_01.ron is corrupted")
+        );
+        let label = app.world().get::<Children>(button).unwrap()[0];
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().sections[0].value,
+            CopyButton::COPIED_LABEL
+        );
+
+        // without a clipboard, as on wasm, there is no button to press
+        struct NoClipboard;
+
+        impl ClipboardProvider for NoClipboard {
+            fn set_text(&mut self, _text: String) -> Result<(), String> {
+                Err("no clipboard".to_string())
+            }
+
+            fn is_available(&self) -> bool {
+                false
+            }
+        }
+
+        app.insert_resource(AlertClipboard::new(NoClipboard));
+        app.insert_resource(AlertCopyButtons::<AlertMarker>::new(true));
+        app.world_mut()
+            .spawn((Alert::bundle("Saved"), CopyableAlert, AlertMarker));
+        app.update();
+        let mut buttons = app.world_mut().query_filtered::<(), With<CopyButton>>();
+        assert_eq!(buttons.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_dismiss_toward_button() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let dismissed = app
+            .world_mut()
+            .spawn((Alert::bundle("Dismissed"), AlertMarker))
+            .id();
+        let expiring = app
+            .world_mut()
+            .spawn((Alert::bundle("Expiring"), AlertMarker))
+            .id();
+        app.update();
+        let alpha = app
+            .world()
+            .get::<BackgroundColor>(dismissed)
+            .unwrap()
+            .0
+            .alpha();
+
+        let button = app
+            .world()
+            .get::<AlertParts>(dismissed)
+            .unwrap()
+            .dismiss_button();
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert!(matches!(
+            app.world().get::<AlertTransition>(dismissed),
+            Some(AlertTransition::FadeOutToward(_))
+        ));
+        app.update();
+        // the card shrinks and fades in place instead of sliding off
+        assert!(app.world().get::<Transform>(dismissed).unwrap().scale.x < 1.);
+        assert!(
+            app.world()
+                .get::<BackgroundColor>(dismissed)
+                .unwrap()
+                .0
+                .alpha()
+                < alpha
+        );
+        assert!(matches!(
+            app.world().get::<Style>(dismissed).unwrap().left,
+            Val::Px(_)
+        ));
+
+        // expiry keeps the slide
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().get_entity(dismissed).is_none());
+        assert!(matches!(
+            app.world().get::<AlertTransition>(expiring),
+            Some(AlertTransition::FadeOut)
+        ));
+        app.update();
+        assert_eq!(app.world().get::<Transform>(expiring).unwrap().scale.x, 1.);
+        assert!(matches!(
+            app.world().get::<Style>(expiring).unwrap().left,
+            Val::Percent(_)
+        ));
+
+        // with reduced motion, the card only fades, and quickly
+        app.insert_resource(ReducedMotion::<AlertMarker>::new(true));
+        let reduced = app
+            .world_mut()
+            .spawn((Alert::bundle("Reduced"), AlertMarker))
+            .id();
+        app.update();
+        let button = app
+            .world()
+            .get::<AlertParts>(reduced)
+            .unwrap()
+            .dismiss_button();
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert!(matches!(
+            app.world().get::<AlertTransition>(reduced),
+            Some(AlertTransition::FadeOutToward(_))
+        ));
+        app.update();
+        assert!(app.world().get_entity(reduced).is_none());
+    }
+
+    #[test]
+    fn test_input_mode_affordance() {
+        let affordance = |app: &App, alert: Entity| {
+            let button = app
+                .world()
+                .get::<AlertParts>(alert)
+                .unwrap()
+                .dismiss_button();
+            let children = app.world().get::<Children>(button).unwrap();
+            assert_eq!(children.len(), 1);
+            let label = app.world().get::<Text>(children[0]).unwrap().sections[0]
+                .value
+                .clone();
+            (button, label)
+        };
+        let set_device = |app: &mut App, device: AlertInputDevice| {
+            app.world_mut()
+                .resource_mut::<AlertInputMode<AlertMarker>>()
+                .device = device;
+            app.update();
+        };
+        let mut app = manual_app();
+        let first = app
+            .world_mut()
+            .spawn((Alert::bundle("Saved"), AlertMarker))
+            .id();
+        app.update();
+        let (button, label) = affordance(&app, first);
+        assert_eq!(label, "X");
+
+        // the hint replaces the button's contents without rebuilding the card
+        set_device(&mut app, AlertInputDevice::Gamepad);
+        assert_eq!(affordance(&app, first), (button, "[B] Dismiss".to_string()));
+        assert_eq!(app.world().get::<Style>(button).unwrap().width, Val::Auto);
+        let second = app
+            .world_mut()
+            .spawn((Alert::bundle("Loaded"), AlertMarker))
+            .id();
+        app.update();
+        assert_eq!(affordance(&app, second).1, "[B] Dismiss");
+
+        set_device(&mut app, AlertInputDevice::Touch);
+        assert_eq!(affordance(&app, first), (button, "X".to_string()));
+        assert_eq!(affordance(&app, second).1, "X");
+        assert_eq!(
+            app.world().get::<Style>(button).unwrap().width,
+            Val::Px(AlertDefaults::DISMISS_BUTTON_WIDTH)
+        );
+
+        // the hinted gamepad button dismisses the newest card
+        set_device(&mut app, AlertInputDevice::Gamepad);
+        let mut gamepad_buttons = ButtonInput::<GamepadButton>::default();
+        gamepad_buttons.press(GamepadButton::new(Gamepad::new(0), GamepadButtonType::East));
+        app.insert_resource(gamepad_buttons);
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(second),
+            Some(&AlertDismissReason::Dismissed)
+        );
+        assert!(app.world().get::<AlertDismissReason>(first).is_none());
+    }
+
+    #[test]
+    fn test_input_lockout() {
+        let mut app = manual_app();
+        app.insert_resource(AlertInputLockout::<AlertMarker>::new(Duration::from_secs(
+            1,
+        )));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("Spawned under the cursor"), AlertMarker))
+            .id();
+        app.update();
+        let button = app
+            .world()
+            .get::<AlertParts>(alert)
+            .unwrap()
+            .dismiss_button();
+        let press = |app: &mut App, interaction: Interaction| {
+            app.world_mut().entity_mut(button).insert(interaction);
+            app.update();
+        };
+
+        // a press during the lockout is ignored, and the button stays dimmed
+        press(&mut app, Interaction::Pressed);
+        assert!(app.world().get::<AlertDismissReason>(alert).is_none());
+        assert_eq!(
+            app.world().get::<BackgroundColor>(button).unwrap().0,
+            AlertDefaults::BUTTON_LOCKED_COLOR
+        );
+        assert_eq!(
+            app.world()
+                .get::<AlertInputLocked>(alert)
+                .unwrap()
+                .remaining(),
+            Duration::from_millis(750)
+        );
+        press(&mut app, Interaction::None);
+        press(&mut app, Interaction::Hovered);
+        assert!(app.world().get::<AlertInputLocked>(alert).is_some());
+
+        // once it runs out, presses behave normally
+        press(&mut app, Interaction::Pressed);
+        assert!(app.world().get::<AlertInputLocked>(alert).is_none());
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(alert),
+            Some(&AlertDismissReason::Dismissed)
+        );
+
+        // the gamepad dismiss is deliberate, so it is not locked out
+        app.world_mut()
+            .resource_mut::<AlertInputMode<AlertMarker>>()
+            .device = AlertInputDevice::Gamepad;
+        let other = app
+            .world_mut()
+            .spawn((Alert::bundle("Dismissed on purpose"), AlertMarker))
+            .id();
+        app.update();
+        let mut gamepad_buttons = ButtonInput::<GamepadButton>::default();
+        gamepad_buttons.press(GamepadButton::new(Gamepad::new(0), GamepadButtonType::East));
+        app.insert_resource(gamepad_buttons);
+        app.update();
+        assert!(app.world().get::<AlertInputLocked>(other).is_some());
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(other),
+            Some(&AlertDismissReason::Dismissed)
+        );
+    }
+
+    #[test]
+    fn test_show_more_button() {
+        let mut app = manual_app();
+        app.insert_resource(AlertTextLimits::<AlertMarker>::new(20, 10));
+        let message = "Failed to parse settings.ron: unexpected token at line 12";
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle(message), AlertMarker))
+            .id();
+        let short = app
+            .world_mut()
+            .spawn((Alert::bundle("Saved"), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+
+        let mut buttons = app
+            .world_mut()
+            .query::<(Entity, &ShowMoreButton, &Children)>();
+        let (button, show_more, children) = buttons.single(app.world());
+        assert_eq!(show_more.alert, alert);
+        assert_ne!(show_more.alert, short);
+        let label = children[0];
+        let parts = *app.world().get::<AlertParts>(alert).unwrap();
+        let text = |app: &App| {
+            app.world().get::<Text>(parts.text()).unwrap().sections[0]
+                .value
+                .clone()
+        };
+        assert_eq!(text(&app), "Failed to …");
+
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+        assert_eq!(text(&app), message);
+        assert!(app.world().get::<AlertExpanded>(alert).is_some());
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().sections[0].value,
+            ShowMoreButton::EXPANDED_LABEL
+        );
+        // the lifetime is paused while expanded
+        let elapsed = app.world().get::<AlertTimer>(alert).unwrap().elapsed();
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertTimer>(alert).unwrap().elapsed(),
+            elapsed
+        );
+
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::None;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+        assert_eq!(text(&app), "Failed to …");
+        assert!(app.world().get::<AlertExpanded>(alert).is_none());
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().sections[0].value,
+            ShowMoreButton::LABEL
+        );
+    }
+
+    #[test]
+    fn test_alert_tooltip() {
+        let mut app = manual_app();
+        app.insert_resource(AlertTextLimits::<AlertMarker>::new(20, 10));
+        let message = "Failed to parse settings.ron: unexpected token at line 12";
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle(message), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+
+        let mut tooltips = app
+            .world_mut()
+            .query::<(&AlertTooltip, &Parent, &Children)>();
+        assert_eq!(tooltips.iter(app.world()).count(), 0);
+        *app.world_mut().get_mut::<Interaction>(alert).unwrap() = Interaction::Hovered;
+        app.update();
+
+        let (tooltip, parent, children) = tooltips.single(app.world());
+        assert_eq!(tooltip.alert(), alert);
+        let root = app.world().resource::<AlertRoots<AlertMarker>>().primary();
+        assert_eq!(Some(parent.get()), root);
+        let text = app.world().get::<Text>(children[0]).unwrap();
+        assert_eq!(text.sections[0].value, message);
+        let tooltip = app.world().get::<Parent>(children[0]).unwrap().get();
+        assert_eq!(
+            app.world().get::<FocusPolicy>(tooltip),
+            Some(&FocusPolicy::Pass)
+        );
+
+        *app.world_mut().get_mut::<Interaction>(alert).unwrap() = Interaction::None;
+        app.update();
+        assert_eq!(tooltips.iter(app.world()).count(), 0);
+
+        // pressing dismiss while hovering closes the tooltip with the card
+        *app.world_mut().get_mut::<Interaction>(alert).unwrap() = Interaction::Hovered;
+        app.update();
+        assert_eq!(tooltips.iter(app.world()).count(), 1);
+        let dismiss = app
+            .world()
+            .get::<AlertParts>(alert)
+            .unwrap()
+            .dismiss_button();
+        *app.world_mut().get_mut::<Interaction>(dismiss).unwrap() = Interaction::Pressed;
+        app.update();
+        assert!(app.world().get::<AlertDismissReason>(alert).is_some());
+        assert_eq!(tooltips.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_alert_actions() {
+        #[derive(Resource, Default)]
+        struct Received(Vec<(Entity, String)>);
+
+        let mut app = manual_app();
+        // read the way a game would, from a system in a later frame
+        app.init_resource::<Received>().add_systems(
+            Update,
+            |mut events: EventReader<AlertActionEvent>, mut received: ResMut<Received>| {
+                received.0.extend(
+                    events
+                        .read()
+                        .map(|event| (event.alert, event.action_id.clone())),
+                );
+            },
+        );
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Failed to sync"),
+                AlertActions(vec![
+                    AlertAction::new("retry", "Retry"),
+                    AlertAction::new("open", "Open folder").keep_open(),
+                ]),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let footer = app
+            .world()
+            .get::<AlertParts>(alert)
+            .unwrap()
+            .actions()
+            .unwrap();
+        let buttons = app.world().get::<Children>(footer).unwrap().to_vec();
+        assert_eq!(buttons.len(), 2);
+        let read_actions = |app: &mut App| {
+            let events = app.world().resource::<Events<AlertActionEvent>>();
+            events
+                .get_reader()
+                .read(events)
+                .map(|event| (event.alert, event.action_id.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        // "Open folder" keeps the alert
+        *app.world_mut().get_mut::<Interaction>(buttons[1]).unwrap() = Interaction::Pressed;
+        app.update();
+        assert_eq!(read_actions(&mut app), vec![(alert, "open".to_string())]);
+        assert!(app.world().get::<AlertDismissReason>(alert).is_none());
+
+        *app.world_mut().get_mut::<Interaction>(buttons[0]).unwrap() = Interaction::Pressed;
+        app.update();
+        assert!(read_actions(&mut app).contains(&(alert, "retry".to_string())));
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(alert),
+            Some(&AlertDismissReason::Dismissed)
+        );
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Received>().0,
+            vec![(alert, "open".to_string()), (alert, "retry".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_lifetime_scroll() {
+        use bevy::input::mouse::MouseScrollUnit;
+
+        fn scroll(app: &mut App, y: f32, times: usize) {
+            for _ in 0..times {
+                app.world_mut().send_event(MouseWheel {
+                    unit: MouseScrollUnit::Line,
+                    x: 0.,
+                    y,
+                    window: Entity::PLACEHOLDER,
+                });
+            }
+            app.update();
+        }
+
+        let mut app = manual_app();
+        app.add_event::<MouseWheel>();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(5)));
+        app.insert_resource(AlertLifetimeScroll::<AlertMarker>::new(
+            Duration::from_secs(5),
+            Duration::from_secs(1),
+            Duration::from_secs(12),
+        ));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("keep me"), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().get::<Interaction>(alert),
+            Some(&Interaction::None)
+        );
+
+        // wheel events pass through while no alert is hovered
+        scroll(&mut app, 1., 1);
+        assert!(app.world().get::<AlertLifetimeOverride>(alert).is_none());
+        assert!(!app.world().resource::<Events<MouseWheel>>().is_empty());
+        app.world_mut().resource_mut::<Events<MouseWheel>>().clear();
+
+        app.world_mut()
+            .entity_mut(alert)
+            .insert(Interaction::Hovered);
+        scroll(&mut app, 1., 1);
+        assert_eq!(
+            app.world().get::<AlertLifetimeOverride>(alert).unwrap().0,
+            Duration::from_secs(10)
+        );
+        assert!(app.world().resource::<Events<MouseWheel>>().is_empty());
+        let mut indicators = app
+            .world_mut()
+            .query_filtered::<&Children, With<AlertLifetimeIndicator>>();
+        let label = indicators.single(app.world())[0];
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().sections[0].value,
+            "+5s"
+        );
+
+        scroll(&mut app, 1., 2);
+        assert_eq!(
+            app.world().get::<AlertLifetimeOverride>(alert).unwrap().0,
+            Duration::from_secs(12)
+        );
+        scroll(&mut app, -1., 5);
+        assert_eq!(
+            app.world().get::<AlertLifetimeOverride>(alert).unwrap().0,
+            Duration::from_secs(1)
+        );
+        assert_eq!(indicators.iter(app.world()).count(), 1);
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(indicators.iter(app.world()).count(), 0);
+    }
+}
@@ -3,9 +3,9 @@
 //! `bevy_ui_mod_alerts` provides a "toast"-like alert UI which can be used to help manage
 //! errors using a convenient UI.
 //!
-//! Alerts can be spawned by directly spawning `AlertBundle`s using `AlertBundle` or
-//! `Alert::bundle`, or by piping a `Vec<String>` of alert messages into the `AlertsPlugin::alert`
-//! system.
+//! Alerts can be spawned by directly spawning `AlertBundle`s using `AlertBundle::new` or
+//! `Alert::bundle`, by piping a `Vec<String>` of alert messages into the `AlertsPlugin::alert`
+//! system, or by sending an `AlertEvent`. The everyday items are collected in `prelude`.
 //!
 //! ## Examples
 //!
@@ -31,6 +31,26 @@
 //! }
 //! ```
 //!
+//! Systems that produce at most one message can pipe an `Option<String>` into
+//! `AlertsPlugin::alert_option` instead:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_ui_mod_alerts::AlertsPlugin;
+//!
+//! let mut app = App::new();
+//! app.add_plugins(MinimalPlugins);
+//! app.add_plugins(AlertsPlugin::new());
+//! app.add_systems(Update, check_for_stragglers.pipe(AlertsPlugin::alert_option));
+//!
+//! #[derive(Component)]
+//! struct Straggler;
+//!
+//! fn check_for_stragglers(stragglers: Query<&Straggler>) -> Option<String> {
+//!     (!stragglers.is_empty()).then(|| format!("{} stragglers", stragglers.iter().len()))
+//! }
+//! ```
+//!
 //! The resulting UI is somewhat restylable but may not fit every application.
 //! Users can restyle the alerts with the `AlertElements` resource:
 //!
@@ -78,30 +98,348 @@
 //! app.add_systems(Update, (|| { vec![] }).pipe(AlertsPlugin::<MyAlert>::custom_alert));
 //! ```
 
-use std::{marker::PhantomData, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use bevy::{
+    asset::LoadState,
+    ecs::{
+        component::{ComponentHooks, StorageType, Tick},
+        query::QueryData,
+        schedule::SystemConfigs,
+        system::{RunSystemOnce, SystemParam},
+        world::Command,
+    },
+    input::mouse::MouseWheel,
+    prelude::*,
+    text::{BreakLineOn, TextLayoutInfo},
+    time::Stopwatch,
+    ui::FocusPolicy,
+    utils::{HashMap, HashSet},
+    window::PrimaryWindow,
+};
+
+mod conditions;
+#[cfg(any(debug_assertions, feature = "diagnostics"))]
+mod diagnostics;
+mod dismiss;
+mod history;
+mod interact;
+mod queue;
+mod root;
+mod snapshot;
+mod spawn;
+mod style;
+mod transition;
+
+pub use conditions::*;
+#[cfg(any(debug_assertions, feature = "diagnostics"))]
+pub use diagnostics::*;
+pub use dismiss::*;
+pub use history::*;
+pub use interact::*;
+pub use queue::*;
+pub use root::*;
+pub use snapshot::*;
+pub use spawn::*;
+pub use style::*;
+pub use transition::*;
+
+/// The items most apps need, for a single glob import that leaves the rest of the crate out of
+/// scope.
+///
+/// Every item is still available at the crate root.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::prelude::*;
+///
+/// fn alert_on_startup(mut commands: Commands) {
+///     commands.alert("Hello!");
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(MinimalPlugins)
+///     .add_plugins(AlertsPlugin::new())
+///     .insert_resource(MaxAlerts::<AlertMarker>::new(3))
+///     .add_systems(Startup, alert_on_startup)
+///     .add_systems(Update, (|| vec![]).pipe(AlertsPlugin::alert));
+/// app.update();
+///
+/// // the root paths keep working alongside the prelude
+/// let _: bevy_ui_mod_alerts::AlertBundle = Alert::bundle("still here");
+/// let _ = bevy_ui_mod_alerts::AlertElements::<AlertMarker>::new();
+/// let _ = bevy_ui_mod_alerts::TransitionTimer::default();
+/// let _ = bevy_ui_mod_alerts::easing::linear(0.5);
+/// let _ = bevy_ui_mod_alerts::AlertTimer::default();
+/// ```
+pub mod prelude {
+    pub use crate::{
+        Alert, AlertBuilder, AlertBundle, AlertCommandsExt, AlertDismissReason, AlertElements,
+        AlertEvent, AlertLevel, AlertLifetime, AlertMarker, AlertSystems, AlertWriter,
+        AlertsPlugin, CriticalAlert, MaxAlerts, WorldAlertExt,
+    };
+}
 
-use bevy::{prelude::*, time::Stopwatch};
+pub const ALERT_Z_INDEX: i32 = AlertDefaults::Z_INDEX;
+pub const DEFAULT_ALERT_HEIGHT: f32 = AlertDefaults::ALERT_HEIGHT;
 
-pub const ALERT_Z_INDEX: i32 = 1000;
-pub const DEFAULT_ALERT_HEIGHT: f32 = 80.;
+/// The values the plugin and the `AlertElements` presets are built from.
+///
+/// Reference these instead of copying the numbers when matching or extending the default look.
+pub struct AlertDefaults;
+
+impl AlertDefaults {
+    /// The `ZIndex::Local` of the alert root and the collapse badge.
+    pub const Z_INDEX: i32 = 1000;
+    /// The minimum height of an alert card.
+    pub const ALERT_HEIGHT: f32 = 80.;
+    /// The initial `AlertLifetime`.
+    pub const LIFETIME: Duration = Duration::from_secs(10);
+    /// The initial `MaxAlerts`.
+    pub const MAX_ALERTS: usize = 3;
+    /// How many alerts `AlertHistory` remembers at first.
+    pub const HISTORY_CAPACITY: usize = 256;
+    /// How long a card takes to slide in or out.
+    pub const TRANSITION: Duration = Duration::from_millis(500);
+    /// The scale a card shrinks to as it closes toward its dismiss button.
+    pub const DISMISS_SCALE: f32 = 0.2;
+    /// How long the `AlertRepeatCount` badge pops after a repeat. See `AlertRepeatPulse`.
+    pub const REPEAT_PULSE: Duration = Duration::from_millis(400);
+    /// The scale the `AlertRepeatCount` badge pops to.
+    pub const REPEAT_PULSE_SCALE: f32 = 1.4;
+    /// How long an `AlertCrossFade` takes once enabled.
+    pub const CROSS_FADE: Duration = Duration::from_millis(120);
+    /// The height of a card's header bar.
+    pub const HEADER_HEIGHT: f32 = 20.;
+    /// The width of the dismiss button.
+    pub const DISMISS_BUTTON_WIDTH: f32 = 22.;
+    /// How long a newly shown card ignores pointer presses. See `AlertInputLockout`.
+    pub const INPUT_LOCKOUT: Duration = Duration::from_millis(250);
+    /// The background of header buttons at rest.
+    pub const BUTTON_COLOR: Color = Color::srgb(0.35, 0.35, 0.35);
+    /// The background of the dismiss button while hovered.
+    pub const BUTTON_HOVERED_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+    /// The background of the dismiss button while pressed.
+    pub const BUTTON_PRESSED_COLOR: Color = Color::Srgba(bevy::color::palettes::css::DARK_GRAY);
+    /// The background of the dismiss button while its card ignores presses.
+    pub const BUTTON_LOCKED_COLOR: Color = Color::srgb(0.2, 0.2, 0.2);
+}
 
 /// A component representing an alert message that should be displayed in a UI.
-#[derive(Debug, Component)]
+///
+/// Inserting an `Alert` marks the entity `AlertPending`, names it with `AlertNames`, and triggers
+/// `AlertQueued` on it, no matter how it was spawned.
+#[derive(Debug)]
 pub struct Alert {
     message: String,
+    title: Option<String>,
+}
+
+impl Component for Alert {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, _| {
+            let sequence = if world.entity(entity).contains::<AlertSequence>() {
+                None
+            } else {
+                world
+                    .get_resource_mut::<AlertSequences>()
+                    .map(|mut sequences| AlertSequence(sequences.reserve(1)))
+            };
+            let occurrences = (!world.entity(entity).contains::<AlertOccurrences>())
+                .then_some(AlertOccurrences(1));
+            let mut commands = world.commands();
+            commands
+                .entity(entity)
+                .insert((AlertPending, AlertState::Pending));
+            if let Some(occurrences) = occurrences {
+                commands.entity(entity).insert(occurrences);
+            }
+            if let Some(sequence) = sequence {
+                commands.entity(entity).insert(sequence);
+            }
+            commands.add(move |world: &mut World| AlertNames::name_alert(world, entity));
+            commands.trigger_targets(AlertQueued, entity);
+        });
+    }
+}
+
+/// An observer event triggered on an alert entity as soon as its `Alert` component is added.
+///
+/// This fires before the plugin promotes the alert into the UI, so observers can attach their own
+/// components based on the message.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{Alert, AlertQueued};
+///
+/// #[derive(Component)]
+/// struct Tagged;
+///
+/// let mut app = App::new();
+/// app.observe(|trigger: Trigger<AlertQueued>, mut commands: Commands| {
+///     commands.entity(trigger.entity()).insert(Tagged);
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, Event)]
+pub struct AlertQueued;
+
+/// Generates the `Name` of each alert entity from a sequence number and the alert, so alerts can be
+/// told apart in inspectors and logs.
+///
+/// The default format looks like `Alert#42: "Failed to save…"`. Alerts spawned with a `Name` other
+/// than the default `"Alert"` keep it.
+#[derive(Debug, Resource)]
+pub struct AlertNames {
+    next: u64,
+    format: fn(u64, &Alert) -> String,
+}
+
+impl AlertNames {
+    pub const MESSAGE_LEN: usize = 24;
+
+    pub fn new(format: fn(u64, &Alert) -> String) -> Self {
+        Self { next: 0, format }
+    }
+
+    pub fn default_format(index: u64, alert: &Alert) -> String {
+        let mut message = alert
+            .message()
+            .chars()
+            .take(Self::MESSAGE_LEN)
+            .collect::<String>();
+        if alert.message().chars().nth(Self::MESSAGE_LEN).is_some() {
+            message.push('…');
+        }
+        format!("Alert#{index}: {message:?}")
+    }
+
+    fn name_alert(world: &mut World, entity: Entity) {
+        let Some(mut names) = world.get_resource_mut::<AlertNames>() else {
+            return;
+        };
+        let index = names.next;
+        names.next += 1;
+        let format = names.format;
+
+        let Some(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+        if entity
+            .get::<Name>()
+            .is_some_and(|name| name.as_str() != "Alert")
+        {
+            return;
+        }
+        let Some(alert) = entity.get::<Alert>() else {
+            return;
+        };
+        let name = Name::new(format(index, alert));
+        entity.insert(name);
+    }
+}
+
+impl Default for AlertNames {
+    fn default() -> Self {
+        Self::new(Self::default_format)
+    }
 }
 
 impl Alert {
-    pub fn bundle(message: impl Into<String>) -> impl Bundle {
-        (
-            Self {
-                message: message.into(),
-            },
-            Name::new("Alert"),
-            AlertTimer {
-                time_alive: Stopwatch::new(),
-            },
-        )
+    /// Builds a single alert titled `title` that lists each of `items` as a row.
+    ///
+    /// To limit the number of rows shown, spawn an `AlertGroup` with `with_max_rows` instead.
+    pub fn group(title: impl Into<String>, items: Vec<String>) -> impl Bundle {
+        (Self::bundle(title), AlertGroup::new(items))
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Replaces the message. Alerts that are already shown update their text in place.
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+    }
+
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            title: None,
+        }
+    }
+
+    /// The short title shown in the header, to the left of the buttons.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Replaces the title. Alerts that are already shown with a title update it in place.
+    pub fn set_title(&mut self, title: Option<String>) {
+        self.title = title;
+    }
+
+    pub fn bundle(message: impl Into<String>) -> AlertBundle {
+        AlertBundle::new(message)
+    }
+
+    /// A bundle for an alert with a title in its header above the message body.
+    pub fn titled(title: impl Into<String>, body: impl Into<String>) -> AlertBundle {
+        let mut bundle = AlertBundle::new(body);
+        bundle.alert.title = Some(title.into());
+        bundle
+    }
+
+    /// A bundle for an alert whose message is made of differently styled sections. See
+    /// `AlertRichText`.
+    pub fn rich(sections: Vec<(String, TextStyle)>) -> (AlertBundle, AlertRichText) {
+        let rich = AlertRichText(sections);
+        (AlertBundle::new(rich.message()), rich)
+    }
+
+    /// A bundle for an alert of the given `AlertLevel`.
+    pub fn with_level(level: AlertLevel, message: impl Into<String>) -> (AlertBundle, AlertLevel) {
+        (AlertBundle::new(message), level)
+    }
+
+    /// Starts an `AlertBuilder` for chaining the options of a new alert.
+    pub fn build(message: impl Into<String>) -> AlertBuilder {
+        AlertBuilder::new(message)
+    }
+
+    /// A bundle for an alert that replaces any live alert of the same kind with the same key. See
+    /// `AlertKey`.
+    pub fn keyed(key: impl Into<String>, message: impl Into<String>) -> (AlertBundle, AlertKey) {
+        (AlertBundle::new(message), AlertKey::new(key))
+    }
+
+    /// A bundle for an alert about a task that is still running. See `UnresolvedAlert`.
+    pub fn unresolved(message: impl Into<String>) -> (AlertBundle, UnresolvedAlert) {
+        (AlertBundle::new(message), UnresolvedAlert)
+    }
+
+    /// A bundle for an alert that never expires. See `PersistentAlert`.
+    pub fn persistent_bundle(message: impl Into<String>) -> (AlertBundle, PersistentAlert) {
+        (AlertBundle::new(message), PersistentAlert)
+    }
+}
+
+impl From<String> for Alert {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for Alert {
+    fn from(message: &str) -> Self {
+        Self::new(message)
     }
 }
 
@@ -109,7 +447,12 @@ impl Alert {
 ///
 /// It accepts a type parameter, `M`, which should implement `Component`.
 /// To configure mulitple kinds of Alert behaviors, add separate `AlertsPlugin`s with unique types
-/// for M. A default (`AlertMarker`) is used if not.
+/// for M. A default (`AlertMarker`) is used if not. Markers that do not implement `Default` are
+/// supported with `AlertsPlugin::with_marker`.
+///
+/// The `with_lifetime`, `with_max_alerts` and `with_elements` builders replace the defaults of
+/// those resources. A resource the app already has when the plugin is added is kept as is, and
+/// a builder value for it is ignored with a warning.
 ///
 /// ```
 /// use bevy::prelude::*;
@@ -126,14 +469,109 @@ impl Alert {
 /// app.add_systems(Update, (|| { vec![] }).pipe(AlertsPlugin::<MyAlert>::custom_alert));
 /// ```
 pub struct AlertsPlugin<M = AlertMarker> {
-    marker: PhantomData<M>,
+    factory: MarkerFactory<M>,
+    lifetime: Option<Duration>,
+    max_alerts: Option<usize>,
+    elements: Option<AlertElements<M>>,
 }
 
-impl<M> Default for AlertsPlugin<M> {
+impl<M> Default for AlertsPlugin<M>
+where
+    M: Default + 'static,
+{
     fn default() -> Self {
+        Self::with_marker(M::default)
+    }
+}
+
+impl<M> AlertsPlugin<M> {
+    /// Builds an AlertsPlugin for a marker that carries data or does not implement `Default`.
+    ///
+    /// `factory` builds the `M` inserted on the alerts and roots that the plugin spawns itself.
+    pub fn with_marker(factory: impl Fn() -> M + Send + Sync + 'static) -> Self {
         Self {
-            marker: PhantomData::<M>,
+            factory: MarkerFactory::new(factory),
+            lifetime: None,
+            max_alerts: None,
+            elements: None,
+        }
+    }
+
+    /// Sets the initial `AlertLifetime`.
+    pub fn with_lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Sets the initial `MaxAlerts`.
+    pub fn with_max_alerts(mut self, max: usize) -> Self {
+        self.max_alerts = Some(max);
+        self
+    }
+
+    /// Sets the initial `AlertElements`.
+    pub fn with_elements(mut self, elements: AlertElements<M>) -> Self {
+        self.elements = Some(elements);
+        self
+    }
+}
+
+/// Inserts a resource configured on an `AlertsPlugin`, unless the app already has one.
+fn insert_configured<R: Resource>(
+    app: &mut App,
+    configured: Option<R>,
+    default: impl FnOnce() -> R,
+) {
+    if app.world().contains_resource::<R>() {
+        if configured.is_some() {
+            warn!(
+                "{} was inserted before its AlertsPlugin was added, so the plugin's value is ignored",
+                bevy::utils::get_short_name(std::any::type_name::<R>())
+            );
         }
+        return;
+    }
+    app.insert_resource(configured.unwrap_or_else(default));
+}
+
+/// Builds the `M` component for the alerts and roots that an `AlertsPlugin<M>` spawns itself,
+/// like follow-ups, piped alerts and self-reported warnings.
+///
+/// The plugin inserts this from `M::default()`, or from the closure given to
+/// `AlertsPlugin::with_marker`.
+#[derive(Resource)]
+pub struct MarkerFactory<M>(Arc<dyn Fn() -> M + Send + Sync>);
+
+impl<M> MarkerFactory<M> {
+    pub fn new(factory: impl Fn() -> M + Send + Sync + 'static) -> Self {
+        Self(Arc::new(factory))
+    }
+
+    pub fn make(&self) -> M {
+        (self.0)()
+    }
+}
+
+impl<M> MarkerFactory<M>
+where
+    M: Component,
+{
+    /// Spawns `bundle` with a marker from the factory, which is read once the commands apply.
+    pub(crate) fn spawn(commands: &mut Commands, bundle: impl Bundle) -> Entity {
+        let entity = commands.spawn_empty().id();
+        commands.add(move |world: &mut World| {
+            let marker = world.resource::<Self>().make();
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity.insert((bundle, marker));
+            }
+        });
+        entity
+    }
+}
+
+impl<M> Clone for MarkerFactory<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
     }
 }
 
@@ -142,260 +580,519 @@ impl AlertsPlugin<AlertMarker> {
     pub fn new() -> Self {
         Default::default()
     }
-
-    /// A PipeableSystem that accepts a vector of alert messages and spawns `Alert`s for each of them.
-    pub fn alert(In(alerts): In<Vec<String>>, mut commands: Commands) {
-        for alert in alerts {
-            commands.spawn((Alert::bundle(alert), AlertMarker));
-        }
-    }
 }
 
 /// A default marker component for use with the default styles.
 #[derive(Debug, Default, Component, Reflect)]
 pub struct AlertMarker;
 
-impl<M> AlertsPlugin<M> {
-    /// A PipeableSystem that accepts a vector of alert messages and spawns `Alert`s for each of them.
-    ///
-    /// Use this if you want to specify your own `AlertMarker`.
-    pub fn custom_alert(In(alerts): In<Vec<String>>, mut commands: Commands)
-    where
-        M: Component + Default + TypePath + Send + Sync + 'static,
-    {
-        for alert in alerts {
-            commands.spawn((Alert::bundle(alert), M::default()));
-        }
-    }
-}
-
 impl<M> Plugin for AlertsPlugin<M>
 where
-    M: Component + Default + TypePath + Send + Sync + 'static,
+    M: Component + TypePath + Send + Sync + 'static,
 {
     fn build(&self, app: &mut App) {
-        app.insert_resource(AlertElements::<M>::new_custom())
-            .insert_resource(AlertLifetime::<M>::new(Duration::from_secs(10)))
-            .insert_resource(MaxAlerts::<M>::new(3))
+        insert_configured(app, self.elements.clone(), AlertElements::<M>::new_custom);
+        insert_configured(app, self.lifetime.map(AlertLifetime::<M>::new), || {
+            AlertLifetime::<M>::new(AlertDefaults::LIFETIME)
+        });
+        insert_configured(app, self.max_alerts.map(MaxAlerts::<M>::new), || {
+            MaxAlerts::<M>::new(AlertDefaults::MAX_ALERTS)
+        });
+        app.insert_resource(self.factory.clone())
+            .insert_resource(AlertEmphasis::<M>::disabled())
+            .insert_resource(CurrentAlertRoot::<M>::default())
+            .insert_resource(AlertRoots::<M>::default())
+            .insert_resource(PendingCount::<M>::default())
+            .init_resource::<AlertExclusionZones<M>>()
+            .init_resource::<AlertDecorators<M>>()
+            .insert_resource(MaxChainDepth::<M>::new(4))
+            .insert_resource(AutoRestyle::<M>::new(true))
+            .insert_resource(DedupAlerts::<M>::new(false))
+            .insert_resource(AlertLogMirror::<M>::new(None))
+            .insert_resource(AlertVisibilityFilter::<M>::new(AlertLevel::Info))
+            .insert_resource(AlertTextLimits::<M>::default())
+            .insert_resource(AlertScaling::<M>::default())
+            .insert_resource(AlertTiming::<M>::default())
+            .insert_resource(AlertTimeScale::<M>::new(1.))
+            .insert_resource(SpilloverAnchor::<M>::new(None))
+            .insert_resource(AlertFontFallback::<M>::default())
+            .insert_resource(MaxConcurrentTransitions::<M>::unlimited())
+            .insert_resource(ReducedMotion::<M>::new(false))
+            .insert_resource(AlertCrossFade::<M>::disabled())
+            .insert_resource(AlertParentPolicy::<M>::new(false))
+            .init_resource::<AlertStatus<M>>()
+            .init_resource::<AlertHistory<M>>()
+            .init_resource::<AlertInputMode<M>>()
+            .insert_resource(AlertInputLockout::<M>::new(AlertDefaults::INPUT_LOCKOUT))
+            .insert_resource(AlertTemplates::<M>::default())
+            .init_resource::<AlertNames>()
+            .init_resource::<AlertSequences>()
+            .add_event::<AlertRootSpawned<M>>()
+            .add_event::<AlertRootDespawned<M>>()
+            .add_event::<AlertEvent<M>>()
+            .add_event::<ClearAlerts<M>>()
+            .add_event::<AlertPromoted<M>>()
+            .add_event::<AlertQueueChanged<M>>()
+            .add_event::<AlertDetailsRequested>()
+            .add_event::<AlertActionEvent>()
+            .add_event::<AlertThresholdReached>()
+            .add_systems(PostUpdate, Self::systems())
+            .observe(Self::mirror_removed_alerts_to_log)
+            .observe(Self::record_alert_interactions)
+            // wheel events are consumed before any `Update` system can read them
             .add_systems(
-                PostUpdate,
-                (
-                    Self::tick_active_alerts,
-                    Self::despawn_alert_root,
-                    Self::tick_transitions,
-                    Self::spawn_alerts,
-                    Self::handle_alert_button_bgs,
-                    Self::handle_dismiss_alert_buttons,
-                )
-                    .chain()
-                    .in_set(AlertSystems),
+                PreUpdate,
+                Self::scroll_alert_lifetimes
+                    .after(bevy::ui::UiSystem::Focus)
+                    .run_if(resource_exists::<AlertLifetimeScroll<M>>)
+                    .run_if(Self::running),
+            )
+            .add_systems(
+                FixedUpdate,
+                Self::count_fixed_ticks
+                    .in_set(AlertSystems)
+                    .run_if(Self::running),
             );
 
+        #[cfg(any(debug_assertions, feature = "diagnostics"))]
+        {
+            if !app.world().contains_resource::<AlertDiagnostics>() {
+                app.init_resource::<AlertDiagnostics>()
+                    .add_systems(Last, AlertDiagnostics::diagnose_unmanaged_alerts);
+            }
+            let managed = app.world_mut().init_component::<AlertManaged<M>>();
+            app.world_mut()
+                .resource_mut::<AlertDiagnostics>()
+                .managed
+                .push(managed);
+            app.add_systems(Last, Self::diagnose_config);
+        }
+
+        #[cfg(feature = "clipboard")]
+        {
+            app.init_resource::<AlertClipboard>()
+                .insert_resource(AlertCopyButtons::<M>::default())
+                .add_systems(
+                    PostUpdate,
+                    (Self::handle_copy_buttons, Self::reset_copy_buttons)
+                        .chain()
+                        .after(Self::handle_dismiss_alert_buttons)
+                        .in_set(AlertSystems),
+                );
+            app.register_type::<AlertCopyButtons<M>>();
+        }
+
         app.register_type::<AlertLifetime<M>>()
             .register_type::<MaxAlerts<M>>()
+            .register_type::<AlertEmphasis<M>>()
+            .register_type::<AutoCollapse<M>>()
+            .register_type::<CurrentAlertRoot<M>>()
+            .register_type::<PendingCount<M>>()
+            .register_type::<AutoRestyle<M>>()
+            .register_type::<DedupAlerts<M>>()
+            .register_type::<AlertLogMirror<M>>()
+            .register_type::<AlertVisibilityFilter<M>>()
+            .register_type::<AlertManaged<M>>()
+            .register_type::<AlertJitter<M>>()
+            .register_type::<AlertTextLimits<M>>()
             .register_type::<AlertTimer>()
+            .register_type::<AlertTiming<M>>()
+            .register_type::<AlertTimeScale<M>>()
+            .register_type::<SpilloverAnchor<M>>()
+            .register_type::<AlertSpilled>()
+            .register_type::<AlertTimeScaleOverride>()
+            .register_type::<MaxConcurrentTransitions<M>>()
+            .register_type::<AlertFadeQueued>()
+            .register_type::<ReducedMotion<M>>()
+            .register_type::<AlertCrossFade<M>>()
+            .register_type::<AlertParentPolicy<M>>()
+            .register_type::<AlertParent>()
+            .register_type::<AlertStatusLine>()
+            .register_type::<AlertOverflowIndicator>()
+            .register_type::<PersistentAlert>()
+            .register_type::<AlertInputMode<M>>()
+            .register_type::<AlertInputLockout<M>>()
+            .register_type::<TypewriterText>()
+            .register_type::<KeepAliveWhile>()
+            .register_type::<AlertLifetimeOverride>()
+            .register_type::<AlertLifetimeScroll<M>>()
+            .register_type::<OnExpireSpawn>()
+            .register_type::<AlertChainDepth>()
+            .register_type::<MaxChainDepth<M>>()
+            .register_type::<AlertLifetimeThresholds>()
+            .register_type::<AlertSource>()
+            .register_type::<AlertGroup>()
+            .register_type::<AlertCompacted>()
+            .register_type::<AlertJitterOffset>()
+            .register_type::<AlertDetails>()
+            .register_type::<CriticalAlert>()
+            .register_type::<AlertLevel>()
+            .register_type::<AlertIcon>()
+            .register_type::<CriticalDisplaced>()
+            .register_type::<AlertDismissReason>()
             .register_type::<AlertTransition>()
             .register_type::<AlertUiRoot>()
+            .register_type::<AlertRootKey>()
+            .register_type::<AlertStackCollapsed>()
+            .register_type::<AlertCollapseBadge>()
+            .register_type::<AlertPending>()
+            .register_type::<AlertState>()
+            .register_type::<AlertSequence>()
+            .register_type::<AlertKey>()
+            .register_type::<AlertRepeatCount>()
+            .register_type::<AlertOccurrences>()
+            .register_type::<AlertInteractions>()
+            .register_type::<AlertRepeatPulse>()
+            .register_type::<AlertRichText>()
+            .register_type::<AlertExpanded>()
+            .register_type::<AlertProgress>()
+            .register_type::<UnresolvedAlert>()
+            .register_type::<AlertActions>()
+            .register_type::<AlertBatch>()
             .register_type::<AlertUi>();
     }
 }
 
 impl<M> AlertsPlugin<M>
 where
-    M: Component + Default + TypePath,
+    M: Component + TypePath,
 {
-    #[allow(clippy::type_complexity)]
-    fn tick_active_alerts(
+    /// The chained `PostUpdate` systems that the plugin schedules for this kind, in
+    /// `AlertSystems`. Most of them pause while the stack is frozen, but events keep queueing
+    /// alerts and the user can still read and dismiss the ones on screen.
+    ///
+    /// The plugin already adds these. Use this to inspect them, or to run the same pipeline in
+    /// another schedule. Each system is named after its full type path, which includes `M`, so
+    /// the systems of different kinds tell apart in traces.
+    pub fn systems() -> SystemConfigs {
+        (
+            // settle the queue before anything is promoted
+            (
+                Self::spawn_alert_events,
+                Self::adopt_alerts.run_if(Self::running),
+                (
+                    Self::merge_duplicate_alerts.run_if(Self::running),
+                    Self::filter_alerts.run_if(Self::running),
+                    Self::clear_alerts.run_if(Self::running),
+                    Self::mirror_alerts_to_log,
+                    Self::timestamp_alerts.run_if(resource_exists::<AlertTimestamps<M>>),
+                ),
+                (
+                    Self::tick_active_alerts,
+                    Self::dismiss_orphaned_alerts,
+                    Self::despawn_alert_root,
+                    Self::limit_fade_outs,
+                    Self::tick_transitions,
+                    Self::scale_alert_elements,
+                    Self::restyle_alerts,
+                    Self::restyle_alert_levels,
+                    Self::spill_over_alerts,
+                    Self::compact_over_budget.run_if(resource_exists::<AlertEntityBudget<M>>),
+                )
+                    .chain()
+                    .run_if(Self::running),
+            )
+                .chain(),
+            // promote alerts, then fill in their parts before the stack is laid out
+            (
+                Self::spawn_alerts.run_if(Self::running),
+                (
+                    Self::record_alert_history,
+                    Self::tick_input_lockouts,
+                    (Self::update_status_line, Self::update_overflow_indicators)
+                        .chain()
+                        .run_if(Self::running),
+                    Self::update_progress_bars.run_if(Self::running),
+                    (
+                        Self::show_alert_timestamps.run_if(resource_exists::<AlertTimestamps<M>>),
+                        Self::show_repeat_counts,
+                        (Self::pulse_repeat_counts, Self::update_alert_spinners)
+                            .run_if(Self::running),
+                    )
+                        .chain(),
+                    (
+                        Self::update_alert_texts,
+                        (Self::cross_fade_alert_texts, Self::reveal_typewriter_text),
+                    )
+                        .chain()
+                        .run_if(Self::running),
+                    Self::fade_lifetime_indicators.run_if(Self::running),
+                ),
+                Self::avoid_exclusion_zones.run_if(Self::running),
+                (
+                    Self::update_group_rows,
+                    (Self::update_dismiss_affordances, Self::dismiss_with_gamepad).chain(),
+                ),
+                Self::fall_back_from_missing_font.run_if(Self::running),
+                Self::handle_alert_button_bgs.run_if(Self::running),
+            )
+                .chain(),
+            // the user can still dismiss alerts while the stack is frozen
+            (
+                Self::handle_dismiss_alert_buttons,
+                (
+                    (
+                        Self::handle_action_buttons,
+                        Self::handle_show_more_buttons,
+                        Self::show_alert_tooltips,
+                    )
+                        .chain(),
+                    Self::track_alert_interactions.run_if(Self::running),
+                ),
+                (
+                    Self::handle_details_buttons,
+                    Self::auto_collapse_stack,
+                    Self::handle_collapse_badge,
+                    Self::update_pending_count,
+                    Self::update_alert_states,
+                )
+                    .chain()
+                    .run_if(Self::running),
+            )
+                .chain(),
+        )
+            .chain()
+            .in_set(AlertSystems)
+    }
+
+    fn running(frozen: Option<Res<AlertsFrozen<M>>>) -> bool {
+        frozen.is_none()
+    }
+
+    /// The other systems only handle adopted alerts, so alerts spawned later in the frame are
+    /// never half promoted.
+    fn adopt_alerts(
         mut commands: Commands,
-        mut spawned_alerts: Query<(Entity, &mut AlertTimer), (With<M>, With<AlertUi>)>,
-        lifetime: Res<AlertLifetime<M>>,
-        time: Res<Time>,
+        alerts: Query<Entity, (With<Alert>, With<M>, Without<AlertManaged<M>>)>,
     ) {
-        for (entity, mut timer) in &mut spawned_alerts {
-            timer.time_alive.tick(time.delta());
-            if timer.time_alive.elapsed() > lifetime.lifetime {
-                commands.entity(entity).insert(AlertTransition::FadeOut);
-            }
+        for entity in &alerts {
+            commands.entity(entity).insert(AlertManaged::<M>::default());
+        }
+    }
+
+    fn update_pending_count(
+        pending: Query<(), (With<AlertManaged<M>>, With<AlertPending>)>,
+        mut pending_count: ResMut<PendingCount<M>>,
+        mut queue_changed: EventWriter<AlertQueueChanged<M>>,
+    ) {
+        // report the net change once per frame, however many systems touched the queue
+        let len = pending.iter().count();
+        if len != pending_count.0 {
+            pending_count.0 = len;
+            queue_changed.send(AlertQueueChanged {
+                len,
+                marker: PhantomData,
+            });
         }
     }
 
-    fn tick_transitions(
+    #[allow(clippy::type_complexity)]
+    fn tick_active_alerts(
         mut commands: Commands,
-        mut alert_nodes: Query<
+        mut spawned_alerts: Query<
             (
                 Entity,
-                &mut Style,
-                &AlertTransition,
-                Option<&mut TransitionTimer>,
+                &mut AlertTimer,
+                Option<&CriticalAlert>,
+                Option<&mut AlertLifetimeThresholds>,
+                Option<&KeepAliveWhile>,
+                Option<&OnExpireSpawn>,
+                Option<&AlertChainDepth>,
+                Option<&AlertTimeScaleOverride>,
+                Option<&AlertLifetimeOverride>,
+                Has<AlertExpanded>,
+                Option<&AlertProgress>,
+                Has<UnresolvedAlert>,
+            ),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUi>,
+                Without<PersistentAlert>,
             ),
-            With<AlertUi>,
         >,
+        max_chain_depth: Res<MaxChainDepth<M>>,
+        entities: &Entities,
+        collapsed_roots: Query<
+            (),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUiRoot>,
+                With<AlertStackCollapsed>,
+            ),
+        >,
+        lifetime: Res<AlertLifetime<M>>,
+        timing: Res<AlertTiming<M>>,
+        time_scale: Res<AlertTimeScale<M>>,
         time: Res<Time>,
+        mut thresholds_reached: EventWriter<AlertThresholdReached>,
     ) {
-        for (entity, mut style, transition, timer) in &mut alert_nodes {
-            let time = if let Some(mut timer) = timer {
-                timer.tick(time.delta());
-                timer.get_completion()
-            } else {
-                let mut timer = TransitionTimer::default();
-                timer.tick(time.delta());
-                let time = timer.get_completion();
-                commands.entity(entity).insert(timer);
-                time
-            };
-
-            fn ease(t: f32) -> f32 {
-                if t > 1. {
-                    1.
-                } else if t < 0. {
-                    0.
-                } else {
-                    1. - (std::f32::consts::PI * t).cos()
-                }
+        // nothing may expire unseen while the stack is collapsed
+        if !collapsed_roots.is_empty() {
+            return;
+        }
+        for (
+            entity,
+            mut timer,
+            critical,
+            thresholds,
+            keep_alive,
+            on_expire,
+            chain_depth,
+            scale_override,
+            lifetime_override,
+            expanded,
+            progress,
+            unresolved,
+        ) in &mut spawned_alerts
+        {
+            let lifetime = lifetime_override.map_or(lifetime.lifetime, |value| value.0);
+            // expanded alerts wait for the user to finish reading, unresolved ones for their task
+            if critical.is_some_and(|critical| critical.sticky) || expanded || unresolved {
+                continue;
             }
-
-            let left = ease(match transition {
-                AlertTransition::FadeIn => 1. - time,
-                AlertTransition::FadeOut => time,
-            });
-            style.left = Val::Percent(left * 100.);
-
-            if time >= 1. {
-                match transition {
-                    AlertTransition::FadeIn => {
-                        commands
-                            .entity(entity)
-                            .remove::<(AlertTransition, TransitionTimer)>();
+            timer
+                .time_alive
+                .tick(time_scale.scale_delta(time.delta(), scale_override));
+            if keep_alive.is_some_and(|keep_alive| entities.contains(keep_alive.0)) {
+                timer.touch();
+            }
+            // the lifetime starts once the operation is done
+            if progress.is_some_and(|progress| !progress.is_complete()) {
+                timer.touch();
+            }
+            if let Some(mut thresholds) = thresholds {
+                // a large tick may cross several thresholds, which are reported in order
+                let fraction = match timing.clock {
+                    AlertClock::Time => {
+                        timer.time_alive.elapsed().as_secs_f32() / lifetime.as_secs_f32()
                     }
-                    AlertTransition::FadeOut => {
-                        commands.entity(entity).despawn_recursive();
+                    AlertClock::FixedTicks(ticks) => timer.fixed_ticks as f32 / ticks as f32,
+                };
+                while let Some(&next) = thresholds.fractions.get(thresholds.reached) {
+                    if next > fraction {
+                        break;
                     }
+                    thresholds.reached += 1;
+                    thresholds_reached.send(AlertThresholdReached {
+                        entity,
+                        fraction: next,
+                    });
                 }
             }
+            let expired = match timing.clock {
+                AlertClock::Time => timer.time_alive.elapsed() > lifetime,
+                AlertClock::FixedTicks(ticks) => timer.fixed_ticks >= ticks,
+            };
+            if expired {
+                commands.add(DismissAlert::<M>::new(entity, AlertDismissReason::Expired));
+
+                let Some(on_expire) = on_expire else {
+                    continue;
+                };
+                commands.entity(entity).remove::<OnExpireSpawn>();
+                // follow-ups stop at the depth cap so templates cannot loop forever
+                let depth = chain_depth.map_or(0, |depth| depth.0) + 1;
+                if depth > max_chain_depth.depth {
+                    continue;
+                }
+                let on_expire = on_expire.clone();
+                commands.add(move |world: &mut World| {
+                    let follow_up = match on_expire {
+                        OnExpireSpawn::Message(message) => {
+                            let marker = world.resource::<MarkerFactory<M>>().make();
+                            world.spawn((Alert::bundle(message), marker)).id()
+                        }
+                        OnExpireSpawn::Template { id, args } => {
+                            AlertTemplates::<M>::spawn(world, &id, &args)
+                        }
+                    };
+                    world.entity_mut(follow_up).insert(AlertChainDepth(depth));
+                });
+            }
         }
     }
 
-    #[allow(clippy::type_complexity)]
-    fn despawn_alert_root(
-        mut commands: Commands,
-        spawned_alerts: Query<Entity, (With<M>, With<AlertUi>)>,
-        alerts_to_spawn: Query<(Entity, &Alert), (With<M>, Without<AlertUi>)>,
-        alerts_ui_root: Query<Entity, (With<M>, With<AlertUiRoot>)>,
-    ) where
-        M: Component + Send + Sync + 'static,
-    {
-        let num_live_alerts = spawned_alerts.iter().count();
-        let num_unspawned_alerts = alerts_to_spawn.iter().count();
-
-        // if there are no alerts, remove any roots
-        if num_unspawned_alerts + num_live_alerts == 0 && !alerts_ui_root.is_empty() {
-            // This is fine as long as this plugin guarantees to only create one root at a time.
-            let entity = alerts_ui_root.single();
-            commands.entity(entity).despawn_recursive();
-        }
-    }
-
-    #[allow(clippy::type_complexity)]
-    fn spawn_alerts(
-        mut commands: Commands,
-        spawned_alerts: Query<Entity, (With<M>, With<AlertUi>)>,
-        alerts_to_spawn: Query<(Entity, &Alert), (With<M>, Without<AlertUi>)>,
-        alerts_ui_root: Query<Entity, (With<M>, With<AlertUiRoot>)>,
-        max_alerts: Res<MaxAlerts<M>>,
-        alert_nodes: Res<AlertElements<M>>,
-    ) where
-        M: Component + Send + Sync + 'static,
-    {
-        let num_live_alerts = spawned_alerts.iter().count();
-        let num_alert_spaces = max_alerts.saturating_sub(num_live_alerts);
-        let num_unspawned_alerts = alerts_to_spawn.iter().count();
-
-        if num_unspawned_alerts + num_live_alerts == 0 {
+    fn count_fixed_ticks(
+        mut spawned_alerts: Query<
+            (&mut AlertTimer, Option<&CriticalAlert>),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUi>,
+                Without<PersistentAlert>,
+            ),
+        >,
+        collapsed_roots: Query<
+            (),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUiRoot>,
+                With<AlertStackCollapsed>,
+            ),
+        >,
+        timing: Res<AlertTiming<M>>,
+    ) {
+        let AlertClock::FixedTicks(ticks) = timing.clock else {
             return;
-        }
-
-        // if there are alerts and no root, add one first
-        let root = if alerts_ui_root.is_empty() {
-            // this is where we promise to only ever spawn one
-            commands
-                .spawn((
-                    AlertUiRoot,
-                    Name::new("Alert UI Root"),
-                    alert_nodes.root().clone(),
-                    M::default(),
-                ))
-                .id()
-        } else {
-            // otherwise get the root
-            alerts_ui_root.single()
         };
-
-        // spawn any alerts that we can
-        for (entity, alert) in alerts_to_spawn.iter().take(num_alert_spaces) {
-            let mut alert_node = alert_nodes.alert().clone();
-            // set the left position to a 100% offset at first
-            alert_node.style.left = Val::Percent(100.);
-            commands
-                .entity(entity)
-                .insert((AlertUi, alert_node, AlertTransition::FadeIn, M::default()))
-                .with_children(|builder| {
-                    builder
-                        .spawn((Name::new("Alert Header UI"), alert_nodes.header().clone()))
-                        .with_children(|builder| {
-                            builder
-                                .spawn(AlertUi::dismiss_button(entity))
-                                .with_children(|builder| {
-                                    builder.spawn(AlertUi::dismiss_text());
-                                });
-                        });
-                    builder
-                        .spawn((Name::new("Alert Body UI"), alert_nodes.body().clone()))
-                        .with_children(|builder| {
-                            builder.spawn(AlertUi::text(
-                                alert.message.clone(),
-                                alert_nodes.text().clone(),
-                            ));
-                        });
-                });
-            commands.entity(root).add_child(entity);
+        if !collapsed_roots.is_empty() {
+            return;
         }
-    }
-
-    fn handle_alert_button_bgs(
-        mut dismiss_buttons: Query<(&Interaction, &mut BackgroundColor), With<DismissButton>>,
-    ) {
-        for (interaction, mut bg_color) in &mut dismiss_buttons {
-            bg_color.0 = match interaction {
-                Interaction::Pressed => Color::Srgba(bevy::color::palettes::css::DARK_GRAY),
-                Interaction::Hovered => Color::srgb(0.4, 0.4, 0.4),
-                Interaction::None => Color::srgb(0.35, 0.35, 0.35),
-            };
+        for (mut timer, critical) in &mut spawned_alerts {
+            if critical.is_some_and(|critical| critical.sticky) {
+                continue;
+            }
+            // saturate so that an alert expires on exactly its last tick, however many fixed
+            // steps run before the next frame
+            timer.fixed_ticks = (timer.fixed_ticks + 1).min(ticks);
         }
     }
 
-    fn handle_dismiss_alert_buttons(
-        mut commands: Commands,
-        dismiss_buttons: Query<(&Interaction, &DismissButton)>,
-    ) {
-        for (interaction, button) in &dismiss_buttons {
-            if matches!(interaction, Interaction::Pressed) {
-                commands
-                    .entity(button.alert)
-                    .remove::<(AlertTransition, TransitionTimer)>();
-                commands
-                    .entity(button.alert)
-                    .insert(AlertTransition::FadeOut);
-            }
+    #[allow(clippy::type_complexity)]
+    fn update_alert_states(
+        mut alerts: Query<
+            (
+                &mut AlertState,
+                Has<AlertPending>,
+                Has<AlertUi>,
+                Option<&AlertTransition>,
+                Has<AlertFadeQueued>,
+            ),
+            With<AlertManaged<M>>,
+        >,
+    ) {
+        for (mut state, pending, displayed, transition, fade_queued) in &mut alerts {
+            debug_assert!(
+                !(pending && displayed),
+                "an alert cannot be both pending and displayed"
+            );
+            let next = match transition {
+                _ if !displayed => AlertState::Pending,
+                _ if fade_queued => AlertState::WaitingToLeave,
+                Some(AlertTransition::FadeIn) => AlertState::Entering,
+                Some(AlertTransition::FadeOut | AlertTransition::FadeOutToward(_)) => {
+                    AlertState::Leaving
+                }
+                None => AlertState::Visible,
+            };
+            state.set_if_neq(next);
         }
     }
 }
 
 /// The `SystemSet` in which alerts-related systems are run.
+///
+/// Alerts spawned while these systems run, such as by an observer of an alert's
+/// `AlertDismissReason` or as an `OnExpireSpawn`, stay queued until the next run, exactly like
+/// alerts spawned in `Update`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, SystemSet)]
 pub struct AlertSystems;
 
 /// A wrapper for the Duration that Alerts of this kind stay alive before transitioning out of
 /// the scene.
+///
+/// This can be changed at runtime: visible alerts compare their elapsed time against the new value
+/// on the next tick, so shortening the lifetime may expire them immediately and lengthening it
+/// extends them.
 #[derive(Debug, Resource, Reflect)]
 pub struct AlertLifetime<M: TypePath> {
     lifetime: Duration,
@@ -414,6 +1111,34 @@ where
             marker: PhantomData::<M>,
         }
     }
+
+    pub fn get(&self) -> Duration {
+        self.lifetime
+    }
+
+    pub fn set(&mut self, lifetime: Duration) {
+        self.lifetime = lifetime;
+    }
+}
+
+impl<M> std::ops::Deref for AlertLifetime<M>
+where
+    M: TypePath,
+{
+    type Target = Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.lifetime
+    }
+}
+
+impl<M> std::ops::DerefMut for AlertLifetime<M>
+where
+    M: TypePath,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.lifetime
+    }
 }
 
 /// The maximum number of Alert UI nodes that can be shown in the UI at once.
@@ -447,206 +1172,689 @@ where
     }
 }
 
-/// A type collecting the UI styles and presentational logic of each possible alert UI element.
-///
-/// Override this resource to restyle the alert UI elements.
-#[derive(Debug, Resource)]
-pub struct AlertElements<M = AlertMarker> {
-    /// The UI root node specification. Use this to frame the layer.
-    ///
-    /// The default view is an inner crop of the window space.
-    /// The default ZIndex is 1000 to overlay all other content.
-    pub root: NodeBundle,
-    /// The alert node specification. This is the "card" for the alert.
-    pub alert: NodeBundle,
-    /// The header node specification for the alert, which also renders the dismiss button.
-    pub header: NodeBundle,
-    /// The body node specification for the alert, which has the text as child.
-    pub body: NodeBundle,
-    /// The style spec for the body text of the alert.
-    pub text: TextStyle,
-    /// A marker for supporting multiple alert styles.
-    pub marker: PhantomData<M>,
-}
-
-impl AlertElements<AlertMarker> {
-    pub fn new() -> Self {
-        Self::corner_popup(DEFAULT_ALERT_HEIGHT)
+/// An event sent when a pending alert of this kind is promoted into the UI.
+#[derive(Event)]
+pub struct AlertPromoted<M: Component> {
+    pub entity: Entity,
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertPromoted<M>
+where
+    M: Component,
+{
+    fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            marker: PhantomData,
+        }
     }
 }
 
-impl<M> AlertElements<M> {
-    pub fn new_custom() -> Self {
-        Self::corner_popup(DEFAULT_ALERT_HEIGHT)
+/// The number of alerts of this kind waiting for a slot, as of the end of the plugin's systems.
+#[derive(Debug, Resource, Reflect)]
+pub struct PendingCount<M: TypePath>(usize, #[reflect(ignore)] PhantomData<M>);
+
+impl<M> PendingCount<M>
+where
+    M: TypePath,
+{
+    pub fn get(&self) -> usize {
+        self.0
     }
+}
 
-    /// Builds an AlertElements that styles the alerts like a typical corner "toast" pop-up.
-    pub fn corner_popup(alert_height: f32) -> Self {
-        AlertElements {
-            root: NodeBundle {
-                style: Style {
-                    position_type: PositionType::Absolute,
-                    left: Val::Percent(70.),
-                    right: Val::Px(24.),
-                    bottom: Val::Px(24.),
-                    max_height: Val::Percent(60.),
-                    display: Display::Flex,
-                    flex_direction: FlexDirection::Column,
-                    justify_content: JustifyContent::FlexEnd,
-                    align_items: AlignItems::FlexEnd,
-                    row_gap: Val::Px(8.),
-                    ..Default::default()
-                },
-                background_color: Color::srgba(0., 0., 0., 0.).into(),
-                z_index: ZIndex::Local(ALERT_Z_INDEX),
-                ..Default::default()
-            },
-            alert: NodeBundle {
-                style: Style {
-                    flex_direction: FlexDirection::Column,
-                    align_items: AlignItems::FlexStart,
-                    justify_content: JustifyContent::FlexStart,
-                    width: Val::Percent(80.),
-                    min_height: Val::Px(alert_height),
-                    border: UiRect::all(Val::Px(2.)),
-                    ..Default::default()
-                },
-                background_color: Color::Srgba(bevy::color::palettes::css::ALICE_BLUE).into(),
-                border_color: Color::Srgba(bevy::color::palettes::css::DARK_GRAY).into(),
-                ..Default::default()
-            },
-            header: NodeBundle {
-                style: Style {
-                    justify_content: JustifyContent::FlexEnd,
-                    width: Val::Percent(100.),
-                    height: Val::Px(20.),
-                    ..Default::default()
-                },
-                background_color: Color::srgba(0., 0.8, 0.8, 0.8).into(),
-                ..Default::default()
-            },
-            body: NodeBundle {
-                style: Style {
-                    flex_grow: 1.,
-                    padding: UiRect::all(Val::Px(4.)),
-                    width: Val::Percent(100.),
-                    ..Default::default()
-                },
-                ..Default::default()
-            },
-            text: TextStyle {
-                font_size: 24.,
-                color: Color::BLACK,
-                ..Default::default()
-            },
-            ..Default::default()
+impl<M> Default for PendingCount<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self(0, PhantomData::<M>)
+    }
+}
+
+/// An event sent at most once per frame when `PendingCount` changes, whether alerts were queued,
+/// promoted, or cancelled.
+#[derive(Event)]
+pub struct AlertQueueChanged<M: Component> {
+    pub len: usize,
+    marker: PhantomData<M>,
+}
+
+/// A small, reproducible horizontal offset applied to each card as it is spawned, which helps
+/// convey that several similar alerts happened.
+///
+/// Offsets are drawn uniformly from `-amplitude..amplitude` using a seeded generator, so the same
+/// seed and spawn sequence always produce the same layout. Each card records its offset in an
+/// `AlertJitterOffset`. This resource is not inserted by default.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertJitter<M: TypePath> {
+    pub amplitude: f32,
+    seed: u64,
+    state: u64,
+    #[reflect(ignore)]
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertJitter<M>
+where
+    M: TypePath,
+{
+    pub fn new(amplitude: f32, seed: u64) -> Self {
+        Self {
+            amplitude,
+            seed,
+            state: seed,
+            marker: PhantomData::<M>,
         }
     }
 
-    pub fn root(&self) -> &NodeBundle {
-        &self.root
+    pub fn seed(&self) -> u64 {
+        self.seed
     }
 
-    pub fn alert(&self) -> &NodeBundle {
-        &self.alert
+    /// Restarts the offset sequence from the seed.
+    pub fn reset(&mut self) {
+        self.state = self.seed;
     }
 
-    pub fn header(&self) -> &NodeBundle {
-        &self.header
+    fn next_offset(&mut self) -> f32 {
+        // splitmix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        let unit = (z >> 40) as f32 / (1u64 << 24) as f32;
+        (2. * unit - 1.) * self.amplitude
+    }
+}
+
+/// A marker copmonent for the root node of the alerts UI.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertUiRoot;
+
+/// Marks an alert as critical: it is pinned to the top of the stack in its own slot, which does
+/// not count towards `MaxAlerts` and is never taken by normal alerts.
+///
+/// Only one critical alert is visible at a time. A newer critical alert takes the slot and the
+/// older one returns to the queue until the slot is free again. Critical alerts are sticky by
+/// default, meaning they stay until they are dismissed.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct CriticalAlert {
+    pub sticky: bool,
+}
+
+impl Default for CriticalAlert {
+    fn default() -> Self {
+        Self { sticky: true }
     }
+}
+
+/// A small image shown at the left edge of an alert's body, styled by `AlertElements::icon`.
+#[derive(Clone, Debug, Component, Reflect)]
+pub struct AlertIcon(pub Handle<Image>);
+
+/// How severe an alert is. Alerts without one are `Info`. Levels are ordered from least to most
+/// severe.
+///
+/// Levels with an entry in `AlertElements::level_colors` recolor the card and header, including
+/// on shown alerts whose level changes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Component, Reflect)]
+pub enum AlertLevel {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+/// A marker component for a `CriticalAlert` that gave up the priority slot to a newer one, and
+/// returns once the slot is free.
+#[derive(Debug, Component, Reflect)]
+pub struct CriticalDisplaced;
+
+/// A marker component for an alerts UI root that is currently collapsed into a badge.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertStackCollapsed;
+
+/// A marker component for the badge shown while the alert stack is collapsed.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertCollapseBadge;
+
+/// A marker component for the unread count text of the collapse badge.
+#[derive(Debug, Component)]
+pub struct AlertCollapseBadgeText;
+
+/// The persistent status line of this kind, shown in its own slot below the stack.
+///
+/// Set it with `AlertCommandsExt::set_status` and remove it with `clear_status`. A new status
+/// replaces the text in place. The status line never counts toward `MaxAlerts`, and keeps the
+/// primary root alive while set, even with no alerts. Style it with `AlertElements::status`.
+#[derive(Debug, Resource)]
+pub struct AlertStatus<M> {
+    pub(crate) message: Option<String>,
+    pub(crate) entity: Option<Entity>,
+    marker: PhantomData<M>,
+}
 
-    pub fn body(&self) -> &NodeBundle {
-        &self.body
+impl<M> AlertStatus<M> {
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
     }
 
-    pub fn text(&self) -> &TextStyle {
-        &self.text
+    /// The node of the status line, once it is shown.
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
     }
 }
 
-impl<M> Default for AlertElements<M> {
+impl<M> Default for AlertStatus<M> {
     fn default() -> Self {
         Self {
-            root: Default::default(),
-            alert: Default::default(),
-            header: Default::default(),
-            body: Default::default(),
-            text: Default::default(),
-            marker: Default::default(),
+            message: None,
+            entity: None,
+            marker: PhantomData::<M>,
         }
     }
 }
 
-/// A marker copmonent for the root node of the alerts UI.
+/// A marker component for the node of the `AlertStatus` line.
 #[derive(Debug, Component, Reflect)]
-pub struct AlertUiRoot;
+pub struct AlertStatusLine;
 
-/// A timer that tracks the current lifetime
+/// A marker component for the "+N more" line at the bottom of a root, shown while more alerts are
+/// queued for it than `MaxAlerts` lets in. It never counts toward `MaxAlerts` itself. Style it
+/// with `AlertElements::overflow`.
 #[derive(Debug, Component, Reflect)]
-pub struct AlertTimer {
-    time_alive: Stopwatch,
-}
+pub struct AlertOverflowIndicator;
+
+/// A marker for alerts that never expire, such as unrecoverable errors. They stay until they
+/// are dismissed or cleared, and keep their root alive meanwhile.
+#[derive(Debug, Default, Component, Reflect)]
+pub struct PersistentAlert;
 
-/// A flag that determines how the Alert transitions in and out of the UI.
+/// How many times an alert was raised, counting the duplicates merged into it by `DedupAlerts`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub struct AlertRepeatCount(pub u32);
+
+/// A short pop of the `AlertRepeatCount` badge of a shown alert that was just repeated, so the
+/// repeat is noticed even though no card appeared. Skipped under `ReducedMotion`.
 #[derive(Clone, Debug, Component, Reflect)]
-pub enum AlertTransition {
-    FadeIn,
-    FadeOut,
+pub struct AlertRepeatPulse(pub(crate) TransitionTimer);
+
+impl Default for AlertRepeatPulse {
+    fn default() -> Self {
+        Self(TransitionTimer::with_duration(AlertDefaults::REPEAT_PULSE))
+    }
+}
+
+/// The message of an alert as sections of text with their own styles, such as a file name in bold
+/// within an error.
+///
+/// The sections are laid out in place of the plain message while the `Alert` message still
+/// matches them, and keep their styles when `AlertElements` change. `Alert::rich` sets the message
+/// to the sections joined together; replacing the message afterwards shows it as plain text.
+/// `AlertTextLimits` do not apply, and a `TypewriterText` reveal uses the style of the first
+/// section throughout.
+#[derive(Clone, Debug, Default, Component, Reflect)]
+pub struct AlertRichText(pub Vec<(String, TextStyle)>);
+
+impl AlertRichText {
+    /// The sections joined into plain text.
+    pub fn message(&self) -> String {
+        self.0.iter().map(|(value, _)| value.as_str()).collect()
+    }
+
+    pub(crate) fn sections(&self) -> Vec<TextSection> {
+        self.0
+            .iter()
+            .map(|(value, style)| TextSection::new(value.clone(), style.clone()))
+            .collect()
+    }
+}
+
+/// The completion of a long-running operation that an alert reports on, from `0.` to `1.`.
+///
+/// Alerts with progress show a slim bar below their body that follows the fraction. Their lifetime
+/// only starts counting once the fraction reaches `1.`. Update it in place, or with
+/// `AlertCommandsExt::set_alert_progress` or `set_keyed_alert_progress`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Component, Reflect)]
+pub struct AlertProgress(pub f32);
+
+impl AlertProgress {
+    pub fn fraction(&self) -> f32 {
+        self.0.clamp(0., 1.)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.0 >= 1.
+    }
+
+    pub(crate) fn bar_width(&self) -> Val {
+        Val::Percent(self.fraction() * 100.)
+    }
 }
 
-/// A timer for AlertTransitions.
+/// A marker for alerts about a task that has not finished yet, such as "Saving…".
+///
+/// An unresolved alert shows a spinner in its header and does not expire. Resolve it with
+/// `AlertCommandsExt::resolve_alert`, which swaps in the outcome and starts its lifetime. Not to be
+/// confused with `AlertPending`, which marks alerts still waiting for a slot.
 #[derive(Debug, Default, Component, Reflect)]
-pub struct TransitionTimer {
-    time_alive: Stopwatch,
+pub struct UnresolvedAlert;
+
+/// The spinning glyph in the header of an `UnresolvedAlert`.
+#[derive(Component)]
+pub struct AlertSpinner(Timer);
+
+impl AlertSpinner {
+    pub const FRAMES: [&'static str; 4] = ["|", "/", "-", "\\"];
+    pub const FRAME_DURATION: Duration = Duration::from_millis(100);
+}
+
+impl Default for AlertSpinner {
+    fn default() -> Self {
+        Self(Timer::new(Self::FRAME_DURATION, TimerMode::Repeating))
+    }
+}
+
+/// A key that deduplicates alerts of the same kind.
+///
+/// An alert queued with the key of an alert that is still pending or shown is merged into it
+/// instead: the existing alert takes the new message and its `AlertTimer` restarts. Alerts that are
+/// already leaving do not take new messages, so the next one is shown anew. While `DedupAlerts` is
+/// enabled, alerts without a key are matched by their message the same way.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Component, Reflect)]
+pub struct AlertKey(pub String);
+
+impl AlertKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+}
+
+/// A lifetime for a single alert that replaces its kind's `AlertLifetime`.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct AlertLifetimeOverride(pub Duration);
+
+/// Keeps an alert alive for as long as the given entity exists. Once it is despawned, the alert
+/// expires one `AlertLifetime` later.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct KeepAliveWhile(pub Entity);
+
+/// A follow-up alert to spawn when this alert expires, with the same marker.
+///
+/// Dismissed alerts do not spawn their follow-up. Follow-ups may have their own, up to
+/// `MaxChainDepth` links.
+#[derive(Clone, Debug, Component, Reflect)]
+pub enum OnExpireSpawn {
+    Message(String),
+    /// An alert from the `AlertTemplates` of the same marker.
+    Template {
+        id: Cow<'static, str>,
+        args: Vec<String>,
+    },
+}
+
+/// How many expiries led to this alert through `OnExpireSpawn`.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct AlertChainDepth(pub u32);
+
+/// The longest chain of `OnExpireSpawn` follow-ups allowed for this kind of alert.
+#[derive(Debug, Resource, Reflect)]
+pub struct MaxChainDepth<M: TypePath> {
+    pub depth: u32,
+    #[reflect(ignore)]
+    marker: PhantomData<M>,
+}
+
+impl<M> MaxChainDepth<M>
+where
+    M: TypePath,
+{
+    pub fn new(depth: u32) -> Self {
+        Self {
+            depth,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// A list of related items rendered as rows in the body of a single alert, below its message.
+///
+/// Resolving an item removes its row. Once every item is resolved, the alert is dismissed.
+#[derive(Clone, Debug, Component, Reflect)]
+pub struct AlertGroup {
+    items: Vec<String>,
+    max_rows: Option<usize>,
+}
+
+impl AlertGroup {
+    pub fn new(items: Vec<String>) -> Self {
+        Self {
+            items,
+            max_rows: None,
+        }
+    }
+
+    /// Limits the rows shown. Any further items are summarized as "…and N more".
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Removes the item at `index`, shrinking the card, and returns it if it existed.
+    pub fn resolve_item(&mut self, index: usize) -> Option<String> {
+        (index < self.items.len()).then(|| self.items.remove(index))
+    }
+}
+
+/// A longer diagnostic attached to an alert.
+///
+/// The payload is not rendered in the toast. Instead, the header gains a "Details" button which
+/// sends an `AlertDetailsRequested` event, so that the application can show it in its own UI.
+#[derive(Clone, Debug, Component, Reflect)]
+pub struct AlertDetails(pub String);
+
+/// Buttons in a footer row of the alert, such as "Retry" or "Open folder".
+///
+/// Pressing one sends an `AlertActionEvent` with the id of its `AlertAction`, and then dismisses
+/// the alert unless the action was made with `keep_open`.
+#[derive(Clone, Debug, Default, Component, Reflect)]
+pub struct AlertActions(pub Vec<AlertAction>);
+
+/// One of the `AlertActions` of an alert.
+#[derive(Clone, Debug, PartialEq, Eq, Reflect)]
+pub struct AlertAction {
+    pub id: String,
+    pub label: String,
+    pub dismisses: bool,
+}
+
+impl AlertAction {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            dismisses: true,
+        }
+    }
+
+    /// Keeps the alert on screen when this action is pressed.
+    pub fn keep_open(mut self) -> Self {
+        self.dismisses = false;
+        self
+    }
+}
+
+/// The horizontal offset, in pixels, that `AlertJitter` applied to this alert's card.
+#[derive(Clone, Copy, Debug, PartialEq, Component, Reflect)]
+pub struct AlertJitterOffset(pub f32);
+
+/// Fractions of the `AlertLifetime` at which an `AlertThresholdReached` event is sent for this
+/// alert, for example to escalate it before it expires.
+#[derive(Clone, Debug, Component, Reflect)]
+pub struct AlertLifetimeThresholds {
+    fractions: Vec<f32>,
+    reached: usize,
 }
 
-impl TransitionTimer {
-    pub const DURATION: Duration = Duration::from_millis(500);
+impl AlertLifetimeThresholds {
+    pub fn new(fractions: impl IntoIterator<Item = f32>) -> Self {
+        let mut fractions = fractions.into_iter().collect::<Vec<_>>();
+        fractions.sort_by(f32::total_cmp);
+        Self {
+            fractions,
+            reached: 0,
+        }
+    }
 
-    fn get_completion(&self) -> f32 {
-        (self.time_alive.elapsed().as_secs_f32() / Self::DURATION.as_secs_f32())
-            .max(0.)
-            .min(1.)
+    pub fn fractions(&self) -> &[f32] {
+        &self.fractions
     }
+}
+
+/// An event sent once for each of an alert's `AlertLifetimeThresholds` when its elapsed lifetime
+/// reaches it.
+#[derive(Clone, Copy, Debug, PartialEq, Event)]
+pub struct AlertThresholdReached {
+    pub entity: Entity,
+    pub fraction: f32,
+}
+
+/// A marker for the entities that the `AlertsPlugin<M>` manages: its roots, badges, and alerts.
+///
+/// The plugin only queries for this marker, never for bare `M`, so other entities that happen to
+/// carry `M` are left alone. Entities with both `Alert` and `M` are adopted automatically.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertManaged<M: TypePath>(#[reflect(ignore)] PhantomData<M>);
 
-    fn tick(&mut self, delta: Duration) {
-        self.time_alive.tick(delta);
+impl<M> Default for AlertManaged<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self(PhantomData::<M>)
     }
 }
 
+/// A marker component for Alerts that are queued and waiting for a slot in the UI.
+///
+/// Alerts move through three states: pending (`AlertPending`), displayed (`AlertUi`), and removing
+/// (`AlertUi` with `AlertTransition::FadeOut`) until the entity is despawned. A displaced
+/// `CriticalAlert` returns to pending.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertPending;
+
+/// Where an alert is in its life, kept consistent with its other components at the end of
+/// `AlertSystems`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub enum AlertState {
+    /// Waiting for a slot, marked `AlertPending`.
+    Pending,
+    /// Sliding into the UI.
+    Entering,
+    /// Shown and settled.
+    Visible,
+    /// Dismissed or expired, but waiting its turn under `MaxConcurrentTransitions`.
+    WaitingToLeave,
+    /// Sliding out of the UI, to be despawned once it finishes.
+    Leaving,
+}
+
 /// A marker component for Alerts that have UI components added and children spawned.
+///
+/// See `AlertPending` for the full lifecycle.
 #[derive(Debug, Component, Reflect)]
 pub struct AlertUi;
 
 impl AlertUi {
-    fn text(message: String, style: TextStyle) -> impl Bundle {
+    fn text(message: String, style: TextStyle, linebreak: BreakLineOn) -> impl Bundle {
+        Self::text_node(TextBundle::from_section(message, style), linebreak)
+    }
+
+    fn rich_text(rich: &AlertRichText, linebreak: BreakLineOn) -> impl Bundle {
+        Self::text_node(TextBundle::from_sections(rich.sections()), linebreak)
+    }
+
+    /// Text that wraps within the width of the card, so long messages grow the card downwards.
+    fn text_node(mut text: TextBundle, linebreak: BreakLineOn) -> impl Bundle {
+        text.text.linebreak_behavior = linebreak;
+        text.style.max_width = Val::Percent(100.);
+        text.style.min_width = Val::Px(0.);
+        text.style.flex_shrink = 1.;
+        (Name::new("Alert Text"), AlertText, text)
+    }
+
+    fn icon(image: Handle<Image>, style: Style) -> impl Bundle {
         (
-            Name::new("Alert Text"),
-            TextBundle::from_section(message, style),
+            Name::new("Alert Icon"),
+            ImageBundle {
+                style,
+                image: UiImage::new(image),
+                ..Default::default()
+            },
         )
     }
 
-    fn dismiss_button(parent: Entity) -> impl Bundle {
+    fn group_rows(parent: Entity) -> impl Bundle {
         (
-            Name::new("Dismiss Button"),
-            ButtonBundle {
+            Name::new("Alert Group Rows"),
+            NodeBundle {
                 style: Style {
-                    width: Val::Px(22.),
-                    height: Val::Percent(100.),
-                    padding: UiRect::px(2., 2., 2., 4.),
-                    align_self: AlignSelf::FlexEnd,
-                    align_items: AlignItems::Center,
-                    justify_content: JustifyContent::Center,
+                    flex_direction: FlexDirection::Column,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            AlertGroupRows { alert: parent },
+        )
+    }
+
+    /// The title, clipped to the space the header buttons leave it rather than pushing them out.
+    fn title(title: String, style: TextStyle) -> (impl Bundle, impl Bundle) {
+        (
+            (
+                Name::new("Alert Title Clip"),
+                NodeBundle {
+                    style: Style {
+                        flex_grow: 1.,
+                        flex_shrink: 1.,
+                        min_width: Val::Px(0.),
+                        padding: UiRect::horizontal(Val::Px(4.)),
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::clip(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ),
+            (
+                Name::new("Alert Title"),
+                AlertTitle,
+                TextBundle::from_section(title, style).with_no_wrap(),
+            ),
+        )
+    }
+
+    fn details_button(parent: Entity, node: NodeBundle) -> impl Bundle {
+        (
+            Name::new("Details Button"),
+            node,
+            Button,
+            Interaction::default(),
+            DetailsButton { alert: parent },
+        )
+    }
+
+    fn details_text() -> impl Bundle {
+        (
+            Name::new("Details Button Text"),
+            TextBundle::from_section(
+                "Details",
+                TextStyle {
+                    font_size: 14.,
+                    color: Color::WHITE,
+                    ..Default::default()
+                },
+            ),
+        )
+    }
+
+    fn action_button(parent: Entity, action: AlertAction, node: NodeBundle) -> impl Bundle {
+        (
+            Name::new(format!("Action Button {}", action.id)),
+            node,
+            Button,
+            Interaction::default(),
+            ActionButton {
+                alert: parent,
+                action,
+            },
+        )
+    }
+
+    fn show_more_button(parent: Entity, node: NodeBundle) -> impl Bundle {
+        (
+            Name::new("Show More Button"),
+            node,
+            Button,
+            Interaction::default(),
+            ShowMoreButton { alert: parent },
+        )
+    }
+
+    fn show_more_text() -> impl Bundle {
+        (
+            Name::new("Show More Button Text"),
+            TextBundle::from_section(
+                ShowMoreButton::LABEL,
+                TextStyle {
+                    font_size: 14.,
+                    color: Color::WHITE,
+                    ..Default::default()
+                },
+            ),
+        )
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn copy_button(parent: Entity, node: NodeBundle) -> impl Bundle {
+        (
+            Name::new("Copy Button"),
+            node,
+            Button,
+            Interaction::default(),
+            CopyButton { alert: parent },
+        )
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn copy_text(label: &str) -> impl Bundle {
+        (
+            Name::new("Copy Button Text"),
+            TextBundle::from_section(
+                label,
+                TextStyle {
+                    font_size: 14.,
+                    color: Color::WHITE,
                     ..Default::default()
                 },
-                background_color: Color::Srgba(bevy::color::palettes::css::DARK_GRAY).into(),
+            ),
+        )
+    }
+
+    fn dismiss_button(parent: Entity, header: &NodeBundle) -> impl Bundle {
+        (
+            Name::new("Dismiss Button"),
+            ButtonBundle {
+                style: Self::dismiss_button_style(header),
+                background_color: AlertDefaults::BUTTON_COLOR.into(),
                 ..Default::default()
             },
             DismissButton { alert: parent },
         )
     }
 
-    fn dismiss_text() -> impl Bundle {
+    /// The dismiss button is at least as wide as the header is tall, so it stays a square target.
+    pub(crate) fn dismiss_button_style(header: &NodeBundle) -> Style {
+        Style {
+            width: Val::Px(AlertDefaults::DISMISS_BUTTON_WIDTH),
+            min_width: header.style.height,
+            height: Val::Percent(100.),
+            padding: UiRect::px(2., 2., 2., 4.),
+            align_self: AlignSelf::FlexEnd,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn dismiss_text() -> impl Bundle {
         (
             Name::new("Dismiss X Button"),
             TextBundle::from_section(
@@ -661,9 +1869,106 @@ impl AlertUi {
     }
 }
 
-/// A marker component for the button in the AlertUI node tree that dismisses the alert.
+/// The entities of the nodes that make up an alert's UI, inserted on the alert when it is shown.
+#[derive(Clone, Copy, Debug, Component)]
+pub struct AlertParts {
+    header: Entity,
+    dismiss_button: Entity,
+    body: Entity,
+    text: Entity,
+    title: Option<Entity>,
+    icon: Option<Entity>,
+    timestamp: Option<Entity>,
+    repeat_count: Option<Entity>,
+    spinner: Option<Entity>,
+    actions: Option<Entity>,
+    progress_track: Option<Entity>,
+    progress_bar: Option<Entity>,
+    group_rows: Option<Entity>,
+}
+
+impl AlertParts {
+    pub fn header(&self) -> Entity {
+        self.header
+    }
+
+    pub fn dismiss_button(&self) -> Entity {
+        self.dismiss_button
+    }
+
+    pub fn body(&self) -> Entity {
+        self.body
+    }
+
+    pub fn text(&self) -> Entity {
+        self.text
+    }
+
+    /// The text node of the title, for alerts shown with one.
+    pub fn title(&self) -> Option<Entity> {
+        self.title
+    }
+
+    /// The image node of alerts shown with an `AlertIcon`.
+    pub fn icon(&self) -> Option<Entity> {
+        self.icon
+    }
+
+    /// The text node of the timestamp, for alerts shown with `AlertTimestamps`.
+    pub fn timestamp(&self) -> Option<Entity> {
+        self.timestamp
+    }
+
+    /// The text node of the `AlertRepeatCount` badge, once the alert was repeated.
+    pub fn repeat_count(&self) -> Option<Entity> {
+        self.repeat_count
+    }
+
+    /// The spinner text node in the header of an `UnresolvedAlert`.
+    pub fn spinner(&self) -> Option<Entity> {
+        self.spinner
+    }
+
+    /// The footer row holding the buttons of alerts with `AlertActions`.
+    pub fn actions(&self) -> Option<Entity> {
+        self.actions
+    }
+
+    /// The filled part of the bar of alerts with `AlertProgress`.
+    pub fn progress_bar(&self) -> Option<Entity> {
+        self.progress_bar
+    }
+
+    pub fn group_rows(&self) -> Option<Entity> {
+        self.group_rows
+    }
+}
+
+/// A marker component for the header node in the AlertUI node tree.
 #[derive(Component)]
-pub struct DismissButton {
+pub struct AlertHeader;
+
+/// A marker component for the title text node in the header of the AlertUI node tree.
+#[derive(Component)]
+pub struct AlertTitle;
+
+/// A marker component for the body node in the AlertUI node tree.
+#[derive(Component)]
+pub struct AlertBody;
+
+/// A marker component for the text node in the AlertUI node tree that renders the message.
+#[derive(Component)]
+pub struct AlertText;
+
+/// A marker component for the previous message of an alert, laid over the `AlertText` while an
+/// `AlertCrossFade` plays.
+#[derive(Component)]
+pub struct AlertFadingText;
+
+/// A marker component for the node in the AlertUI node tree that holds the rows of an
+/// `AlertGroup`.
+#[derive(Component)]
+pub struct AlertGroupRows {
     alert: Entity,
 }
 
@@ -676,7 +1981,7 @@ mod tests {
     use super::*;
 
     #[derive(Default, Component, Reflect)]
-    struct MyAlert;
+    pub(crate) struct MyAlert;
 
     fn alert_per_second(
         time: Res<Time>,
@@ -692,7 +1997,7 @@ mod tests {
         }
     }
 
-    fn app(use_custom: bool) -> App {
+    pub(crate) fn app(use_custom: bool) -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
@@ -712,7 +2017,17 @@ mod tests {
         app
     }
 
-    fn count_alerts(world: &mut World, use_custom: bool) -> usize {
+    pub(crate) fn manual_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_plugins(AlertsPlugin::new());
+        app
+    }
+
+    pub(crate) fn count_alerts(world: &mut World, use_custom: bool) -> usize {
         if use_custom {
             let mut query = world.query::<(&MyAlert, &Alert)>();
             query.iter(&world).count()
@@ -766,4 +2081,937 @@ mod tests {
             app.update();
         }
     }
+
+    #[test]
+    fn test_critical_alert_slot() {
+        let mut app = manual_app();
+        for _ in 0..4 {
+            app.world_mut()
+                .spawn((Alert::bundle("Normal"), AlertMarker));
+        }
+        app.update();
+        let first = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Reactor"),
+                AlertMarker,
+                CriticalAlert::default(),
+            ))
+            .id();
+        app.update();
+        let second = app
+            .world_mut()
+            .spawn((Alert::bundle("Hull"), AlertMarker, CriticalAlert::default()))
+            .id();
+        app.update();
+
+        let root = app
+            .world_mut()
+            .query_filtered::<&Children, With<AlertUiRoot>>()
+            .single(app.world())
+            .to_vec();
+        assert_eq!(root[0], second);
+        assert!(app.world().get::<AlertUi>(second).is_some());
+        assert!(app.world().get::<AlertUi>(first).is_none());
+        assert!(app.world().get::<CriticalDisplaced>(first).is_some());
+        // nothing points into the despawned card
+        assert!(app.world().get::<AlertParts>(first).is_none());
+
+        let mut normal_alerts = app
+            .world_mut()
+            .query_filtered::<(), (With<AlertUi>, Without<CriticalAlert>)>();
+        assert_eq!(normal_alerts.iter(app.world()).count(), 3);
+        assert_eq!(root.len(), 4);
+    }
+
+    #[test]
+    fn test_simultaneous_critical_alerts() {
+        let mut app = manual_app();
+        let older = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Reactor"),
+                AlertMarker,
+                CriticalAlert::default(),
+            ))
+            .id();
+        let newer = app
+            .world_mut()
+            .spawn((Alert::bundle("Hull"), AlertMarker, CriticalAlert::default()))
+            .id();
+        // the newer critical is shown right away, and keeps the slot
+        for _ in 0..3 {
+            app.update();
+            assert!(app.world().get::<AlertUi>(newer).is_some());
+            assert!(app.world().get::<AlertUi>(older).is_none());
+            assert!(app.world().get::<CriticalDisplaced>(older).is_some());
+        }
+    }
+
+    #[test]
+    fn test_seeded_jitter() {
+        fn offsets(seed: u64) -> Vec<f32> {
+            let mut app = manual_app();
+            app.insert_resource(MaxAlerts::<AlertMarker>::new(10));
+            app.insert_resource(AlertJitter::<AlertMarker>::new(4., seed));
+            let mut alerts = vec![];
+            for _ in 0..10 {
+                let alert = app.world_mut().spawn((Alert::bundle("Hit"), AlertMarker));
+                alerts.push(alert.id());
+                app.update();
+            }
+            alerts
+                .into_iter()
+                .map(|alert| app.world().get::<AlertJitterOffset>(alert).unwrap().0)
+                .collect()
+        }
+
+        let expected = vec![
+            1.932519, -2.720717, -1.7711911, -1.2464747, -3.6957588, 2.9458241, -2.2527585,
+            2.4050546, -1.2805519, 0.9478564,
+        ];
+        assert_eq!(offsets(42), expected);
+        assert_ne!(offsets(7), expected);
+    }
+
+    #[test]
+    fn test_pending_marker_lifecycle() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(1));
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            500,
+        )));
+        let first = app
+            .world_mut()
+            .spawn((Alert::bundle("first"), AlertMarker))
+            .id();
+        let second = app
+            .world_mut()
+            .spawn((Alert::bundle("second"), AlertMarker))
+            .id();
+        app.update();
+
+        assert!(app.world().get::<AlertPending>(first).is_none());
+        assert!(app.world().get::<AlertUi>(first).is_some());
+        assert!(app.world().get::<AlertPending>(second).is_some());
+        assert!(app.world().get::<AlertUi>(second).is_none());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(matches!(
+            app.world().get::<AlertTransition>(first),
+            Some(AlertTransition::FadeOut)
+        ));
+        assert!(app.world().get::<AlertPending>(first).is_none());
+        assert!(app.world().get::<AlertPending>(second).is_some());
+
+        app.update();
+        app.update();
+        assert!(app.world().get_entity(first).is_none());
+        assert!(app.world().get::<AlertPending>(second).is_none());
+        assert!(app.world().get::<AlertUi>(second).is_some());
+    }
+
+    #[test]
+    fn test_runtime_lifetime_change() {
+        let is_fading_out = |app: &App, alert: Entity| {
+            matches!(
+                app.world().get::<AlertTransition>(alert),
+                Some(AlertTransition::FadeOut)
+            )
+        };
+
+        // shortening the lifetime expires an alert that is already past it
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("shortened"), AlertMarker))
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(!is_fading_out(&app, alert));
+        app.world_mut()
+            .resource_mut::<AlertLifetime<AlertMarker>>()
+            .set(Duration::from_millis(500));
+        app.update();
+        assert!(is_fading_out(&app, alert));
+
+        // lengthening the lifetime keeps an alert that would otherwise have expired
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            750,
+        )));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("lengthened"), AlertMarker))
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+        **app.world_mut().resource_mut::<AlertLifetime<AlertMarker>>() = Duration::from_secs(2);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(!is_fading_out(&app, alert));
+        assert_eq!(
+            app.world().resource::<AlertLifetime<AlertMarker>>().get(),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn test_alert_names() {
+        let mut app = manual_app();
+        let first = app
+            .world_mut()
+            .spawn((Alert::bundle("Failed to save"), AlertMarker))
+            .id();
+        let second = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("This message is far too long to fit in a name"),
+                AlertMarker,
+            ))
+            .id();
+        let named = app
+            .world_mut()
+            .spawn((Alert::bundle("kept"), AlertMarker, Name::new("Custom")))
+            .id();
+        app.update();
+
+        let name = |app: &App, entity: Entity| app.world().get::<Name>(entity).unwrap().to_string();
+        assert_eq!(name(&app, first), r#"Alert#0: "Failed to save""#);
+        assert_eq!(
+            name(&app, second),
+            r#"Alert#1: "This message is far too …""#
+        );
+        assert_eq!(name(&app, named), "Custom");
+        let root = app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .unwrap();
+        assert_eq!(name(&app, root), "Alert UI Root AlertMarker");
+
+        app.insert_resource(AlertNames::new(|index, alert| {
+            format!("[{index}] {}", alert.message())
+        }));
+        let custom = app
+            .world_mut()
+            .spawn((Alert::bundle("custom"), AlertMarker))
+            .id();
+        app.update();
+        assert_eq!(name(&app, custom), "[0] custom");
+    }
+
+    #[test]
+    fn test_lifetime_thresholds() {
+        let mut app = manual_app();
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(Duration::from_secs(10));
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(2)));
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("escalating"),
+                AlertMarker,
+                AlertLifetimeThresholds::new([0.9, 0.5]),
+            ))
+            .id();
+        let reached = |app: &App| {
+            let events = app.world().resource::<Events<AlertThresholdReached>>();
+            events
+                .get_reader()
+                .read(events)
+                .map(|event| (event.entity, event.fraction))
+                .collect::<Vec<_>>()
+        };
+
+        app.update();
+        app.update();
+        assert!(reached(&app).is_empty());
+
+        // a single coarse step crosses both thresholds
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            1700,
+        )));
+        app.update();
+        assert_eq!(reached(&app), [(alert, 0.5), (alert, 0.9)]);
+
+        app.update();
+        app.update();
+        assert!(reached(&app).is_empty());
+    }
+
+    #[test]
+    fn test_foreign_marker_entities() {
+        let mut app = manual_app();
+        let decoy = app
+            .world_mut()
+            .spawn((AlertMarker, Name::new("Decoy")))
+            .id();
+        let decoy_root = app
+            .world_mut()
+            .spawn((
+                AlertMarker,
+                AlertUiRoot,
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(5.),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("real"), AlertMarker))
+            .id();
+        app.update();
+
+        // restyling only touches the plugin's own roots
+        app.insert_resource(AlertElements::<AlertMarker>::new());
+        for _ in 0..5 {
+            app.update();
+        }
+
+        assert!(app.world().get::<AlertUi>(alert).is_some());
+        let root = app.world().resource::<AlertRoots<AlertMarker>>().primary();
+        assert_ne!(root, Some(decoy_root));
+        for entity in [decoy, decoy_root] {
+            let entity = app.world().entity(entity);
+            assert!(!entity.contains::<AlertManaged<AlertMarker>>());
+            assert!(!entity.contains::<Parent>());
+            assert!(!entity.contains::<Children>());
+        }
+        assert_eq!(
+            app.world().get::<Style>(decoy_root).unwrap().width,
+            Val::Px(5.)
+        );
+    }
+
+    #[test]
+    fn test_keep_alive() {
+        let is_fading_out = |app: &App, alert: Entity| {
+            matches!(
+                app.world().get::<AlertTransition>(alert),
+                Some(AlertTransition::FadeOut)
+            )
+        };
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let enemy = app.world_mut().spawn_empty().id();
+        let touched = app
+            .world_mut()
+            .spawn((Alert::bundle("touched"), AlertMarker))
+            .id();
+        let kept = app
+            .world_mut()
+            .spawn((Alert::bundle("kept"), AlertMarker, KeepAliveWhile(enemy)))
+            .id();
+        app.update();
+
+        // three lifetimes of keeping both alive
+        for _ in 0..12 {
+            app.update();
+            app.world_mut()
+                .get_mut::<AlertTimer>(touched)
+                .unwrap()
+                .touch();
+        }
+        app.world_mut().despawn(enemy);
+        for _ in 0..4 {
+            app.update();
+            assert!(!is_fading_out(&app, touched));
+            assert!(!is_fading_out(&app, kept));
+        }
+        app.update();
+        assert!(is_fading_out(&app, touched));
+        assert!(is_fading_out(&app, kept));
+    }
+
+    #[test]
+    fn test_full_flow() {
+        #[derive(Default, Resource)]
+        struct Dismissals(Vec<(Entity, AlertDismissReason)>);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_plugins(AlertsPlugin::<MyAlert>::default());
+        let mut elements = AlertElements::<MyAlert>::corner_popup(60.);
+        elements.header.background_color = Color::srgb(0., 0.5, 0.).into();
+        app.insert_resource(elements);
+        app.init_resource::<Dismissals>();
+        app.observe(
+            |trigger: Trigger<OnAdd, AlertDismissReason>,
+             reasons: Query<&AlertDismissReason>,
+             mut dismissals: ResMut<Dismissals>| {
+                let reason = *reasons.get(trigger.entity()).unwrap();
+                dismissals.0.push((trigger.entity(), reason));
+            },
+        );
+        app.add_systems(
+            Update,
+            (|mut failed: Local<bool>| -> Result<(), Vec<String>> {
+                if std::mem::replace(&mut *failed, true) {
+                    return Ok(());
+                }
+                Err((1..=3)
+                    .map(|attempt| format!("Attempt {attempt} failed"))
+                    .collect())
+            })
+            .pipe_err(AlertsPlugin::<MyAlert>::custom_alert),
+        );
+        **app.world_mut().resource_mut::<MaxAlerts<MyAlert>>() = 2;
+        app.world_mut()
+            .resource_mut::<AlertLifetime<MyAlert>>()
+            .set(Duration::from_secs(60));
+        app.update();
+
+        // every error became an alert under the custom marker, but only two fit
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<(Entity, Option<&AlertParts>), (With<Alert>, With<MyAlert>)>();
+        let alerts = alerts.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(alerts.len(), 3);
+        let visible = alerts
+            .iter()
+            .filter_map(|(entity, parts)| parts.map(|parts| (*entity, *parts)))
+            .collect::<Vec<_>>();
+        assert_eq!(visible.len(), 2);
+
+        let (alert, parts) = visible[0];
+        let header = app.world().get::<BackgroundColor>(parts.header()).unwrap();
+        assert_eq!(header.0, Color::srgb(0., 0.5, 0.));
+
+        app.world_mut()
+            .entity_mut(parts.dismiss_button())
+            .insert(Interaction::Pressed);
+        app.update();
+        app.world_mut()
+            .entity_mut(parts.dismiss_button())
+            .insert(Interaction::None);
+        app.update();
+        assert_eq!(
+            app.world().resource::<Dismissals>().0,
+            [(alert, AlertDismissReason::Dismissed)]
+        );
+    }
+
+    #[test]
+    fn test_expiry_chains() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            250,
+        )));
+        app.insert_resource(MaxChainDepth::<AlertMarker>::new(2));
+        app.world_mut()
+            .resource_mut::<AlertTemplates<AlertMarker>>()
+            .register(
+                "retry",
+                AlertTemplate {
+                    on_expire: Some(OnExpireSpawn::Template {
+                        id: "retry".into(),
+                        args: vec![],
+                    }),
+                    ..AlertTemplate::new("Retrying autosave…")
+                },
+            );
+        app.world_mut().spawn((
+            Alert::bundle("Autosave failed"),
+            AlertMarker,
+            OnExpireSpawn::Message("Autosave will retry".to_string()),
+        ));
+        app.world_mut().spawn((
+            Alert::bundle("Retrying autosave…"),
+            AlertMarker,
+            OnExpireSpawn::Template {
+                id: "retry".into(),
+                args: vec![],
+            },
+        ));
+
+        let mut seen = Vec::<(String, u32)>::new();
+        for _ in 0..30 {
+            app.update();
+            let mut alerts = app
+                .world_mut()
+                .query::<(Entity, &Alert, Option<&AlertChainDepth>)>();
+            let alerts = alerts
+                .iter(app.world())
+                .map(|(entity, alert, depth)| {
+                    (
+                        entity,
+                        alert.message().to_string(),
+                        depth.map_or(0, |depth| depth.0),
+                    )
+                })
+                .collect::<Vec<_>>();
+            for (_, message, depth) in alerts {
+                if !seen.contains(&(message.clone(), depth)) {
+                    seen.push((message, depth));
+                }
+            }
+        }
+        seen.sort();
+
+        assert_eq!(
+            seen,
+            [
+                ("Autosave failed".to_string(), 0),
+                ("Autosave will retry".to_string(), 1),
+                ("Retrying autosave…".to_string(), 0),
+                ("Retrying autosave…".to_string(), 1),
+                ("Retrying autosave…".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_tick_clock() {
+        let mut app = manual_app();
+        // 2.5 fixed steps per frame, so frames see an uneven number of ticks
+        app.insert_resource(Time::<Fixed>::from_seconds(0.1));
+        // would expire on the first frame if lifetimes were still measured in time
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(1)));
+        app.insert_resource(AlertTiming::<AlertMarker>::new(AlertClock::FixedTicks(4)));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("four ticks"), AlertMarker))
+            .id();
+
+        let mut frames = 0;
+        loop {
+            app.update();
+            frames += 1;
+            let ticks = app
+                .world()
+                .get::<AlertTimer>(alert)
+                .map_or(0, |timer| timer.fixed_ticks());
+            let expired = app.world().get::<AlertDismissReason>(alert).is_some();
+            assert_eq!(expired, ticks == 4, "frame {frames}: {ticks} ticks");
+            if expired {
+                break;
+            }
+            assert!(frames < 10);
+        }
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(alert),
+            Some(&AlertDismissReason::Expired)
+        );
+    }
+
+    #[test]
+    fn test_alert_defaults() {
+        let mut app = manual_app();
+        assert_eq!(
+            app.world().resource::<AlertLifetime<AlertMarker>>().get(),
+            AlertDefaults::LIFETIME
+        );
+        assert_eq!(
+            **app.world().resource::<MaxAlerts<AlertMarker>>(),
+            AlertDefaults::MAX_ALERTS
+        );
+        assert_eq!(
+            TransitionTimer::default().duration,
+            AlertDefaults::TRANSITION
+        );
+        let elements = app.world().resource::<AlertElements<AlertMarker>>();
+        assert_eq!(
+            elements.alert.style.min_height,
+            Val::Px(AlertDefaults::ALERT_HEIGHT)
+        );
+        assert_eq!(
+            elements.header.style.height,
+            Val::Px(AlertDefaults::HEADER_HEIGHT)
+        );
+
+        let default_alert = app
+            .world_mut()
+            .spawn((Alert::bundle("default"), AlertMarker))
+            .id();
+        app.update();
+        let parts = *app.world().get::<AlertParts>(default_alert).unwrap();
+        let dismiss_button = parts.dismiss_button();
+        assert_eq!(
+            app.world().get::<Style>(dismiss_button).unwrap().width,
+            Val::Px(AlertDefaults::DISMISS_BUTTON_WIDTH)
+        );
+        assert_eq!(
+            app.world()
+                .get::<BackgroundColor>(dismiss_button)
+                .unwrap()
+                .0,
+            AlertDefaults::BUTTON_LOCKED_COLOR
+        );
+
+        // overriding a default reaches alerts spawned afterwards
+        app.insert_resource(AlertElements::<AlertMarker>::corner_popup(120.));
+        let tall_alert = app
+            .world_mut()
+            .spawn((Alert::bundle("tall"), AlertMarker))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().get::<Style>(tall_alert).unwrap().min_height,
+            Val::Px(120.)
+        );
+    }
+
+    #[test]
+    fn test_alert_time_scale() {
+        for (scale, expected_ticks) in [(1., [4, 8]), (0.5, [8, 16])] {
+            let mut app = manual_app();
+            app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+                999,
+            )));
+            app.insert_resource(AlertTimeScale::<AlertMarker>::new(scale));
+            let plain = app
+                .world_mut()
+                .spawn((Alert::bundle("plain"), AlertMarker))
+                .id();
+            let slowed = app
+                .world_mut()
+                .spawn((
+                    Alert::bundle("slowed"),
+                    AlertMarker,
+                    AlertTimeScaleOverride(0.5),
+                ))
+                .id();
+            // promoted on the first frame, which has no delta
+            app.update();
+
+            let mut expired_after = [None, None];
+            for tick in 1..=20 {
+                app.update();
+                for (index, alert) in [plain, slowed].into_iter().enumerate() {
+                    if expired_after[index].is_none()
+                        && app.world().get::<AlertDismissReason>(alert).is_some()
+                    {
+                        expired_after[index] = Some(tick);
+                    }
+                }
+            }
+            assert_eq!(expired_after, expected_ticks.map(Some));
+        }
+    }
+
+    #[test]
+    fn test_alert_conversions() {
+        assert_eq!(Alert::new("new").message(), "new");
+        assert_eq!(Alert::from("borrowed").message(), "borrowed");
+        assert_eq!(Alert::from("owned".to_string()).message(), "owned");
+
+        let mut app = manual_app();
+        app.world_mut()
+            .spawn((Alert::bundle("queried"), AlertMarker));
+        app.update();
+        let mut alerts = app.world_mut().query::<&Alert>();
+        assert_eq!(alerts.single(app.world()).message(), "queried");
+    }
+
+    #[test]
+    fn test_alert_states() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(2));
+        app.insert_resource(MaxConcurrentTransitions::<AlertMarker>::new(1));
+        let [first, second, tracked] = ["first", "second", "tracked"].map(|message| {
+            app.world_mut()
+                .spawn((Alert::bundle(message), AlertMarker))
+                .id()
+        });
+        let mut states = Vec::new();
+        let mut update = |app: &mut App| {
+            app.update();
+            if let Some(state) = app.world().get::<AlertState>(tracked) {
+                if states.last() != Some(state) {
+                    states.push(*state);
+                }
+            }
+        };
+        let dismiss = |app: &mut App, alert: Entity| {
+            app.world_mut()
+                .entity_mut(alert)
+                .insert(AlertTransition::FadeOut);
+        };
+
+        update(&mut app);
+        // freeing a slot lets the tracked alert in
+        dismiss(&mut app, first);
+        for _ in 0..8 {
+            update(&mut app);
+        }
+        // the tracked alert waits for the second to finish leaving
+        dismiss(&mut app, second);
+        update(&mut app);
+        dismiss(&mut app, tracked);
+        for _ in 0..8 {
+            update(&mut app);
+        }
+
+        assert!(app.world().get_entity(tracked).is_none());
+        assert_eq!(
+            states,
+            [
+                AlertState::Pending,
+                AlertState::Entering,
+                AlertState::Visible,
+                AlertState::WaitingToLeave,
+                AlertState::Leaving,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_marker_factory() {
+        #[derive(Component, Reflect)]
+        struct Channel(u8);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_plugins(AlertsPlugin::with_marker(|| Channel(3)));
+        app.add_systems(
+            Update,
+            (|| vec!["piped".to_string()]).pipe(AlertsPlugin::<Channel>::custom_alert),
+        );
+        app.insert_resource(MaxAlerts::<Channel>::new(4));
+        let sent = app
+            .world_mut()
+            .spawn((Alert::bundle("sent"), Channel(7)))
+            .id();
+        // the spawning helpers go through the factory too
+        app.world_mut().spawn_alert::<Channel>("spawned");
+        app.world_mut().run_system_once(|mut commands: Commands| {
+            commands.alert_with_marker::<Channel>("commanded");
+        });
+        app.update();
+
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<(Entity, &Alert, &Channel), With<AlertUi>>();
+        let mut channels = alerts
+            .iter(app.world())
+            .map(|(entity, alert, channel)| (entity, alert.message().to_string(), channel.0))
+            .collect::<Vec<_>>();
+        channels.sort_by_key(|(_, message, _)| message.clone());
+        assert_eq!(channels.len(), 4);
+        for index in [0, 1, 3] {
+            assert_eq!(channels[index].2, 3);
+        }
+        assert_eq!(channels[1].1, "piped");
+        // alerts keep the marker they were spawned with
+        assert_eq!(channels[2], (sent, "sent".to_string(), 7));
+
+        let mut roots = app
+            .world_mut()
+            .query_filtered::<&Channel, With<AlertUiRoot>>();
+        assert_eq!(roots.single(app.world()).0, 3);
+    }
+
+    #[test]
+    fn test_plugin_builder() {
+        fn build(plugin: AlertsPlugin) -> App {
+            let mut app = App::new();
+            app.add_plugins(MinimalPlugins);
+            app.add_plugins(plugin);
+            app
+        }
+        let header = |app: &App| {
+            app.world()
+                .resource::<AlertElements>()
+                .header
+                .background_color
+                .0
+        };
+        let mut elements = AlertElements::corner_popup(60.);
+        elements.header.background_color = Color::BLACK.into();
+
+        let app = build(AlertsPlugin::new().with_lifetime(Duration::from_secs(5)));
+        assert_eq!(
+            app.world().resource::<AlertLifetime<AlertMarker>>().get(),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            **app.world().resource::<MaxAlerts<AlertMarker>>(),
+            AlertDefaults::MAX_ALERTS
+        );
+
+        let app = build(AlertsPlugin::new().with_max_alerts(6));
+        assert_eq!(**app.world().resource::<MaxAlerts<AlertMarker>>(), 6);
+        assert_eq!(
+            app.world().resource::<AlertLifetime<AlertMarker>>().get(),
+            AlertDefaults::LIFETIME
+        );
+
+        let app = build(AlertsPlugin::new().with_elements(elements.clone()));
+        assert_eq!(header(&app), Color::BLACK);
+
+        let app = build(
+            AlertsPlugin::new()
+                .with_lifetime(Duration::from_secs(5))
+                .with_max_alerts(6)
+                .with_elements(elements.clone()),
+        );
+        assert_eq!(
+            app.world().resource::<AlertLifetime<AlertMarker>>().get(),
+            Duration::from_secs(5)
+        );
+        assert_eq!(**app.world().resource::<MaxAlerts<AlertMarker>>(), 6);
+        assert_eq!(header(&app), Color::BLACK);
+
+        // resources inserted up front are kept over both the builder and the defaults
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(2));
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        app.add_plugins(AlertsPlugin::new().with_max_alerts(6));
+        assert_eq!(**app.world().resource::<MaxAlerts<AlertMarker>>(), 2);
+        assert_eq!(
+            app.world().resource::<AlertLifetime<AlertMarker>>().get(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn test_persistent_alert() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let persistent = app
+            .world_mut()
+            .spawn((
+                Alert::persistent_bundle("Save data is corrupted"),
+                AlertMarker,
+            ))
+            .id();
+        let normal = app
+            .world_mut()
+            .spawn((Alert::bundle("Autosaved"), AlertMarker))
+            .id();
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(app.world().get_entity(normal).is_none());
+        assert!(app.world().get::<AlertUi>(persistent).is_some());
+        assert!(app.world().get::<AlertTransition>(persistent).is_none());
+        let root = app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .unwrap();
+        assert_eq!(**app.world().get::<Children>(root).unwrap(), [persistent]);
+
+        let button = app
+            .world()
+            .get::<AlertParts>(persistent)
+            .unwrap()
+            .dismiss_button();
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(app.world().get_entity(persistent).is_none());
+        assert!(app.world().get_entity(root).is_none());
+    }
+
+    #[test]
+    fn test_plugin_systems() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(AlertsPlugin::<MyAlert>::systems());
+        schedule.initialize(&mut world).unwrap();
+        let names = schedule
+            .systems()
+            .unwrap()
+            .map(|(_, system)| system.name().to_string())
+            .filter(|name| name.contains("AlertsPlugin"))
+            .collect::<Vec<_>>();
+        assert_eq!(names.len(), 47);
+        assert!(names.iter().any(|name| name.ends_with("spawn_alerts")));
+        assert!(names.iter().all(|name| name.contains("MyAlert")));
+    }
+
+    #[test]
+    fn test_plugin_schedule() {
+        // the nested chains in `systems` have to build without a cycle
+        let mut app = manual_app();
+        app.add_plugins(AlertsPlugin::<MyAlert>::default());
+        app.world_mut()
+            .spawn((Alert::bundle("Failed to sync"), AlertMarker));
+        app.world_mut().spawn((Alert::bundle("Saved"), MyAlert));
+        app.update();
+        app.update();
+        assert_eq!(count_alerts(app.world_mut(), false), 1);
+        assert_eq!(count_alerts(app.world_mut(), true), 1);
+    }
+
+    #[test]
+    fn test_alerts_raised_by_alert_callbacks() {
+        #[derive(Resource, Default)]
+        struct Retries(Vec<Entity>);
+
+        let mut app = manual_app();
+        // retrying fails again at once, from inside the alert systems
+        app.init_resource::<Retries>().observe(
+            |trigger: Trigger<OnAdd, AlertDismissReason>,
+             reasons: Query<&AlertDismissReason>,
+             mut retries: ResMut<Retries>,
+             mut commands: Commands| {
+                if reasons.get(trigger.entity()).ok() == Some(&AlertDismissReason::Dismissed) {
+                    retries
+                        .0
+                        .push(commands.alert_with_marker::<AlertMarker>("Sync failed again"));
+                }
+            },
+        );
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Failed to sync"),
+                AlertActions(vec![AlertAction::new("retry", "Retry")]),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        app.update();
+        let footer = app
+            .world()
+            .get::<AlertParts>(alert)
+            .unwrap()
+            .actions()
+            .unwrap();
+        let retry = app.world().get::<Children>(footer).unwrap()[0];
+
+        *app.world_mut().get_mut::<Interaction>(retry).unwrap() = Interaction::Pressed;
+        app.update();
+        let [retried] = app.world().resource::<Retries>().0[..] else {
+            panic!("expected one retry alert");
+        };
+        let entity = app.world().entity(retried);
+        assert!(entity.contains::<AlertPending>());
+        assert!(!entity.contains::<AlertManaged<AlertMarker>>());
+        assert!(!entity.contains::<AlertUi>());
+        assert!(!entity.contains::<AlertParts>());
+
+        app.update();
+        let parts = *app.world().get::<AlertParts>(retried).unwrap();
+        assert!(app.world().entity(retried).contains::<AlertUi>());
+        assert!(!app.world().entity(retried).contains::<AlertPending>());
+        assert_eq!(
+            app.world().get::<Text>(parts.text()).unwrap().sections[0].value,
+            "Sync failed again"
+        );
+    }
 }
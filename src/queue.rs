@@ -0,0 +1,530 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    /// Folds newly queued alerts into live alerts with the same `AlertKey`, or with the same
+    /// message while `DedupAlerts` is enabled.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn merge_duplicate_alerts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                Option<&AlertKey>,
+                &mut Alert,
+                Option<&mut AlertTimer>,
+                Option<&mut AlertRepeatCount>,
+                Option<&mut AlertOccurrences>,
+                Has<AlertUi>,
+                Option<&AlertSequence>,
+                Has<AlertDismissReason>,
+                Ref<AlertManaged<M>>,
+            ),
+            Or<(With<AlertPending>, With<AlertUi>)>,
+        >,
+        dedup: Res<DedupAlerts<M>>,
+        mut history: ResMut<AlertHistory<M>>,
+        time: Res<Time>,
+    ) {
+        // alerts are the same by key, or by message for unkeyed alerts while deduplicating
+        let identity = |key: Option<&AlertKey>, alert: &Alert| match key {
+            Some(key) => Some((true, key.0.clone())),
+            None if dedup.enabled => Some((false, alert.message.clone())),
+            None => None,
+        };
+        let mut live = HashMap::<(bool, String), Entity>::default();
+        let mut incoming = Vec::new();
+        for (entity, key, alert, _, _, _, _, sequence, leaving, managed) in &alerts {
+            let Some(identity) = identity(key, alert) else {
+                continue;
+            };
+            if managed.is_added() {
+                incoming.push((sequence.copied(), entity, identity));
+            } else if !leaving {
+                live.insert(identity, entity);
+            }
+        }
+        if incoming.is_empty() {
+            return;
+        }
+        incoming.sort_by_key(|(sequence, entity, _)| (*sequence, *entity));
+
+        let mut repeats = HashMap::<Entity, u32>::default();
+        for (_, entity, identity) in incoming {
+            let Some(&target) = live.get(&identity) else {
+                live.insert(identity, entity);
+                continue;
+            };
+            let Ok((.., mut alert, _, _, _, _, _, _, _)) = alerts.get_mut(entity) else {
+                continue;
+            };
+            let message = std::mem::take(&mut alert.message);
+            commands.entity(entity).despawn_recursive();
+
+            let Ok((_, _, mut alert, timer, _, occurrences, shown, ..)) = alerts.get_mut(target)
+            else {
+                continue;
+            };
+            if let Some(mut timer) = timer {
+                *timer = AlertTimer::default();
+            }
+            // every way an alert resurfaces is counted here
+            match occurrences {
+                Some(mut occurrences) => occurrences.0 += 1,
+                None => {
+                    commands.entity(target).insert(AlertOccurrences(2));
+                }
+            }
+            // pending alerts are recorded once they are shown
+            if shown {
+                history.record(&identity.1, time.elapsed(), 1);
+            }
+            if dedup.enabled {
+                *repeats.entry(target).or_default() += 1;
+            }
+            // the text follows in `update_alert_texts`
+            if alert.message != message {
+                alert.message = message;
+            }
+        }
+
+        for (target, repeats) in repeats {
+            let Ok((.., count, _, _, _, _, _)) = alerts.get_mut(target) else {
+                continue;
+            };
+            match count {
+                Some(mut count) => count.0 += repeats,
+                None => {
+                    commands
+                        .entity(target)
+                        .insert(AlertRepeatCount(1 + repeats));
+                }
+            }
+        }
+    }
+
+    /// Applies the `AlertVisibilityFilter` to the alerts that are already queued or shown.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn filter_alerts(
+        mut commands: Commands,
+        pending: Query<(Entity, Option<&AlertLevel>), (With<AlertManaged<M>>, With<AlertPending>)>,
+        shown: Query<
+            (Entity, Option<&AlertLevel>),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUi>,
+                Without<AlertDismissReason>,
+            ),
+        >,
+        filter: Res<AlertVisibilityFilter<M>>,
+    ) {
+        let hidden = |level: Option<&AlertLevel>| !filter.shows(level.copied().unwrap_or_default());
+        if filter.drop_hidden {
+            for (entity, _) in pending.iter().filter(|(_, level)| hidden(*level)) {
+                commands
+                    .entity(entity)
+                    .insert(AlertDismissReason::Filtered)
+                    .despawn_recursive();
+            }
+        }
+        if filter.is_changed() {
+            for (entity, _) in shown.iter().filter(|(_, level)| hidden(*level)) {
+                commands.add(DismissAlert::<M>::new(entity, AlertDismissReason::Filtered));
+            }
+        }
+    }
+}
+
+/// Whether an alert of this kind with the same message as a live one is merged into it instead of
+/// shown again. Disabled by default.
+///
+/// The live alert restarts its `AlertTimer` and counts the repeats in an `AlertRepeatCount`, shown
+/// as a badge in its header. Alerts with an `AlertKey` are matched by key instead, and are counted
+/// too while this is enabled.
+#[derive(Debug, Resource, Reflect)]
+pub struct DedupAlerts<M: TypePath> {
+    pub enabled: bool,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> DedupAlerts<M>
+where
+    M: TypePath,
+{
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// A `SystemParam` for inspecting and cancelling the alerts of this kind that are still waiting for
+/// a slot.
+///
+/// Cancelled alerts were never visible, so they are despawned immediately with
+/// `AlertDismissReason::Cancelled` instead of fading out. Alerts spawned this frame are only seen
+/// once the plugin adopts them in `PostUpdate`.
+#[derive(SystemParam)]
+pub struct PendingAlerts<'w, 's, M: Component + TypePath> {
+    commands: Commands<'w, 's>,
+    pending: Query<'w, 's, (Entity, &'static Alert), (With<AlertManaged<M>>, With<AlertPending>)>,
+}
+
+impl<M> PendingAlerts<'_, '_, M>
+where
+    M: Component + TypePath,
+{
+    pub fn pending_len(&self) -> usize {
+        self.pending.iter().count()
+    }
+
+    pub fn pending_messages(&self) -> impl Iterator<Item = &str> {
+        self.pending.iter().map(|(_, alert)| alert.message())
+    }
+
+    /// Cancels `entity` if it is a pending alert, returning whether it was.
+    pub fn cancel_pending(&mut self, entity: Entity) -> bool {
+        if !self.pending.contains(entity) {
+            return false;
+        }
+        self.cancel(entity);
+        true
+    }
+
+    /// Cancels every pending alert for which `predicate` returns true, returning how many were.
+    pub fn cancel_pending_matching(&mut self, mut predicate: impl FnMut(&Alert) -> bool) -> usize {
+        let cancelled = self
+            .pending
+            .iter()
+            .filter(|(_, alert)| predicate(alert))
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+        for entity in &cancelled {
+            self.cancel(*entity);
+        }
+        cancelled.len()
+    }
+
+    fn cancel(&mut self, entity: Entity) {
+        self.commands
+            .entity(entity)
+            .insert(AlertDismissReason::Cancelled)
+            .despawn_recursive();
+    }
+}
+
+/// A check that `spawn_alerts` runs against each pending alert of this kind before promoting it.
+///
+/// Use this for conditions that are only known at display time, like not showing loot alerts while
+/// an inventory screen is open. Rejected alerts stay pending and are checked again next frame.
+/// `CriticalAlert`s bypass the gate unless `gates_criticals` is set. This resource is not inserted
+/// by default.
+///
+/// ```
+/// use std::sync::{
+///     atomic::{AtomicBool, Ordering},
+///     Arc,
+/// };
+/// use bevy_ui_mod_alerts::{AlertMarker, PromotionGate};
+///
+/// let inventory_open = Arc::new(AtomicBool::new(false));
+/// let gate = PromotionGate::<AlertMarker>::new({
+///     let inventory_open = inventory_open.clone();
+///     move |alert| !(inventory_open.load(Ordering::Relaxed) && alert.message().contains("loot"))
+/// });
+/// ```
+#[derive(Resource)]
+pub struct PromotionGate<M> {
+    check: Box<dyn Fn(&Alert) -> bool + Send + Sync>,
+    pub gates_criticals: bool,
+    marker: PhantomData<M>,
+}
+
+impl<M> PromotionGate<M> {
+    pub fn new(check: impl Fn(&Alert) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            check: Box::new(check),
+            gates_criticals: false,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Applies the gate to `CriticalAlert`s as well.
+    pub fn gating_criticals(mut self) -> Self {
+        self.gates_criticals = true;
+        self
+    }
+
+    pub fn allows(&self, alert: &Alert) -> bool {
+        (self.check)(alert)
+    }
+}
+
+/// The lowest `AlertLevel` of this kind that is shown, such as `Warning` to hide info toasts from
+/// a settings menu. Every level is shown by default.
+///
+/// Alerts below it are held in the queue, or cancelled there when `drop_hidden` is set. Raising
+/// the level fades out the shown alerts that no longer qualify, and lowering it releases the held
+/// alerts that now do.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertVisibilityFilter<M: TypePath> {
+    pub min_level: AlertLevel,
+    pub drop_hidden: bool,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertVisibilityFilter<M>
+where
+    M: TypePath,
+{
+    pub fn new(min_level: AlertLevel) -> Self {
+        Self {
+            min_level,
+            drop_hidden: false,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Cancels hidden alerts instead of holding them in the queue.
+    pub fn dropping_hidden(mut self) -> Self {
+        self.drop_hidden = true;
+        self
+    }
+
+    pub fn shows(&self, level: AlertLevel) -> bool {
+        level >= self.min_level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_cancel_pending_alerts() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(1));
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            250,
+        )));
+        app.world_mut()
+            .spawn((Alert::bundle("blocker"), AlertMarker));
+        for message in ["keep 1", "drop 2", "drop 3", "keep 4", "drop 5"] {
+            app.world_mut().spawn((Alert::bundle(message), AlertMarker));
+        }
+        app.update();
+
+        let cancelled =
+            app.world_mut()
+                .run_system_once(|mut pending: PendingAlerts<AlertMarker>| {
+                    assert_eq!(pending.pending_len(), 5);
+                    pending.cancel_pending_matching(|alert| alert.message().starts_with("drop"))
+                });
+        assert_eq!(cancelled, 3);
+
+        let mut displayed = Vec::new();
+        for _ in 0..30 {
+            app.update();
+            let mut query = app
+                .world_mut()
+                .query_filtered::<&Alert, (With<AlertUi>, Without<AlertPending>)>();
+            for alert in query.iter(app.world()) {
+                if !displayed.iter().any(|message| message == alert.message()) {
+                    displayed.push(alert.message().to_string());
+                }
+            }
+        }
+        assert_eq!(displayed, ["blocker", "keep 1", "keep 4"]);
+    }
+
+    #[test]
+    fn test_promotion_gate() {
+        use std::sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        };
+
+        let paused = Arc::new(AtomicBool::new(true));
+        let mut app = manual_app();
+        app.insert_resource(PromotionGate::<AlertMarker>::new({
+            let paused = paused.clone();
+            move |_| !paused.load(Ordering::Relaxed)
+        }));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("gated"), AlertMarker))
+            .id();
+        let critical = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("critical"),
+                AlertMarker,
+                CriticalAlert::default(),
+            ))
+            .id();
+        app.update();
+        app.update();
+        assert!(app.world().get::<AlertPending>(alert).is_some());
+        assert!(app.world().get::<AlertUi>(critical).is_some());
+
+        paused.store(false, Ordering::Relaxed);
+        app.update();
+        assert!(app.world().get::<AlertUi>(alert).is_some());
+
+        paused.store(true, Ordering::Relaxed);
+        let second = app
+            .world_mut()
+            .spawn((Alert::bundle("gated again"), AlertMarker))
+            .id();
+        app.update();
+        assert!(app.world().get::<AlertPending>(second).is_some());
+
+        paused.store(false, Ordering::Relaxed);
+        app.update();
+        assert!(app.world().get::<AlertUi>(second).is_some());
+    }
+
+    #[test]
+    fn test_visibility_filter() {
+        let mut app = manual_app();
+        let [info, warning, error] = [
+            (AlertLevel::Info, "Saved"),
+            (AlertLevel::Warning, "Low disk space"),
+            (AlertLevel::Error, "Save failed"),
+        ]
+        .map(|(level, message)| {
+            app.world_mut()
+                .spawn((Alert::with_level(level, message), AlertMarker))
+                .id()
+        });
+        app.update();
+        let shown = |app: &App, alert: Entity| {
+            app.world().get_entity(alert).is_some_and(|alert| {
+                alert.contains::<AlertUi>() && !alert.contains::<AlertDismissReason>()
+            })
+        };
+        assert!([info, warning, error]
+            .iter()
+            .all(|alert| shown(&app, *alert)));
+
+        // raising the level fades out the info alert, and holds new ones in the queue
+        app.world_mut()
+            .resource_mut::<AlertVisibilityFilter<AlertMarker>>()
+            .min_level = AlertLevel::Warning;
+        let held = app
+            .world_mut()
+            .spawn((Alert::bundle("Autosaved"), AlertMarker))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(info),
+            Some(&AlertDismissReason::Filtered)
+        );
+        assert!(shown(&app, warning) && shown(&app, error));
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().get_entity(info).is_none());
+        assert!(app.world().entity(held).contains::<AlertPending>());
+
+        // lowering it releases the held alert
+        app.world_mut()
+            .resource_mut::<AlertVisibilityFilter<AlertMarker>>()
+            .min_level = AlertLevel::Info;
+        app.update();
+        assert!(shown(&app, held));
+
+        // hidden alerts can be dropped instead
+        app.insert_resource(
+            AlertVisibilityFilter::<AlertMarker>::new(AlertLevel::Error).dropping_hidden(),
+        );
+        let dropped = app
+            .world_mut()
+            .spawn((Alert::bundle("Autosaved again"), AlertMarker))
+            .id();
+        app.update();
+        assert!(app.world().get_entity(dropped).is_none());
+        assert!(!shown(&app, held) && !shown(&app, warning) && shown(&app, error));
+    }
+
+    #[test]
+    fn test_dedup_alerts() {
+        let mut app = manual_app();
+        app.insert_resource(DedupAlerts::<AlertMarker>::new(true));
+        app.add_systems(
+            Update,
+            (|| vec!["disk full".to_string()])
+                .pipe(AlertsPlugin::alert)
+                .run_if(|mut fired: Local<u32>| {
+                    *fired += 1;
+                    *fired <= 5
+                }),
+        );
+        for _ in 0..6 {
+            app.update();
+        }
+
+        let mut shown = app
+            .world_mut()
+            .query_filtered::<(&Alert, &AlertRepeatCount, &AlertParts), With<AlertUi>>();
+        let shown = shown.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(shown.len(), 1);
+        let (alert, count, parts) = shown[0];
+        assert_eq!(alert.message(), "disk full");
+        assert_eq!(*count, AlertRepeatCount(5));
+        let badge = parts.repeat_count().unwrap();
+        assert_eq!(
+            app.world().get::<Text>(badge).unwrap().sections[0].value,
+            "x5"
+        );
+        let header = parts.header();
+        let badge_node = app.world().get::<Parent>(badge).unwrap().get();
+        assert_eq!(app.world().get::<Children>(header).unwrap()[0], badge_node);
+
+        // other messages are still shown on their own
+        app.world_mut()
+            .spawn((Alert::bundle("disk almost full"), AlertMarker));
+        app.update();
+        let mut shown = app.world_mut().query_filtered::<&Alert, With<AlertUi>>();
+        assert_eq!(shown.iter(app.world()).count(), 2);
+    }
+
+    #[test]
+    fn test_repeat_of_displaced_critical() {
+        let mut app = manual_app();
+        app.insert_resource(DedupAlerts::<AlertMarker>::new(true));
+        let reactor = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Reactor"),
+                AlertMarker,
+                CriticalAlert::default(),
+            ))
+            .id();
+        app.update();
+        app.world_mut()
+            .spawn((Alert::bundle("Hull"), AlertMarker, CriticalAlert::default()));
+        app.update();
+        assert!(app.world().get::<CriticalDisplaced>(reactor).is_some());
+
+        // merges into the queued critical, whose badge waits for its next card
+        app.world_mut()
+            .spawn((Alert::bundle("Reactor"), AlertMarker));
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertRepeatCount>(reactor),
+            Some(&AlertRepeatCount(2))
+        );
+        assert!(app.world().get::<AlertParts>(reactor).is_none());
+        // and is not recorded as seen while queued
+        let history = app.world().resource::<AlertHistory<AlertMarker>>();
+        assert!(history.last_seen("Reactor") > history.last_seen("Hull"));
+    }
+}
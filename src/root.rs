@@ -0,0 +1,1025 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn despawn_alert_root(
+        mut commands: Commands,
+        alerts: Query<
+            (Option<&AlertRootKey>, Has<AlertParent>),
+            (
+                With<AlertManaged<M>>,
+                Or<(With<AlertPending>, With<AlertUi>)>,
+            ),
+        >,
+        collapse_badges: Query<Entity, (With<AlertManaged<M>>, With<AlertCollapseBadge>)>,
+        parent_policy: Res<AlertParentPolicy<M>>,
+        status: Res<AlertStatus<M>>,
+        mut roots: ResMut<AlertRoots<M>>,
+        mut current_root: ResMut<CurrentAlertRoot<M>>,
+        mut despawned_roots: EventWriter<AlertRootDespawned<M>>,
+    ) where
+        M: Component + Send + Sync + 'static,
+    {
+        // alerts in their own parent only hold on to a root that they take slots from
+        let occupied_keys = alerts
+            .iter()
+            .filter(|(_, parented)| !parented || parent_policy.counts_toward_max_alerts)
+            .map(|(key, _)| key.unwrap_or(&AlertRootKey::PRIMARY))
+            .chain(status.message.is_some().then_some(&AlertRootKey::PRIMARY))
+            .collect::<HashSet<_>>();
+
+        // remove any roots that no longer have alerts of their own, or were despawned from outside
+        // so that the next alert recreates them
+        roots.roots.retain(|key, root| {
+            if occupied_keys.contains(key) && commands.get_entity(*root).is_some() {
+                return true;
+            }
+            if let Some(entity) = commands.get_entity(*root) {
+                entity.despawn_recursive();
+            }
+            despawned_roots.send(AlertRootDespawned(*root, PhantomData));
+            if *key == AlertRootKey::PRIMARY {
+                current_root.0 = None;
+                for badge in &collapse_badges {
+                    commands.entity(badge).despawn_recursive();
+                }
+            }
+            false
+        });
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn spill_over_alerts(
+        mut commands: Commands,
+        displayed: Query<
+            (
+                Entity,
+                Option<&AlertRootKey>,
+                Has<AlertSpilled>,
+                Option<&AlertTransition>,
+                &AlertTimer,
+            ),
+            (With<AlertManaged<M>>, With<AlertUi>, Without<CriticalAlert>),
+        >,
+        pending: Query<
+            (
+                Entity,
+                Option<&AlertRootKey>,
+                Has<AlertSpilled>,
+                Option<&AlertSequence>,
+            ),
+            (
+                With<AlertManaged<M>>,
+                With<AlertPending>,
+                Without<CriticalAlert>,
+            ),
+        >,
+        root_nodes: Query<(&Node, &Style), (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+        windows: Query<&Window, With<PrimaryWindow>>,
+        roots: Res<AlertRoots<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+        max_alerts: Res<MaxAlerts<M>>,
+        spillover: Res<SpilloverAnchor<M>>,
+    ) {
+        let is_primary =
+            |key: Option<&AlertRootKey>| key.map_or(true, |key| *key == AlertRootKey::PRIMARY);
+        if spillover.anchor.is_none() {
+            // queued spillover goes back to the primary root; displayed spillover stays put
+            for (entity, _, spilled, _) in &pending {
+                if spilled {
+                    commands
+                        .entity(entity)
+                        .remove::<AlertSpilled>()
+                        .insert(AlertRootKey::PRIMARY);
+                }
+            }
+            return;
+        }
+
+        let live = displayed
+            .iter()
+            .filter(|(_, key, ..)| is_primary(*key))
+            .count();
+        let mut spaces = max_alerts.saturating_sub(live);
+        let primary_root = roots.primary().and_then(|root| root_nodes.get(root).ok());
+        if let Some(height_spaces) =
+            Self::height_spaces(primary_root, windows.get_single().ok(), &alert_nodes)
+        {
+            spaces = spaces.min(height_spaces);
+        }
+
+        if spillover.migrate {
+            // the oldest spilled alerts move back first, ahead of anything still queued
+            let mut spilled = displayed
+                .iter()
+                .filter(|(_, _, spilled, transition, _)| {
+                    *spilled && !transition.is_some_and(AlertTransition::is_leaving)
+                })
+                .collect::<Vec<_>>();
+            spilled.sort_by_key(|(.., timer)| std::cmp::Reverse(timer.elapsed()));
+            for (entity, ..) in spilled.into_iter().take(spaces) {
+                Self::unpromote_alert(&mut commands, entity)
+                    .remove::<AlertSpilled>()
+                    .insert(AlertRootKey::PRIMARY);
+                spaces -= 1;
+            }
+        }
+
+        let mut candidates = pending
+            .iter()
+            .filter(|(_, key, spilled, _)| *spilled || is_primary(*key))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(.., sequence)| sequence.copied());
+        for (index, (entity, _, spilled, _)) in candidates.into_iter().enumerate() {
+            let spill = index >= spaces;
+            if spill && !spilled {
+                commands
+                    .entity(entity)
+                    .insert((AlertRootKey::SPILLOVER, AlertSpilled));
+            } else if !spill && spilled {
+                commands
+                    .entity(entity)
+                    .remove::<AlertSpilled>()
+                    .insert(AlertRootKey::PRIMARY);
+            }
+        }
+    }
+
+    /// How many more cards fit under the primary root's `max_height`, once layout has run.
+    pub(crate) fn height_spaces(
+        root: Option<(&Node, &Style)>,
+        window: Option<&Window>,
+        alert_nodes: &AlertElements<M>,
+    ) -> Option<usize> {
+        let (node, style) = root?;
+        let window = window?;
+        let viewport = Vec2::new(window.width(), window.height());
+        let max_height = style.max_height.resolve(viewport.y, viewport).ok()?;
+        let card_height = alert_nodes
+            .alert()
+            .style
+            .min_height
+            .resolve(viewport.y, viewport)
+            .ok()?;
+        let gap = style.row_gap.resolve(viewport.y, viewport).unwrap_or(0.);
+        let free = max_height - node.size().y;
+        Some(((free + gap) / (card_height + gap)).floor().max(0.) as usize)
+    }
+
+    pub(crate) fn avoid_exclusion_zones(
+        mut roots: Query<&mut Style, (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+        windows: Query<&Window, With<PrimaryWindow>>,
+        zones: Res<AlertExclusionZones<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        let Ok(window) = windows.get_single() else {
+            return;
+        };
+        let size = Vec2::new(window.width(), window.height());
+        let bottom = zones.clear_bottom(&alert_nodes.root().style, size);
+        for mut style in &mut roots {
+            // only write on a difference so the root is not marked changed every frame
+            if style.bottom != bottom {
+                style.bottom = bottom;
+            }
+        }
+    }
+
+    pub(crate) fn update_group_rows(
+        mut commands: Commands,
+        rows: Query<(Entity, Ref<AlertGroupRows>)>,
+        groups: Query<Ref<AlertGroup>, (With<AlertManaged<M>>, With<AlertUi>)>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        for (entity, rows) in &rows {
+            let Ok(group) = groups.get(rows.alert) else {
+                continue;
+            };
+            if !rows.is_added() && !group.is_changed() {
+                continue;
+            }
+            if group.items.is_empty() {
+                commands.add(DismissAlert::<M>::new(
+                    rows.alert,
+                    AlertDismissReason::Resolved,
+                ));
+                continue;
+            }
+
+            let num_rows = group.max_rows.unwrap_or(usize::MAX).min(group.items.len());
+            commands
+                .entity(entity)
+                .despawn_descendants()
+                .with_children(|builder| {
+                    for item in group.items.iter().take(num_rows) {
+                        builder.spawn(AlertUi::text(
+                            item.clone(),
+                            alert_nodes.text().clone(),
+                            alert_nodes.linebreak,
+                        ));
+                    }
+                    let num_hidden = group.items.len() - num_rows;
+                    if num_hidden > 0 {
+                        builder.spawn(AlertUi::text(
+                            format!("…and {num_hidden} more"),
+                            alert_nodes.text().clone(),
+                            alert_nodes.linebreak,
+                        ));
+                    }
+                });
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn auto_collapse_stack(
+        mut commands: Commands,
+        mut roots: Query<
+            (Entity, &AlertRootKey, Option<&Interaction>, &mut Visibility),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUiRoot>,
+                Without<AlertStackCollapsed>,
+            ),
+        >,
+        mut badge_texts: Query<&mut Text, With<AlertCollapseBadgeText>>,
+        badges: Query<&Children, (With<AlertManaged<M>>, With<AlertCollapseBadge>)>,
+        alerts: Query<(), (With<AlertManaged<M>>, With<Alert>)>,
+        auto_collapse: Option<ResMut<AutoCollapse<M>>>,
+        alert_nodes: Res<AlertElements<M>>,
+        factory: Res<MarkerFactory<M>>,
+        time: Res<Time>,
+    ) {
+        let Some(mut auto_collapse) = auto_collapse else {
+            return;
+        };
+        let num_alerts = alerts.iter().count();
+
+        // keep the unread count current while collapsed
+        for children in &badges {
+            for child in children.iter() {
+                if let Ok(mut text) = badge_texts.get_mut(*child) {
+                    text.sections[0].value = num_alerts.to_string();
+                }
+            }
+        }
+
+        // only the primary root collapses into the badge
+        let Some((root, _, interaction, mut visibility)) = roots
+            .iter_mut()
+            .find(|(_, key, ..)| **key == AlertRootKey::PRIMARY)
+        else {
+            auto_collapse.idle.reset();
+            return;
+        };
+        let Some(interaction) = interaction else {
+            // the root needs an `Interaction` to notice the pointer
+            commands.entity(root).insert(Interaction::default());
+            return;
+        };
+
+        if !matches!(interaction, Interaction::None) {
+            auto_collapse.idle.reset();
+            return;
+        }
+        auto_collapse.idle.tick(time.delta());
+        if auto_collapse.idle.elapsed() < auto_collapse.after {
+            return;
+        }
+
+        *visibility = Visibility::Hidden;
+        commands.entity(root).insert(AlertStackCollapsed);
+        commands
+            .spawn((
+                AlertCollapseBadge,
+                Name::new("Alert Collapse Badge"),
+                alert_nodes.badge().clone(),
+                Interaction::default(),
+                factory.make(),
+                AlertManaged::<M>::default(),
+            ))
+            .with_children(|builder| {
+                builder.spawn((
+                    AlertCollapseBadgeText,
+                    Name::new("Alert Collapse Badge Text"),
+                    TextBundle::from_section(
+                        num_alerts.to_string(),
+                        alert_nodes.badge_text().clone(),
+                    ),
+                ));
+            });
+    }
+}
+
+/// Collapses the alert stack into a compact badge showing the number of unread alerts once the
+/// player has not interacted with it for `after`.
+///
+/// While collapsed, alert lifetimes are paused so nothing expires unseen. Clicking the badge
+/// expands the stack again. This resource is not inserted by default.
+#[derive(Debug, Resource, Reflect)]
+pub struct AutoCollapse<M: TypePath> {
+    pub after: Duration,
+    /// Whether a newly raised alert should expand a collapsed stack.
+    pub expand_on_alert: bool,
+    pub(crate) idle: Stopwatch,
+    #[reflect(ignore)]
+    marker: PhantomData<M>,
+}
+
+impl<M> AutoCollapse<M>
+where
+    M: TypePath,
+{
+    pub fn new(after: Duration) -> Self {
+        Self {
+            after,
+            expand_on_alert: false,
+            idle: Stopwatch::new(),
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Expands the stack whenever a new alert is raised.
+    pub fn expanding_on_alert(mut self) -> Self {
+        self.expand_on_alert = true;
+        self
+    }
+}
+
+/// The alerts UI root entity for this marker, if one currently exists.
+///
+/// This is kept in sync with `AlertRootSpawned` and `AlertRootDespawned`.
+#[derive(Debug, Resource, Reflect)]
+pub struct CurrentAlertRoot<M: TypePath>(
+    pub(crate) Option<Entity>,
+    #[reflect(ignore)] PhantomData<M>,
+);
+
+impl<M> CurrentAlertRoot<M>
+where
+    M: TypePath,
+{
+    pub fn get(&self) -> Option<Entity> {
+        self.0
+    }
+}
+
+impl<M> Default for CurrentAlertRoot<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self(None, PhantomData::<M>)
+    }
+}
+
+/// Selects which alerts UI root an alert is placed under. Alerts without one go to the primary root.
+///
+/// Each key gets its own root with its own `MaxAlerts` budget, and a root is only despawned once
+/// the alerts routed to it are gone.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Component, Reflect)]
+pub struct AlertRootKey(pub Cow<'static, str>);
+
+impl AlertRootKey {
+    pub const PRIMARY: Self = Self(Cow::Borrowed("primary"));
+    /// The root that alerts overflow into under a `SpilloverAnchor`.
+    pub const SPILLOVER: Self = Self(Cow::Borrowed("spillover"));
+
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self(key.into())
+    }
+}
+
+impl Default for AlertRootKey {
+    fn default() -> Self {
+        Self::PRIMARY
+    }
+}
+
+/// A second root that alerts overflow into once the primary root is full, instead of waiting.
+///
+/// The primary root is full once it holds `MaxAlerts` cards or, after layout has run, once another
+/// card would not fit within its `max_height`. With `migrate`, spilled alerts move back to the
+/// primary root as space frees up, oldest first; otherwise they stay put until they leave, and the
+/// spillover root is despawned once it empties.
+#[derive(Debug, Resource, Reflect)]
+pub struct SpilloverAnchor<M: TypePath> {
+    pub anchor: Option<AlertAnchor>,
+    pub migrate: bool,
+    #[reflect(ignore)]
+    marker: PhantomData<M>,
+}
+
+impl<M> SpilloverAnchor<M>
+where
+    M: TypePath,
+{
+    pub fn new(anchor: Option<AlertAnchor>) -> Self {
+        Self {
+            anchor,
+            migrate: false,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub fn migrating(mut self) -> Self {
+        self.migrate = true;
+        self
+    }
+
+    pub(crate) fn root_node(
+        &self,
+        key: &AlertRootKey,
+        alert_nodes: &AlertElements<M>,
+    ) -> NodeBundle {
+        let mut node = alert_nodes.root().clone();
+        if let (true, Some(anchor)) = (*key == AlertRootKey::SPILLOVER, self.anchor) {
+            anchor.place(&mut node.style);
+        }
+        node
+    }
+}
+
+/// Marks an alert that overflowed into the `SpilloverAnchor` root.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertSpilled;
+
+/// The alerts UI root entities for this marker, by `AlertRootKey`.
+#[derive(Debug, Resource)]
+pub struct AlertRoots<M> {
+    pub(crate) roots: HashMap<AlertRootKey, Entity>,
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertRoots<M> {
+    pub fn get(&self, key: &AlertRootKey) -> Option<Entity> {
+        self.roots.get(key).copied()
+    }
+
+    pub fn primary(&self) -> Option<Entity> {
+        self.get(&AlertRootKey::PRIMARY)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&AlertRootKey, Entity)> {
+        self.roots.iter().map(|(key, root)| (key, *root))
+    }
+}
+
+impl<M> Default for AlertRoots<M> {
+    fn default() -> Self {
+        Self {
+            roots: HashMap::default(),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// An event sent when the plugin spawns the `AlertUiRoot` for this marker.
+///
+/// Use this to decorate the root with your own components.
+#[derive(Event)]
+pub struct AlertRootSpawned<M: Component>(pub Entity, pub(crate) PhantomData<M>);
+
+/// An event sent when the plugin despawns the `AlertUiRoot` for this marker.
+#[derive(Event)]
+pub struct AlertRootDespawned<M: Component>(pub Entity, pub(crate) PhantomData<M>);
+
+/// Screen regions, in logical pixels from the top-left of the primary window, that the alerts root
+/// of this kind must not cover.
+///
+/// When the root would overlap a zone, it is raised until its bottom edge sits above the zone. This
+/// is re-evaluated every frame, so zones may change and the window may resize at any time. Other
+/// plugins can register their own zones by name.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{AlertExclusionZones, AlertMarker};
+///
+/// let mut app = App::new();
+/// app.init_resource::<AlertExclusionZones<AlertMarker>>();
+/// app.world_mut()
+///     .resource_mut::<AlertExclusionZones<AlertMarker>>()
+///     .insert("minimap", Rect::new(1080., 520., 1280., 720.));
+/// ```
+#[derive(Debug, Resource)]
+pub struct AlertExclusionZones<M> {
+    zones: HashMap<Cow<'static, str>, Rect>,
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertExclusionZones<M> {
+    pub fn insert(&mut self, name: impl Into<Cow<'static, str>>, zone: Rect) -> &mut Self {
+        self.zones.insert(name.into(), zone);
+        self
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Rect> {
+        self.zones.remove(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Rect)> {
+        self.zones.iter().map(|(name, zone)| (name.as_ref(), *zone))
+    }
+
+    /// The `bottom` offset that keeps a root styled with `style` clear of every zone, in a window
+    /// of `size`.
+    pub fn clear_bottom(&self, style: &Style, size: Vec2) -> Val {
+        fn resolve(val: Val, extent: f32) -> Option<f32> {
+            match val {
+                Val::Px(px) => Some(px),
+                Val::Percent(percent) => Some(percent / 100. * extent),
+                _ => None,
+            }
+        }
+
+        let left = resolve(style.left, size.x).unwrap_or(0.);
+        let right = size.x - resolve(style.right, size.x).unwrap_or(0.);
+        let height = resolve(style.max_height, size.y).unwrap_or(size.y);
+        let mut bottom = resolve(style.bottom, size.y).unwrap_or(0.);
+        let mut raised = false;
+        // raising the root past one zone may run it into another
+        for _ in 0..self.zones.len() {
+            let base = size.y - bottom;
+            let Some(zone) = self.zones.values().find(|zone| {
+                zone.min.x < right
+                    && zone.max.x > left
+                    && zone.min.y < base
+                    && zone.max.y > base - height
+            }) else {
+                break;
+            };
+            bottom = size.y - zone.min.y;
+            raised = true;
+        }
+
+        if raised {
+            Val::Px(bottom)
+        } else {
+            style.bottom
+        }
+    }
+}
+
+impl<M> Default for AlertExclusionZones<M> {
+    fn default() -> Self {
+        Self {
+            zones: HashMap::default(),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_root_lifecycle() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            500,
+        )));
+        for _ in 0..3 {
+            app.world_mut().spawn((Alert::bundle("Burst"), AlertMarker));
+        }
+        app.update();
+        let root = app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .expect("a root should be spawned with the first alerts");
+        assert!(app.world().get::<AlertUiRoot>(root).is_some());
+        let events = app
+            .world()
+            .resource::<Events<AlertRootSpawned<AlertMarker>>>();
+        let spawned = events
+            .get_reader()
+            .read(events)
+            .map(|event| event.0)
+            .collect::<Vec<_>>();
+        assert_eq!(spawned, vec![root]);
+
+        for _ in 0..10 {
+            app.update();
+            if app
+                .world()
+                .resource::<CurrentAlertRoot<AlertMarker>>()
+                .get()
+                .is_none()
+            {
+                break;
+            }
+        }
+        assert!(app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .is_none());
+        assert!(app.world().get_entity(root).is_none());
+        let events = app
+            .world()
+            .resource::<Events<AlertRootDespawned<AlertMarker>>>();
+        let despawned = events
+            .get_reader()
+            .read(events)
+            .map(|event| event.0)
+            .collect::<Vec<_>>();
+        assert_eq!(despawned, vec![root]);
+    }
+
+    #[test]
+    fn test_keyed_roots() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            500,
+        )));
+        let minimap = AlertRootKey::new("minimap");
+
+        app.world_mut()
+            .spawn((Alert::bundle("primary"), AlertMarker));
+        for _ in 0..3 {
+            app.update();
+        }
+        app.world_mut()
+            .spawn((Alert::bundle("minimap"), AlertMarker, minimap.clone()));
+        app.update();
+
+        let roots = app.world().resource::<AlertRoots<AlertMarker>>();
+        let primary = roots.primary().expect("primary root exists");
+        let minimap_root = roots.get(&minimap).expect("minimap root exists");
+        assert_ne!(primary, minimap_root);
+
+        // the primary alert drains first, but the minimap root keeps its own alert
+        app.update();
+        app.update();
+
+        let roots = app.world().resource::<AlertRoots<AlertMarker>>();
+        assert!(roots.primary().is_none());
+        assert!(app.world().get_entity(primary).is_none());
+        assert_eq!(roots.get(&minimap), Some(minimap_root));
+        assert!(app.world().get_entity(minimap_root).is_some());
+    }
+
+    #[test]
+    fn test_spillover_anchor() {
+        for migrate in [true, false] {
+            let mut app = manual_app();
+            app.insert_resource(MaxAlerts::<AlertMarker>::new(2));
+            app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+                500,
+            )));
+            let spillover = SpilloverAnchor::<AlertMarker>::new(Some(AlertAnchor::BottomLeft));
+            app.insert_resource(if migrate {
+                spillover.migrating()
+            } else {
+                spillover
+            });
+            let busy = app.world_mut().spawn_empty().id();
+            let brief = (0..2)
+                .map(|index| {
+                    app.world_mut()
+                        .spawn((Alert::bundle(format!("brief {index}")), AlertMarker))
+                        .id()
+                })
+                .collect::<Vec<_>>();
+            let kept = (0..2)
+                .map(|index| {
+                    app.world_mut()
+                        .spawn((
+                            Alert::bundle(format!("kept {index}")),
+                            AlertMarker,
+                            KeepAliveWhile(busy),
+                        ))
+                        .id()
+                })
+                .collect::<Vec<_>>();
+            app.update();
+
+            let parent =
+                |app: &App, entity: Entity| app.world().get::<Parent>(entity).map(Parent::get);
+            let roots = app.world().resource::<AlertRoots<AlertMarker>>();
+            let primary = roots.primary().unwrap();
+            let spillover_root = roots.get(&AlertRootKey::SPILLOVER).unwrap();
+            assert_ne!(primary, spillover_root);
+            assert_eq!(
+                app.world().get::<Style>(spillover_root).unwrap().left,
+                Val::Px(24.)
+            );
+            for entity in &brief {
+                assert_eq!(parent(&app, *entity), Some(primary));
+            }
+            for entity in &kept {
+                assert_eq!(parent(&app, *entity), Some(spillover_root));
+            }
+
+            // the brief alerts expire and fade out, freeing the primary root
+            for _ in 0..8 {
+                app.update();
+            }
+            let roots = app.world().resource::<AlertRoots<AlertMarker>>();
+            if migrate {
+                assert!(roots.get(&AlertRootKey::SPILLOVER).is_none());
+                let primary = roots.primary().unwrap();
+                for entity in &kept {
+                    assert_eq!(parent(&app, *entity), Some(primary));
+                    assert!(app.world().get::<AlertSpilled>(*entity).is_none());
+                    // the parts point into the rebuilt card
+                    let parts = app.world().get::<AlertParts>(*entity).unwrap();
+                    assert_eq!(parent(&app, parts.header()), Some(*entity));
+                }
+            } else {
+                assert!(roots.primary().is_none());
+                assert_eq!(roots.get(&AlertRootKey::SPILLOVER), Some(spillover_root));
+                for entity in &kept {
+                    assert_eq!(parent(&app, *entity), Some(spillover_root));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exclusion_zones() {
+        use bevy::window::WindowResolution;
+
+        let mut app = manual_app();
+        app.world_mut().spawn((
+            Window {
+                resolution: WindowResolution::new(1000., 800.),
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ));
+        app.world_mut()
+            .resource_mut::<AlertExclusionZones<AlertMarker>>()
+            .insert("minimap", Rect::new(800., 600., 1000., 800.));
+        app.world_mut()
+            .spawn((Alert::bundle("clear of the minimap"), AlertMarker));
+        app.update();
+        app.update();
+
+        let root = app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .unwrap();
+        assert_eq!(
+            app.world().get::<Style>(root).unwrap().bottom,
+            Val::Px(200.)
+        );
+
+        app.world_mut()
+            .resource_mut::<AlertExclusionZones<AlertMarker>>()
+            .remove("minimap");
+        app.update();
+        assert_eq!(app.world().get::<Style>(root).unwrap().bottom, Val::Px(24.));
+    }
+
+    #[test]
+    fn test_small_window_layout() {
+        use bevy::window::WindowResolution;
+
+        let mut app = manual_app();
+        let window = app
+            .world_mut()
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(800., 600.),
+                    ..Default::default()
+                },
+                PrimaryWindow,
+            ))
+            .id();
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("tiny"), AlertMarker))
+            .id();
+        app.update();
+
+        let root = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Style, With<AlertUiRoot>>()
+                .single(app.world())
+                .clone()
+        };
+        let card = |app: &App| app.world().get::<Style>(alert).unwrap().clone();
+        let header = |app: &App| {
+            let parts = app.world().get::<AlertParts>(alert).unwrap();
+            let header = app.world().get::<Style>(parts.header()).unwrap().clone();
+            let dismiss = app.world().get::<Style>(parts.dismiss_button()).unwrap();
+            (header.height, dismiss.min_width)
+        };
+        assert_eq!(root(&mut app).left, Val::Percent(70.));
+        assert_eq!(card(&app).width, Val::Percent(80.));
+        assert!(!app
+            .world()
+            .resource::<AlertScaling<AlertMarker>>()
+            .is_small_window());
+
+        // shrinking below the threshold switches the live stack to the banner layout
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set(250., 600.);
+        app.update();
+        assert!(app
+            .world()
+            .resource::<AlertScaling<AlertMarker>>()
+            .is_small_window());
+        let banner = SmallWindowLayout::default();
+        assert_eq!(root(&mut app).top, banner.root.top);
+        assert_eq!(root(&mut app).width, Val::Percent(100.));
+        assert_eq!(card(&app).width, Val::Percent(100.));
+        assert_eq!(header(&app), (Val::Px(44.), Val::Px(44.)));
+
+        // alerts spawned in the small window use it too
+        let other = app
+            .world_mut()
+            .spawn((Alert::bundle("also tiny"), AlertMarker))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().get::<Style>(other).unwrap().width,
+            Val::Percent(100.)
+        );
+
+        // growing again restores the regular layout
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set(800., 600.);
+        app.update();
+        assert!(!app
+            .world()
+            .resource::<AlertScaling<AlertMarker>>()
+            .is_small_window());
+        assert_eq!(root(&mut app).left, Val::Percent(70.));
+        assert_eq!(card(&app).width, Val::Percent(80.));
+        assert_eq!(
+            header(&app),
+            (
+                Val::Px(AlertDefaults::HEADER_HEIGHT),
+                Val::Px(AlertDefaults::HEADER_HEIGHT)
+            )
+        );
+    }
+
+    #[test]
+    fn test_alert_group_rows() {
+        let mut app = manual_app();
+        let items = (1..=6).map(|i| format!("Field {i} is invalid")).collect();
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("6 problems found"),
+                AlertGroup::new(items).with_max_rows(4),
+                AlertMarker,
+            ))
+            .id();
+        let row_texts = |app: &mut App| {
+            let mut rows = app.world_mut().query::<(&AlertGroupRows, &Children)>();
+            let (_, children) = rows.single(app.world());
+            children
+                .iter()
+                .map(|child| {
+                    app.world().get::<Text>(*child).unwrap().sections[0]
+                        .value
+                        .clone()
+                })
+                .collect::<Vec<_>>()
+        };
+        app.update();
+        assert_eq!(
+            row_texts(&mut app),
+            vec![
+                "Field 1 is invalid",
+                "Field 2 is invalid",
+                "Field 3 is invalid",
+                "Field 4 is invalid",
+                "…and 2 more",
+            ]
+        );
+
+        app.world_mut()
+            .get_mut::<AlertGroup>(alert)
+            .unwrap()
+            .resolve_item(0);
+        app.update();
+        assert_eq!(
+            row_texts(&mut app),
+            vec![
+                "Field 2 is invalid",
+                "Field 3 is invalid",
+                "Field 4 is invalid",
+                "Field 5 is invalid",
+                "…and 1 more",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_auto_collapse() {
+        let mut app = manual_app();
+        app.insert_resource(
+            AutoCollapse::<AlertMarker>::new(Duration::from_secs(2)).expanding_on_alert(),
+        );
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("Unattended"), AlertMarker))
+            .id();
+        let collapsed_roots = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), (With<AlertUiRoot>, With<AlertStackCollapsed>)>()
+                .iter(app.world())
+                .count()
+        };
+        // t: 0s to 2.5s, idle time accumulates
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(collapsed_roots(&mut app), 1);
+        let mut badges = app
+            .world_mut()
+            .query_filtered::<Entity, With<AlertCollapseBadge>>();
+        assert_eq!(badges.iter(app.world()).count(), 1);
+
+        // lifetimes are paused while collapsed
+        let elapsed = app
+            .world()
+            .get::<AlertTimer>(alert)
+            .unwrap()
+            .time_alive
+            .elapsed();
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            app.world()
+                .get::<AlertTimer>(alert)
+                .unwrap()
+                .time_alive
+                .elapsed(),
+            elapsed
+        );
+
+        // a new alert forces the stack open again
+        app.world_mut()
+            .spawn((Alert::bundle("Urgent"), AlertMarker));
+        app.update();
+        assert_eq!(collapsed_roots(&mut app), 0);
+        assert_eq!(badges.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_bulk_despawn_recovery() {
+        let mut app = manual_app();
+        app.world_mut()
+            .spawn((Alert::bundle("before reload"), AlertMarker));
+        app.update();
+        let stale_root = app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .unwrap();
+
+        // a scene reload that knows nothing about alerts
+        app.world_mut().clear_entities();
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("after reload"), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+        let root = app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .unwrap();
+        assert_ne!(root, stale_root);
+        assert!(app.world().get::<AlertUiRoot>(root).is_some());
+        assert_eq!(
+            app.world().get::<Parent>(alert).map(Parent::get),
+            Some(root)
+        );
+
+        app.world_mut()
+            .spawn((Alert::bundle("pending"), AlertMarker, AlertPending));
+        AlertsReset::<AlertMarker>::new().apply(app.world_mut());
+        let mut alerts = app.world_mut().query::<&Alert>();
+        assert_eq!(alerts.iter(app.world()).count(), 0);
+        assert!(app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .is_none());
+        assert!(app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .is_none());
+    }
+}
@@ -0,0 +1,283 @@
+use super::*;
+
+/// A command that returns every piece of plugin state for this kind of alert to a clean slate,
+/// despawning its alerts, roots and collapse badge along the way.
+///
+/// Queue this after bulk despawns such as scene reloads. Without it, roots and cached entities
+/// that were despawned from outside are recreated as alerts need them, but events like
+/// `AlertRootDespawned` and `AlertQueueChanged` only catch up over the next frame.
+pub struct AlertsReset<M>(PhantomData<M>);
+
+impl<M> AlertsReset<M> {
+    pub fn new() -> Self {
+        Self(PhantomData::<M>)
+    }
+}
+
+impl<M> Default for AlertsReset<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Command for AlertsReset<M>
+where
+    M: Component + TypePath,
+{
+    fn apply(self, world: &mut World) {
+        // alerts spawned since the last adoption go too
+        world.run_system_once(AlertsPlugin::<M>::adopt_alerts);
+        world.run_system_once(
+            |mut commands: Commands,
+             roots: Query<Entity, (With<AlertManaged<M>>, Without<Alert>)>,
+             undisplayed_alerts: Query<
+                Entity,
+                (With<Alert>, With<AlertManaged<M>>, Without<AlertUi>),
+            >| {
+                // displayed alerts go with their roots
+                for entity in roots.iter().chain(&undisplayed_alerts) {
+                    commands.entity(entity).despawn_recursive();
+                }
+            },
+        );
+        world.insert_resource(AlertRoots::<M>::default());
+        world.insert_resource(CurrentAlertRoot::<M>::default());
+        world.insert_resource(PendingCount::<M>::default());
+        world.insert_resource(AlertStatus::<M>::default());
+        if let Some(mut limit) = world.get_resource_mut::<MaxConcurrentTransitions<M>>() {
+            limit.queue.clear();
+            limit.active.clear();
+        }
+    }
+}
+
+/// Pauses every alert system of this kind while present. See `AlertStackSnapshot`.
+#[derive(Resource)]
+pub(crate) struct AlertsFrozen<M>(PhantomData<M>);
+
+/// A displayed alert whose UI was despawned by `AlertStackSnapshot::despawn_for_screenshot`.
+struct RetainedAlert {
+    entity: Entity,
+    transition: Option<AlertTransition>,
+    timer: Option<TransitionTimer>,
+}
+
+/// A frozen alert stack, for photo modes and screenshots.
+///
+/// `freeze` pauses every alert system of this kind, including timers, transitions and
+/// promotion, and hides the roots. `thaw` shows them again and everything resumes from exactly
+/// where it stopped. To take the cards out of the UI entirely, call `despawn_for_screenshot`
+/// while frozen, and `restore` before thawing to rebuild them mid-transition as they were.
+pub struct AlertStackSnapshot<M> {
+    hidden: Vec<(Entity, Visibility)>,
+    retained: Vec<RetainedAlert>,
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertStackSnapshot<M>
+where
+    M: Component + TypePath,
+{
+    pub fn freeze(world: &mut World) -> Self {
+        world.insert_resource(AlertsFrozen::<M>(PhantomData));
+        // alerts the user already dismissed don't stay behind in the frozen stack
+        let mut dismissed = world.query_filtered::<(Entity, &AlertDismissReason), (
+            With<AlertManaged<M>>,
+            With<AlertUi>,
+        )>();
+        let dismissed = dismissed
+            .iter(world)
+            .filter(|(_, reason)| **reason == AlertDismissReason::Dismissed)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+        for entity in dismissed {
+            world.entity_mut(entity).despawn_recursive();
+        }
+        let mut nodes = world.query_filtered::<(Entity, &mut Visibility), (
+            With<AlertManaged<M>>,
+            Or<(With<AlertUiRoot>, With<AlertCollapseBadge>)>,
+        )>();
+        let hidden = nodes
+            .iter_mut(world)
+            .map(|(entity, mut visibility)| {
+                let previous = *visibility;
+                *visibility = Visibility::Hidden;
+                (entity, previous)
+            })
+            .collect();
+        Self {
+            hidden,
+            retained: Vec::new(),
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub fn thaw(mut self, world: &mut World) {
+        if !self.retained.is_empty() {
+            self.restore(world);
+        }
+        for (entity, visibility) in self.hidden {
+            if let Some(mut previous) = world.get_mut::<Visibility>(entity) {
+                *previous = visibility;
+            }
+        }
+        world.remove_resource::<AlertsFrozen<M>>();
+    }
+
+    /// Despawns the UI of every displayed alert and the roots holding them. The alert entities
+    /// are kept and queued again, and their lifetimes and transition progress are remembered.
+    pub fn despawn_for_screenshot(&mut self, world: &mut World) {
+        let retained = world.run_system_once(
+            |mut commands: Commands,
+             roots: Query<&Children, (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+             roots_to_despawn: Query<Entity, (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+             alerts: Query<
+                (Option<&AlertTransition>, Option<&TransitionTimer>),
+                (With<AlertManaged<M>>, With<AlertUi>),
+            >| {
+                let mut retained = Vec::new();
+                for children in &roots {
+                    for &entity in children {
+                        let Ok((transition, timer)) = alerts.get(entity) else {
+                            continue;
+                        };
+                        retained.push(RetainedAlert {
+                            entity,
+                            transition: transition.cloned(),
+                            timer: timer.cloned(),
+                        });
+                        AlertsPlugin::<M>::unpromote_alert(&mut commands, entity);
+                    }
+                }
+                for root in &roots_to_despawn {
+                    commands.entity(root).despawn_recursive();
+                }
+                retained
+            },
+        );
+        self.retained.extend(retained);
+    }
+
+    /// Rebuilds the cards despawned by `despawn_for_screenshot`, in their previous order and at
+    /// their previous transition progress. Thawing restores them as well.
+    pub fn restore(&mut self, world: &mut World) {
+        // only the retained alerts are promoted; anything else waiting stays pending
+        let mut pending =
+            world.query_filtered::<Entity, (With<AlertManaged<M>>, With<AlertPending>)>();
+        let waiting = pending
+            .iter(world)
+            .filter(|&entity| {
+                !self
+                    .retained
+                    .iter()
+                    .any(|retained| retained.entity == entity)
+            })
+            .collect::<Vec<_>>();
+        for &entity in &waiting {
+            world.entity_mut(entity).remove::<AlertPending>();
+        }
+        world.run_system_once(AlertsPlugin::<M>::spawn_alerts);
+        for entity in waiting {
+            if let Some(mut entity) = world.get_entity_mut(entity) {
+                entity.insert(AlertPending);
+            }
+        }
+
+        for retained in self.retained.drain(..) {
+            let Some(mut entity) = world.get_entity_mut(retained.entity) else {
+                continue;
+            };
+            let left = match (&retained.transition, &retained.timer) {
+                (Some(transition), Some(timer)) => transition.offset(timer.completion()),
+                (Some(transition), None) => transition.offset(0.),
+                (None, _) => 0.,
+            };
+            if let Some(mut style) = entity.get_mut::<Style>() {
+                style.left = Val::Percent(left * 100.);
+            }
+            match retained.transition {
+                Some(transition) => entity.insert(transition),
+                None => entity.remove::<AlertTransition>(),
+            };
+            match retained.timer {
+                Some(timer) => entity.insert(timer),
+                None => entity.remove::<TransitionTimer>(),
+            };
+            // re-append in the retained order so the stack reads as it did
+            if let Some(parent) = entity.get::<Parent>().map(Parent::get) {
+                world.entity_mut(parent).push_children(&[retained.entity]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_stack_snapshot() {
+        let completion = |app: &App, alert: Entity| {
+            app.world()
+                .get::<TransitionTimer>(alert)
+                .unwrap()
+                .completion()
+        };
+        // thawing restores despawned cards that were not restored explicitly
+        for (despawn, restore) in [(false, false), (true, true), (true, false)] {
+            let mut app = manual_app();
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+            let alert = app
+                .world_mut()
+                .spawn((Alert::bundle("frozen"), AlertMarker))
+                .id();
+            // promoted on the first frame, then 200ms into a 500ms fade-in
+            for _ in 0..3 {
+                app.update();
+            }
+            assert!((completion(&app, alert) - 0.4).abs() < 1e-4);
+
+            let mut snapshot = AlertStackSnapshot::<AlertMarker>::freeze(app.world_mut());
+            let root = app
+                .world()
+                .resource::<AlertRoots<AlertMarker>>()
+                .primary()
+                .unwrap();
+            assert_eq!(
+                app.world().get::<Visibility>(root),
+                Some(&Visibility::Hidden)
+            );
+            if despawn {
+                snapshot.despawn_for_screenshot(app.world_mut());
+                app.update();
+                assert!(app.world().get_entity(root).is_none());
+                assert!(app.world().get::<AlertUi>(alert).is_none());
+                assert!(app.world().get::<AlertParts>(alert).is_none());
+                assert!(app.world().get::<AlertPending>(alert).is_some());
+            }
+            for _ in 0..5 {
+                app.update();
+            }
+            if restore {
+                snapshot.restore(app.world_mut());
+                assert!(app.world().get::<AlertUi>(alert).is_some());
+            }
+            if !despawn || restore {
+                assert!((completion(&app, alert) - 0.4).abs() < 1e-4);
+            }
+
+            snapshot.thaw(app.world_mut());
+            app.update();
+            assert!((completion(&app, alert) - 0.6).abs() < 1e-4);
+            assert!(matches!(
+                app.world().get::<AlertTransition>(alert),
+                Some(AlertTransition::FadeIn)
+            ));
+        }
+    }
+}
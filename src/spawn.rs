@@ -0,0 +1,3573 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn spawn_alert_events(
+        mut commands: Commands,
+        mut events: EventReader<AlertEvent<M>>,
+        factory: Res<MarkerFactory<M>>,
+    ) {
+        for event in events.read() {
+            commands.spawn((Alert::bundle(event.message.clone()), factory.make()));
+        }
+    }
+
+    /// Shows the `AlertRepeatCount` of alerts in a badge in their header, and pops the badge
+    /// when a shown alert is repeated.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn show_repeat_counts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (Entity, &AlertRepeatCount, &mut AlertParts),
+            (
+                With<AlertManaged<M>>,
+                With<AlertUi>,
+                Or<(Changed<AlertRepeatCount>, Added<AlertParts>)>,
+            ),
+        >,
+        mut texts: Query<&mut Text>,
+        alert_nodes: Res<AlertElements<M>>,
+        reduced_motion: Res<ReducedMotion<M>>,
+    ) {
+        for (entity, count, mut parts) in &mut alerts {
+            // alerts shown with a count already have nothing new to point out
+            if !parts.is_added() && !reduced_motion.enabled {
+                commands.entity(entity).insert(AlertRepeatPulse::default());
+            }
+            let label = format!("x{}", count.0);
+            if let Some(mut text) = parts.repeat_count.and_then(|text| texts.get_mut(text).ok()) {
+                text.sections[0].value = label;
+                continue;
+            }
+            if commands.get_entity(parts.header).is_none() {
+                continue;
+            }
+            let mut text = Entity::PLACEHOLDER;
+            let badge = commands
+                .spawn((
+                    Name::new("Alert Repeat Count"),
+                    alert_nodes.repeat_count().clone(),
+                ))
+                .with_children(|builder| {
+                    text = builder
+                        .spawn(TextBundle::from_section(
+                            label,
+                            alert_nodes.repeat_count_text().clone(),
+                        ))
+                        .id();
+                })
+                .id();
+            // after the spinner, title and timestamp, before the header buttons
+            let index = [parts.spinner, parts.title, parts.timestamp]
+                .into_iter()
+                .flatten()
+                .count();
+            commands
+                .entity(parts.header)
+                .insert_children(index, &[badge]);
+            parts.repeat_count = Some(text);
+        }
+    }
+
+    /// Resizes the progress bars of shown alerts whose `AlertProgress` changed.
+    pub(crate) fn update_progress_bars(
+        alerts: Query<
+            (&AlertProgress, &AlertParts),
+            (With<AlertManaged<M>>, Changed<AlertProgress>),
+        >,
+        mut styles: Query<&mut Style>,
+    ) {
+        for (progress, parts) in &alerts {
+            let Some(mut style) = parts.progress_bar.and_then(|bar| styles.get_mut(bar).ok())
+            else {
+                continue;
+            };
+            if style.width != progress.bar_width() {
+                style.width = progress.bar_width();
+            }
+        }
+    }
+
+    /// Shows a spinner at the start of the header of each `UnresolvedAlert`, advances it, and takes
+    /// it away once the alert is resolved.
+    pub(crate) fn update_alert_spinners(
+        mut commands: Commands,
+        mut alerts: Query<(&mut AlertParts, Has<UnresolvedAlert>), With<AlertManaged<M>>>,
+        mut spinners: Query<(&mut AlertSpinner, &mut Text)>,
+        alert_nodes: Res<AlertElements<M>>,
+        time: Res<Time>,
+    ) {
+        for (mut parts, unresolved) in &mut alerts {
+            match (parts.spinner, unresolved) {
+                (None, true) => {
+                    let spinner = commands
+                        .spawn((
+                            Name::new("Alert Spinner"),
+                            AlertSpinner::default(),
+                            TextBundle::from_section(
+                                AlertSpinner::FRAMES[0],
+                                alert_nodes.spinner().clone(),
+                            )
+                            .with_style(Style {
+                                align_self: AlignSelf::Center,
+                                margin: UiRect::horizontal(Val::Px(4.)),
+                                flex_shrink: 0.,
+                                ..Default::default()
+                            }),
+                        ))
+                        .id();
+                    commands.entity(parts.header).insert_children(0, &[spinner]);
+                    parts.spinner = Some(spinner);
+                }
+                (Some(spinner), true) => {
+                    let Ok((mut spinner, mut text)) = spinners.get_mut(spinner) else {
+                        continue;
+                    };
+                    if spinner.0.tick(time.delta()).just_finished() {
+                        let frames = spinner.0.times_finished_this_tick() as usize;
+                        let frame = AlertSpinner::FRAMES
+                            .iter()
+                            .position(|frame| text.sections[0].value == *frame)
+                            .map_or(0, |frame| (frame + frames) % AlertSpinner::FRAMES.len());
+                        text.sections[0].value = AlertSpinner::FRAMES[frame].to_string();
+                    }
+                }
+                (Some(spinner), false) => {
+                    commands.entity(spinner).despawn_recursive();
+                    parts.spinner = None;
+                }
+                (None, false) => {}
+            }
+        }
+    }
+
+    /// Rewrites the text of shown alerts whose `Alert` changed, starting an `AlertCrossFade` to
+    /// the new message when enabled.
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
+    pub(crate) fn update_alert_texts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                &Alert,
+                &AlertParts,
+                Option<&AlertRichText>,
+                Option<&mut TypewriterReveal>,
+                Has<AlertExpanded>,
+                Option<&AlertTextFade>,
+            ),
+            (With<AlertManaged<M>>, Changed<Alert>),
+        >,
+        mut texts: Query<&mut Text, Or<(With<AlertText>, With<AlertTitle>)>>,
+        layout: Query<(&Node, &GlobalTransform, Option<&Parent>)>,
+        alert_nodes: Res<AlertElements<M>>,
+        text_limits: Res<AlertTextLimits<M>>,
+        (cross_fade, reduced_motion): (Res<AlertCrossFade<M>>, Res<ReducedMotion<M>>),
+    ) {
+        for (entity, alert, parts, rich_text, reveal, expanded, fade) in &mut alerts {
+            if let (Some(title), Some(value)) = (parts.title, alert.title()) {
+                if let Ok(mut text) = texts.get_mut(title) {
+                    if text.sections.len() != 1 || text.sections[0].value != value {
+                        text.sections = vec![TextSection::new(value, alert_nodes.title().clone())];
+                    }
+                }
+            }
+            // rich text stays as long as it is still the message
+            if rich_text.is_some_and(|rich| rich.message() == alert.message()) {
+                continue;
+            }
+            let message = if expanded {
+                alert.message().to_string()
+            } else {
+                text_limits.display_text(alert.message())
+            };
+            if let Some(mut reveal) = reveal.filter(|reveal| !reveal.done) {
+                // the reveal renders its own message as it goes
+                reveal.message = message;
+                continue;
+            }
+            let Ok(mut text) = texts.get_mut(parts.text) else {
+                continue;
+            };
+            let unchanged = text.sections.len() == 1 && text.sections[0].value == message;
+            if unchanged {
+                continue;
+            }
+            let sections = vec![TextSection::new(message, alert_nodes.text().clone())];
+            let outgoing = std::mem::replace(&mut text.sections, sections);
+            if reduced_motion.enabled {
+                continue;
+            }
+            let Some(duration) = cross_fade.duration else {
+                continue;
+            };
+            // the old message is laid over the new one, where it was, out of the layout
+            let Some((parent, offset, size)) =
+                layout
+                    .get(parts.text)
+                    .ok()
+                    .and_then(|(node, transform, parent)| {
+                        let parent = parent?.get();
+                        let (parent_node, parent_transform, _) = layout.get(parent).ok()?;
+                        let corner = |node: &Node, transform: &GlobalTransform| {
+                            transform.translation().truncate() - node.size() / 2.
+                        };
+                        let offset =
+                            corner(node, transform) - corner(parent_node, parent_transform);
+                        Some((parent, offset, node.size()))
+                    })
+            else {
+                continue;
+            };
+            if let Some(fade) = fade {
+                commands.entity(fade.outgoing).despawn_recursive();
+            }
+            let mut old_text = Text::from_sections(outgoing).with_justify(text.justify);
+            old_text.linebreak_behavior = text.linebreak_behavior;
+            let outgoing = commands
+                .spawn((
+                    Name::new("Alert Fading Text"),
+                    AlertFadingText,
+                    TextBundle {
+                        text: old_text,
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(offset.x),
+                            top: Val::Px(offset.y),
+                            width: Val::Px(size.x),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                ))
+                .id();
+            commands.entity(parent).add_child(outgoing);
+            commands.entity(entity).insert(AlertTextFade {
+                timer: TransitionTimer::with_duration(duration),
+                outgoing,
+                from_height: size.y,
+            });
+        }
+    }
+
+    pub(crate) fn timestamp_alerts(
+        mut commands: Commands,
+        alerts: Query<Entity, (With<Alert>, Added<AlertManaged<M>>, Without<AlertTimestamp>)>,
+        time: Res<Time>,
+    ) {
+        for entity in &alerts {
+            commands.entity(entity).insert(AlertTimestamp::now(&time));
+        }
+    }
+
+    /// Adds the timestamp text to the header of newly shown alerts, left of the header buttons.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn show_alert_timestamps(
+        mut commands: Commands,
+        mut alerts: Query<
+            (&AlertTimestamp, &mut AlertParts),
+            (With<AlertManaged<M>>, Added<AlertParts>),
+        >,
+        alert_nodes: Res<AlertElements<M>>,
+        timestamps: Res<AlertTimestamps<M>>,
+    ) {
+        for (timestamp, mut parts) in &mut alerts {
+            let text = commands
+                .spawn((
+                    Name::new("Alert Timestamp"),
+                    AlertTimestampText,
+                    TextBundle::from_section(
+                        timestamps.format(timestamp),
+                        alert_nodes.timestamp().clone(),
+                    )
+                    .with_no_wrap()
+                    .with_style(Style {
+                        align_self: AlignSelf::Center,
+                        margin: UiRect::horizontal(Val::Px(4.)),
+                        flex_shrink: 0.,
+                        ..Default::default()
+                    }),
+                ))
+                .id();
+            // the spinner and title, when there are any, stay first
+            let index = usize::from(parts.spinner.is_some()) + usize::from(parts.title.is_some());
+            commands
+                .entity(parts.header)
+                .insert_children(index, &[text]);
+            parts.timestamp = Some(text);
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn compact_over_budget(
+        mut commands: Commands,
+        alerts: Query<
+            (
+                Entity,
+                &Alert,
+                Option<&AlertGroup>,
+                Option<&AlertSequence>,
+                Has<AlertUi>,
+                Option<&CriticalAlert>,
+                Has<PersistentAlert>,
+                Option<&AlertDismissReason>,
+            ),
+            (With<AlertManaged<M>>, Without<AlertCompacted>),
+        >,
+        mut compacted: Query<(&mut AlertGroup, &mut AlertTimer), With<AlertCompacted>>,
+        children: Query<&Children>,
+        mut budget: ResMut<AlertEntityBudget<M>>,
+        factory: Res<MarkerFactory<M>>,
+    ) {
+        if budget
+            .compacted
+            .is_some_and(|card| !compacted.contains(card))
+        {
+            // the combined card has left the UI, so go back to one card per alert
+            budget.compacted = None;
+        }
+
+        let mut alerts = alerts
+            .iter()
+            .filter(|(.., critical, persistent, reason)| {
+                let sticky = *persistent || critical.is_some_and(|critical| critical.sticky);
+                AlertDismissReason::Compacted.arbitrate(reason.copied(), sticky, false)
+                    != DismissalDecision::Ignore
+            })
+            .map(|(entity, alert, group, sequence, visible, ..)| {
+                (entity, alert, group, sequence, visible)
+            })
+            .collect::<Vec<_>>();
+        if alerts.is_empty() {
+            return;
+        }
+        if budget.compacted.is_none() {
+            let cost = alerts
+                .iter()
+                .map(|(entity, _, group, _, visible)| {
+                    if *visible {
+                        1 + children.iter_descendants(*entity).count()
+                    } else {
+                        AlertEntityBudget::<M>::CARD_COST
+                            + group.map_or(0, |group| 1 + group.items.len())
+                    }
+                })
+                .sum::<usize>();
+            if cost <= budget.max {
+                return;
+            }
+        }
+
+        // newest first, so the rows that fit show the most recent messages
+        alerts.sort_by_key(|(_, _, _, sequence, _)| {
+            std::cmp::Reverse(sequence.map_or(u64::MAX, |sequence| sequence.0))
+        });
+        let messages = alerts
+            .iter()
+            .flat_map(|(_, alert, group, _, _)| {
+                std::iter::once(alert.message.clone()).chain(
+                    group
+                        .into_iter()
+                        .flat_map(|group| group.items.iter().cloned()),
+                )
+            })
+            .collect::<Vec<_>>();
+        for (entity, ..) in &alerts {
+            commands
+                .entity(*entity)
+                .insert(AlertDismissReason::Compacted)
+                .despawn_recursive();
+        }
+
+        match budget
+            .compacted
+            .and_then(|card| compacted.get_mut(card).ok())
+        {
+            Some((mut group, mut timer)) => {
+                group.items.splice(0..0, messages);
+                timer.touch();
+            }
+            None => {
+                let card = commands
+                    .spawn((
+                        Alert::bundle(AlertEntityBudget::<M>::TITLE),
+                        AlertGroup::new(messages).with_max_rows(budget.max_rows),
+                        AlertCompacted,
+                        factory.make(),
+                    ))
+                    .id();
+                budget.compacted = Some(card);
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn spawn_alerts(
+        mut commands: Commands,
+        spawned_alerts: Query<
+            (Option<&AlertRootKey>, Has<AlertParent>),
+            (With<AlertManaged<M>>, With<AlertUi>, Without<CriticalAlert>),
+        >,
+        alerts_to_spawn: Query<
+            PendingAlert,
+            (
+                With<AlertManaged<M>>,
+                With<AlertPending>,
+                Without<CriticalAlert>,
+            ),
+        >,
+        spawned_criticals: Query<
+            (Entity, Option<&AlertRootKey>),
+            (With<AlertManaged<M>>, With<AlertUi>, With<CriticalAlert>),
+        >,
+        criticals_to_spawn: Query<
+            (PendingAlert, Has<CriticalDisplaced>),
+            (
+                With<AlertManaged<M>>,
+                With<AlertPending>,
+                With<CriticalAlert>,
+            ),
+        >,
+        existing_roots: Query<(), (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+        max_alerts: Res<MaxAlerts<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+        emphasis: Res<AlertEmphasis<M>>,
+        (mut roots, mut current_root, mut spawned_roots, mut promoted): (
+            ResMut<AlertRoots<M>>,
+            ResMut<CurrentAlertRoot<M>>,
+            EventWriter<AlertRootSpawned<M>>,
+            EventWriter<AlertPromoted<M>>,
+        ),
+        mut jitter: Option<ResMut<AlertJitter<M>>>,
+        (text_limits, decorators, spillover, factory): (
+            Res<AlertTextLimits<M>>,
+            Res<AlertDecorators<M>>,
+            Res<SpilloverAnchor<M>>,
+            Res<MarkerFactory<M>>,
+        ),
+        (gate, filter): (Option<Res<PromotionGate<M>>>, Res<AlertVisibilityFilter<M>>),
+        (parent_policy, entities): (Res<AlertParentPolicy<M>>, &Entities),
+        #[cfg(feature = "clipboard")] copy_buttons: (Res<AlertCopyButtons<M>>, Res<AlertClipboard>),
+    ) where
+        M: Component + Send + Sync + 'static,
+    {
+        if alerts_to_spawn.is_empty() && criticals_to_spawn.is_empty() {
+            return;
+        }
+        #[cfg(feature = "trace")]
+        let _span = info_span!("spawn_alerts", marker = M::short_type_path()).entered();
+        #[cfg(feature = "clipboard")]
+        let copy_buttons = CopyButtonPolicy::new(&copy_buttons.0, &copy_buttons.1);
+
+        // gated and filtered alerts stay pending and are reconsidered next frame
+        let gate = gate.as_deref();
+        let allows = |pending: &PendingAlertItem| {
+            filter.shows(pending.level()) && gate.map_or(true, |gate| gate.allows(pending.alert))
+        };
+        let allows_critical = |pending: &PendingAlertItem| {
+            filter.shows(pending.level())
+                && gate.map_or(true, |gate| {
+                    !gate.gates_criticals || gate.allows(pending.alert)
+                })
+        };
+
+        let parent_of = |pending: &PendingAlertItem| {
+            pending
+                .parent
+                .map(|parent| parent.0)
+                .filter(|parent| entities.contains(*parent))
+        };
+        let counts = |pending: &PendingAlertItem| {
+            parent_policy.counts_toward_max_alerts || parent_of(pending).is_none()
+        };
+
+        let mut num_live_alerts = HashMap::<&AlertRootKey, usize>::new();
+        for (key, parented) in &spawned_alerts {
+            if parented && !parent_policy.counts_toward_max_alerts {
+                continue;
+            }
+            *num_live_alerts
+                .entry(key.unwrap_or(&AlertRootKey::PRIMARY))
+                .or_default() += 1;
+        }
+        let pending_keys = alerts_to_spawn
+            .iter()
+            .filter(|pending| allows(pending) && counts(pending))
+            .chain(
+                criticals_to_spawn
+                    .iter()
+                    .map(|(pending, _)| pending)
+                    .filter(allows_critical),
+            )
+            .map(|pending| pending.key())
+            .collect::<HashSet<_>>();
+
+        // alerts in their own parent without a claim on a root slot are shown right away
+        for pending in alerts_to_spawn
+            .iter()
+            .filter(|pending| allows(pending) && !counts(pending))
+        {
+            let Some(parent) = parent_of(&pending) else {
+                continue;
+            };
+            let offset = jitter.as_mut().map(|jitter| jitter.next_offset());
+            let alert_node = Self::card_node(alert_nodes.alert(), &emphasis, offset);
+            if let Some(offset) = offset {
+                commands
+                    .entity(pending.entity)
+                    .insert(AlertJitterOffset(offset));
+            }
+            Self::build_alert_ui(
+                &mut commands,
+                &pending,
+                alert_node,
+                &alert_nodes,
+                &text_limits,
+                &decorators,
+                #[cfg(feature = "clipboard")]
+                copy_buttons,
+            );
+            commands.entity(parent).add_child(pending.entity);
+            promoted.send(AlertPromoted::new(pending.entity));
+        }
+
+        for key in pending_keys {
+            // if there are alerts and no root for them, add one first
+            let root = match roots.get(key).filter(|root| existing_roots.contains(*root)) {
+                Some(root) => root,
+                None => Self::spawn_root(
+                    &mut commands,
+                    key,
+                    spillover.root_node(key, &alert_nodes),
+                    factory.make(),
+                    &mut roots,
+                    &mut current_root,
+                    &mut spawned_roots,
+                ),
+            };
+
+            // the newest critical alert that was never shown takes the priority slot from the
+            // current one, otherwise a displaced critical returns once the slot is free
+            let mut criticals = criticals_to_spawn
+                .iter()
+                .filter(|(pending, _)| pending.key() == key && allows_critical(pending));
+            let mut spawned_critical = spawned_criticals
+                .iter()
+                .filter(|(_, spawned_key)| spawned_key.unwrap_or(&AlertRootKey::PRIMARY) == key)
+                .map(|(entity, _)| entity)
+                .peekable();
+            let fresh_critical = criticals
+                .clone()
+                .filter(|(_, displaced)| !displaced)
+                .max_by_key(|(pending, _)| pending.sequence());
+            let next_critical = match fresh_critical {
+                Some(critical) => {
+                    for displaced in spawned_critical {
+                        Self::unpromote_alert(&mut commands, displaced).insert(CriticalDisplaced);
+                    }
+                    // older criticals from the same frame wait behind it, as if already displaced
+                    for (older, _) in criticals.clone().filter(|(pending, displaced)| {
+                        !displaced && pending.entity != critical.0.entity
+                    }) {
+                        commands.entity(older.entity).insert(CriticalDisplaced);
+                    }
+                    Some(critical)
+                }
+                None if spawned_critical.peek().is_none() => criticals.next(),
+                None => None,
+            };
+            if let Some((pending, _)) = next_critical {
+                let offset = jitter.as_mut().map(|jitter| jitter.next_offset());
+                let alert_node = Self::card_node(alert_nodes.critical(), &emphasis, offset);
+                if let Some(offset) = offset {
+                    commands
+                        .entity(pending.entity)
+                        .insert(AlertJitterOffset(offset));
+                }
+                Self::build_alert_ui(
+                    &mut commands,
+                    &pending,
+                    alert_node,
+                    &alert_nodes,
+                    &text_limits,
+                    &decorators,
+                    #[cfg(feature = "clipboard")]
+                    copy_buttons,
+                );
+                // the priority slot is always the first child of the root
+                commands.entity(root).insert_children(0, &[pending.entity]);
+                promoted.send(AlertPromoted::new(pending.entity));
+            }
+
+            // spawn any alerts that we can
+            let num_alert_spaces =
+                max_alerts.saturating_sub(num_live_alerts.get(key).copied().unwrap_or_default());
+            let mut queued = alerts_to_spawn
+                .iter()
+                .filter(|pending| pending.key() == key && allows(pending) && counts(pending))
+                .collect::<Vec<_>>();
+            queued.sort_by_key(PendingAlertItem::sequence);
+            for pending in queued.into_iter().take(num_alert_spaces) {
+                let offset = jitter.as_mut().map(|jitter| jitter.next_offset());
+                let alert_node = Self::card_node(alert_nodes.alert(), &emphasis, offset);
+                if let Some(offset) = offset {
+                    commands
+                        .entity(pending.entity)
+                        .insert(AlertJitterOffset(offset));
+                }
+                Self::build_alert_ui(
+                    &mut commands,
+                    &pending,
+                    alert_node,
+                    &alert_nodes,
+                    &text_limits,
+                    &decorators,
+                    #[cfg(feature = "clipboard")]
+                    copy_buttons,
+                );
+                commands
+                    .entity(parent_of(&pending).unwrap_or(root))
+                    .add_child(pending.entity);
+                promoted.send(AlertPromoted::new(pending.entity));
+            }
+        }
+    }
+
+    /// Spawns the root for `key`. This is where we promise to only ever spawn one root per key.
+    pub(crate) fn spawn_root(
+        commands: &mut Commands,
+        key: &AlertRootKey,
+        root_node: NodeBundle,
+        marker: M,
+        roots: &mut AlertRoots<M>,
+        current_root: &mut CurrentAlertRoot<M>,
+        spawned_roots: &mut EventWriter<AlertRootSpawned<M>>,
+    ) -> Entity {
+        let root = commands
+            .spawn((
+                AlertUiRoot,
+                key.clone(),
+                Name::new(format!("Alert UI Root {}", M::short_type_path())),
+                root_node,
+                marker,
+                AlertManaged::<M>::default(),
+            ))
+            .id();
+        roots.roots.insert(key.clone(), root);
+        if *key == AlertRootKey::PRIMARY {
+            current_root.0 = Some(root);
+        }
+        spawned_roots.send(AlertRootSpawned(root, PhantomData));
+        root
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn update_status_line(
+        mut commands: Commands,
+        mut status: ResMut<AlertStatus<M>>,
+        lines: Query<&Children, With<AlertStatusLine>>,
+        existing_roots: Query<Option<&Children>, (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+        mut texts: Query<&mut Text>,
+        alert_nodes: Res<AlertElements<M>>,
+        (mut roots, mut current_root, mut spawned_roots, spillover, factory): (
+            ResMut<AlertRoots<M>>,
+            ResMut<CurrentAlertRoot<M>>,
+            EventWriter<AlertRootSpawned<M>>,
+            Res<SpilloverAnchor<M>>,
+            Res<MarkerFactory<M>>,
+        ),
+    ) {
+        let status = &mut *status;
+        let Some(message) = &status.message else {
+            if let Some(line) = status.entity.take() {
+                if let Some(line) = commands.get_entity(line) {
+                    line.despawn_recursive();
+                }
+            }
+            return;
+        };
+
+        // the status line keeps the primary root alive, so it may have to bring one up itself
+        let key = &AlertRootKey::PRIMARY;
+        let root = match roots.get(key).filter(|root| existing_roots.contains(*root)) {
+            Some(root) => root,
+            None => Self::spawn_root(
+                &mut commands,
+                key,
+                spillover.root_node(key, &alert_nodes),
+                factory.make(),
+                &mut roots,
+                &mut current_root,
+                &mut spawned_roots,
+            ),
+        };
+
+        match status.entity.filter(|line| lines.contains(*line)) {
+            Some(line) => {
+                // replaced in place, without touching the text unless it changed
+                let text = lines.get(line).ok().and_then(|children| children.first());
+                if let Some(mut text) = text.and_then(|text| texts.get_mut(*text).ok()) {
+                    if text.sections[0].value != *message {
+                        text.sections[0].value.clone_from(message);
+                    }
+                }
+                // new alerts are appended to the root, so keep the status line below them
+                let last = existing_roots
+                    .get(root)
+                    .ok()
+                    .flatten()
+                    .and_then(|children| children.last());
+                if last != Some(&line) {
+                    commands.entity(root).add_child(line);
+                }
+            }
+            None => {
+                let line = commands
+                    .spawn((
+                        AlertStatusLine,
+                        Name::new(format!("Alert Status {}", M::short_type_path())),
+                        alert_nodes.status().clone(),
+                    ))
+                    .with_children(|builder| {
+                        builder.spawn(TextBundle::from_section(
+                            message.clone(),
+                            alert_nodes.status_text().clone(),
+                        ));
+                    })
+                    .id();
+                commands.entity(root).add_child(line);
+                status.entity = Some(line);
+            }
+        }
+    }
+
+    /// Keeps a "+N more" line at the bottom of each root with alerts still waiting for a slot,
+    /// above the status line.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn update_overflow_indicators(
+        mut commands: Commands,
+        queued: Query<
+            (Option<&AlertRootKey>, Has<AlertParent>),
+            (
+                With<AlertManaged<M>>,
+                With<AlertPending>,
+                Without<CriticalAlert>,
+            ),
+        >,
+        indicators: Query<(Entity, &Parent, &Children), With<AlertOverflowIndicator>>,
+        root_children: Query<&Children, (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+        status_lines: Query<(), With<AlertStatusLine>>,
+        mut texts: Query<&mut Text>,
+        roots: Res<AlertRoots<M>>,
+        parent_policy: Res<AlertParentPolicy<M>>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        // alerts in their own parent only wait on a root that they take slots from
+        let mut overflow = HashMap::<Entity, usize>::new();
+        for (key, parented) in &queued {
+            if parented && !parent_policy.counts_toward_max_alerts {
+                continue;
+            }
+            if let Some(root) = roots.get(key.unwrap_or(&AlertRootKey::PRIMARY)) {
+                *overflow.entry(root).or_default() += 1;
+            }
+        }
+
+        let mut placed = Vec::new();
+        for (indicator, root, children) in &indicators {
+            // other kinds keep their own lines
+            if !root_children.contains(root.get()) {
+                continue;
+            }
+            let Some(count) = overflow.remove(&root.get()) else {
+                commands.entity(indicator).despawn_recursive();
+                continue;
+            };
+            let label = format!("+{count} more");
+            if let Some(mut text) = children.first().and_then(|text| texts.get_mut(*text).ok()) {
+                if text.sections[0].value != label {
+                    text.sections[0].value = label;
+                }
+            }
+            placed.push((root.get(), indicator));
+        }
+        for (root, count) in overflow {
+            if !root_children.contains(root) {
+                continue;
+            }
+            let indicator = commands
+                .spawn((
+                    AlertOverflowIndicator,
+                    Name::new(format!("Alert Overflow {}", M::short_type_path())),
+                    alert_nodes.overflow().clone(),
+                ))
+                .with_children(|builder| {
+                    builder.spawn(TextBundle::from_section(
+                        format!("+{count} more"),
+                        alert_nodes.overflow_text().clone(),
+                    ));
+                })
+                .id();
+            placed.push((root, indicator));
+        }
+
+        // new alerts are appended to the root, so move the line back below them
+        for (root, indicator) in placed {
+            let children = root_children
+                .get(root)
+                .map(|children| &children[..])
+                .unwrap_or_default();
+            let others = children.iter().filter(|child| **child != indicator);
+            let index = others
+                .clone()
+                .position(|child| status_lines.contains(*child))
+                .unwrap_or(others.count());
+            if children.get(index) != Some(&indicator) {
+                commands.entity(root).insert_children(index, &[indicator]);
+            }
+        }
+    }
+
+    /// Takes a displayed alert out of its root and queues it again. Its card is despawned, along
+    /// with the `AlertParts` and other components that point into it.
+    pub(crate) fn unpromote_alert<'a>(
+        commands: &'a mut Commands,
+        entity: Entity,
+    ) -> EntityCommands<'a> {
+        let mut alert = commands.entity(entity);
+        alert
+            .despawn_descendants()
+            .remove_parent()
+            .remove::<(
+                AlertUi,
+                NodeBundle,
+                AlertTransition,
+                TransitionTimer,
+                AlertParts,
+                AlertRepeatCount,
+                AlertRepeatPulse,
+                AlertExpanded,
+                AlertTextFade,
+            )>()
+            .insert(AlertPending);
+        alert
+    }
+
+    pub(crate) fn card_node(
+        node: &NodeBundle,
+        emphasis: &AlertEmphasis<M>,
+        jitter_offset: Option<f32>,
+    ) -> NodeBundle {
+        let mut node = node.clone();
+        // set the left position to a 100% offset at first
+        node.style.left = Val::Percent(100.);
+        if emphasis.is_enabled() {
+            node.transform.scale = Vec2::splat(emphasis.scale).extend(1.);
+        }
+        if let Some(offset) = jitter_offset {
+            node.style.margin.right = Val::Px(offset);
+        }
+        node
+    }
+
+    pub(crate) fn build_alert_ui(
+        commands: &mut Commands,
+        pending: &PendingAlertItem,
+        mut alert_node: NodeBundle,
+        alert_nodes: &AlertElements<M>,
+        text_limits: &AlertTextLimits<M>,
+        decorators: &AlertDecorators<M>,
+        #[cfg(feature = "clipboard")] copy_buttons: CopyButtonPolicy,
+    ) {
+        #[cfg(feature = "trace")]
+        let _span = info_span!("build_alert_ui", marker = M::short_type_path()).entered();
+        let entity = pending.entity;
+        let mut header = alert_nodes.header().clone();
+        if let Some(colors) = alert_nodes.level_colors(pending.level()) {
+            colors.apply(&mut alert_node, &mut header);
+        }
+        let mut body = alert_nodes.body().clone();
+        if pending.is_group {
+            body.style.flex_direction = FlexDirection::Column;
+        }
+        let mut parts = AlertParts {
+            header: Entity::PLACEHOLDER,
+            dismiss_button: Entity::PLACEHOLDER,
+            body: Entity::PLACEHOLDER,
+            text: Entity::PLACEHOLDER,
+            title: None,
+            icon: None,
+            timestamp: None,
+            repeat_count: None,
+            spinner: None,
+            actions: None,
+            progress_track: None,
+            progress_bar: None,
+            group_rows: None,
+        };
+        commands
+            .entity(entity)
+            .remove::<AlertPending>()
+            .insert((AlertUi, alert_node, AlertTransition::FadeIn))
+            .with_children(|builder| {
+                parts.header = builder
+                    .spawn((Name::new("Alert Header UI"), AlertHeader, header))
+                    .with_children(|builder| {
+                        if let Some(title) = &pending.alert.title {
+                            let (clip, title) =
+                                AlertUi::title(title.clone(), alert_nodes.title().clone());
+                            builder.spawn(clip).with_children(|builder| {
+                                parts.title = Some(builder.spawn(title).id());
+                            });
+                        }
+                        if pending.has_details {
+                            builder
+                                .spawn(AlertUi::details_button(
+                                    entity,
+                                    alert_nodes.details_button().clone(),
+                                ))
+                                .with_children(|builder| {
+                                    builder.spawn(AlertUi::details_text());
+                                });
+                        }
+                        #[cfg(feature = "clipboard")]
+                        if copy_buttons.shows(pending.copyable) {
+                            builder
+                                .spawn(AlertUi::copy_button(
+                                    entity,
+                                    alert_nodes.copy_button().clone(),
+                                ))
+                                .with_children(|builder| {
+                                    builder.spawn(AlertUi::copy_text(CopyButton::LABEL));
+                                });
+                        }
+                        parts.dismiss_button = builder
+                            .spawn(AlertUi::dismiss_button(entity, alert_nodes.header()))
+                            .with_children(|builder| {
+                                builder.spawn(AlertUi::dismiss_text());
+                            })
+                            .id();
+                    })
+                    .id();
+                parts.body = builder
+                    .spawn((Name::new("Alert Body UI"), AlertBody, body))
+                    .with_children(|builder| {
+                        if let Some(icon) = pending.icon {
+                            parts.icon = Some(
+                                builder
+                                    .spawn(AlertUi::icon(
+                                        icon.0.clone(),
+                                        alert_nodes.icon().clone(),
+                                    ))
+                                    .id(),
+                            );
+                        }
+                        let rich_text = pending
+                            .rich_text
+                            .filter(|rich| rich.message() == pending.alert.message);
+                        parts.text = match rich_text {
+                            Some(rich) => builder
+                                .spawn(AlertUi::rich_text(rich, alert_nodes.linebreak))
+                                .id(),
+                            None => builder
+                                .spawn(AlertUi::text(
+                                    text_limits.display_text(&pending.alert.message),
+                                    alert_nodes.text().clone(),
+                                    alert_nodes.linebreak,
+                                ))
+                                .id(),
+                        };
+                        if rich_text.is_none() && text_limits.is_truncated(&pending.alert.message) {
+                            builder
+                                .spawn(AlertUi::show_more_button(
+                                    entity,
+                                    alert_nodes.show_more_button().clone(),
+                                ))
+                                .with_children(|builder| {
+                                    builder.spawn(AlertUi::show_more_text());
+                                });
+                        }
+                        if pending.is_group {
+                            // the rows themselves are filled in by `update_group_rows`
+                            parts.group_rows =
+                                Some(builder.spawn(AlertUi::group_rows(entity)).id());
+                        }
+                    })
+                    .id();
+                if let Some(actions) = pending.actions.filter(|actions| !actions.0.is_empty()) {
+                    let footer = builder
+                        .spawn((Name::new("Alert Actions UI"), alert_nodes.actions().clone()))
+                        .with_children(|builder| {
+                            for action in &actions.0 {
+                                let label = action.label.clone();
+                                builder
+                                    .spawn(AlertUi::action_button(
+                                        entity,
+                                        action.clone(),
+                                        alert_nodes.action_button().clone(),
+                                    ))
+                                    .with_children(|builder| {
+                                        builder.spawn((
+                                            Name::new("Action Button Text"),
+                                            TextBundle::from_section(
+                                                label,
+                                                alert_nodes.action_text().clone(),
+                                            ),
+                                        ));
+                                    });
+                            }
+                        })
+                        .id();
+                    parts.actions = Some(footer);
+                }
+                if let Some(progress) = pending.progress {
+                    let mut bar = alert_nodes.progress_bar().clone();
+                    bar.style.width = progress.bar_width();
+                    let track = builder
+                        .spawn((
+                            Name::new("Alert Progress Track"),
+                            alert_nodes.progress_track().clone(),
+                        ))
+                        .with_children(|builder| {
+                            parts.progress_bar =
+                                Some(builder.spawn((Name::new("Alert Progress Bar"), bar)).id());
+                        })
+                        .id();
+                    parts.progress_track = Some(track);
+                }
+            });
+        let joins_previous = pending.batch.is_some_and(|batch| batch.index > 0);
+        if let Some(connector) = alert_nodes
+            .batch_connector
+            .as_ref()
+            .filter(|_| joins_previous)
+        {
+            let connector = commands
+                .spawn((Name::new("Alert Batch Connector"), connector.clone()))
+                .id();
+            commands.entity(entity).insert_children(0, &[connector]);
+        }
+        let mut alert = commands.entity(entity);
+        for decorator in &decorators.decorators {
+            decorator(&mut alert, pending.alert, &parts);
+        }
+        alert.insert(parts);
+    }
+}
+
+/// The components of a new alert. Add a marker like `AlertMarker` alongside it when spawning.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{AlertBundle, AlertMarker};
+///
+/// fn spawn_alert(mut commands: Commands) {
+///     commands.spawn((
+///         AlertBundle {
+///             name: Name::new("Autosave failure"),
+///             ..AlertBundle::new("Autosave failed")
+///         },
+///         AlertMarker,
+///     ));
+/// }
+/// ```
+#[derive(Bundle)]
+pub struct AlertBundle {
+    pub alert: Alert,
+    pub timer: AlertTimer,
+    pub name: Name,
+}
+
+impl AlertBundle {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            alert: Alert::new(message),
+            timer: AlertTimer::default(),
+            name: Name::new("Alert"),
+        }
+    }
+}
+
+/// Chains the per-alert options of a new alert. Start one with `Alert::build`.
+///
+/// The alert ends up with the same components as one spawned by hand: an `AlertBundle`, the
+/// marker, and `AlertLifetimeOverride`, `AlertKey` or `PersistentAlert` for the options set.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::Alert;
+///
+/// #[derive(Default, Component)]
+/// struct NetworkAlert;
+///
+/// fn connection_lost(mut commands: Commands) {
+///     Alert::build("Connection lost")
+///         .key("net")
+///         .lifetime(Duration::from_secs(3))
+///         .marker::<NetworkAlert>()
+///         .spawn(&mut commands);
+/// }
+///
+/// fn save_corrupted(mut commands: Commands) {
+///     commands.spawn(Alert::build("Save data is corrupted").persistent().bundle());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AlertBuilder<M = AlertMarker> {
+    message: String,
+    options: AlertOptions,
+    marker: M,
+}
+
+impl AlertBuilder {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            options: AlertOptions::default(),
+            marker: AlertMarker,
+        }
+    }
+}
+
+impl<M> AlertBuilder<M> {
+    /// Replaces the kind's `AlertLifetime` for this alert. See `AlertLifetimeOverride`.
+    pub fn lifetime(mut self, lifetime: Duration) -> Self {
+        self.options.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Keeps the alert until it is dismissed or cleared. See `PersistentAlert`.
+    pub fn persistent(mut self) -> Self {
+        self.options.persistent = true;
+        self
+    }
+
+    /// Refreshes the live alert with the same key instead of adding another. See `AlertKey`.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.options.key = Some(key.into());
+        self
+    }
+
+    /// Shows the alert as the kind of `T`.
+    pub fn marker<T: Default>(self) -> AlertBuilder<T> {
+        self.with_marker(T::default())
+    }
+
+    /// Like `AlertBuilder::marker`, for markers that do not implement `Default`.
+    pub fn with_marker<T>(self, marker: T) -> AlertBuilder<T> {
+        AlertBuilder {
+            message: self.message,
+            options: self.options,
+            marker,
+        }
+    }
+
+    /// The components of the alert, for spawning alongside components of your own.
+    ///
+    /// The options are inserted as their own components as soon as the bundle is spawned.
+    pub fn bundle(self) -> impl Bundle
+    where
+        M: Component,
+    {
+        (AlertBundle::new(self.message), self.marker, self.options)
+    }
+
+    pub fn spawn(self, commands: &mut Commands) -> Entity
+    where
+        M: Component,
+    {
+        let mut alert = commands.spawn((AlertBundle::new(self.message), self.marker));
+        self.options.insert(&mut alert);
+        alert.id()
+    }
+}
+
+/// The options of an `AlertBuilder` bundle, replaced by the components they stand for when added.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AlertOptions {
+    lifetime: Option<Duration>,
+    key: Option<String>,
+    persistent: bool,
+}
+
+impl AlertOptions {
+    fn insert(self, alert: &mut EntityCommands) {
+        if let Some(lifetime) = self.lifetime {
+            alert.insert(AlertLifetimeOverride(lifetime));
+        }
+        if let Some(key) = self.key {
+            alert.insert(AlertKey(key));
+        }
+        if self.persistent {
+            alert.insert(PersistentAlert);
+        }
+    }
+}
+
+impl Component for AlertOptions {
+    const STORAGE_TYPE: StorageType = StorageType::SparseSet;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, entity, _| {
+            let Some(options) = world.get::<AlertOptions>(entity).cloned() else {
+                return;
+            };
+            let mut commands = world.commands();
+            let mut alert = commands.entity(entity);
+            alert.remove::<AlertOptions>();
+            options.insert(&mut alert);
+        });
+    }
+}
+
+impl AlertsPlugin<AlertMarker> {
+    /// A PipeableSystem that accepts a vector of alert messages and spawns `Alert`s for each of them.
+    pub fn alert(In(alerts): In<Vec<String>>, mut commands: Commands) {
+        for alert in alerts {
+            commands.spawn((AlertBundle::new(alert), AlertMarker));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert`, for any messages that convert into a `String`.
+    pub fn alert_from<T: Into<String> + Send + Sync + 'static>(
+        In(alerts): In<Vec<T>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_from(In(alerts), commands, factory);
+    }
+
+    /// Like `AlertsPlugin::alert`, spawning alerts straight from an iterator instead of a `Vec`.
+    pub fn alert_iter<I: IntoIterator<Item = String> + Send + 'static>(
+        In(alerts): In<I>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_iter(In(alerts), commands, factory);
+    }
+
+    /// A PipeableSystem that accepts `(key, message)` pairs and spawns keyed `Alert`s, which refresh
+    /// the live alert with the same key instead of stacking up. See `AlertKey`.
+    pub fn alert_keyed(
+        In(alerts): In<Vec<(String, String)>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_keyed(In(alerts), commands, factory);
+    }
+
+    /// A PipeableSystem that accepts `(level, message)` pairs and spawns an `Alert` of each
+    /// `AlertLevel`.
+    pub fn alert_levels(
+        In(alerts): In<Vec<(AlertLevel, String)>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_levels(In(alerts), commands, factory);
+    }
+
+    /// A PipeableSystem that spawns a single `Alert` when given `Some` message.
+    pub fn alert_option(
+        In(alert): In<Option<String>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_option(In(alert), commands, factory);
+    }
+
+    /// A PipeableSystem that spawns an `Alert` with the rendered error when given an `Err`.
+    pub fn alert_result<E: std::fmt::Display>(
+        In(result): In<Result<(), E>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_result(In(result), commands, factory);
+    }
+
+    /// A PipeableSystem that spawns an `Alert` for each rendered error, for use with `pipe_err`.
+    pub fn alert_errors<E: std::fmt::Display>(
+        In(errors): In<Vec<E>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<AlertMarker>>,
+    ) {
+        Self::custom_alert_errors(In(errors), commands, factory);
+    }
+}
+
+/// A predefined alert that can be spawned by id with `AlertCommandsExt`.
+///
+/// `format` may contain positional placeholders like `{0}`, which are replaced by the arguments
+/// given when the template is spawned. A `lifetime` replaces the kind's `AlertLifetime` for the
+/// alerts of the template, like `AlertLifetimeOverride`.
+#[derive(Clone, Debug, Default)]
+pub struct AlertTemplate {
+    pub format: String,
+    pub level: Option<AlertLevel>,
+    pub lifetime: Option<Duration>,
+    pub critical: Option<CriticalAlert>,
+    pub root: Option<AlertRootKey>,
+    pub details: Option<String>,
+    pub on_expire: Option<OnExpireSpawn>,
+}
+
+impl AlertTemplate {
+    pub fn new(format: impl Into<String>) -> Self {
+        Self {
+            format: format.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn message(&self, args: &[String]) -> String {
+        args.iter()
+            .enumerate()
+            .fold(self.format.clone(), |message, (index, arg)| {
+                message.replace(&format!("{{{index}}}"), arg)
+            })
+    }
+}
+
+/// The `AlertTemplate`s of this kind, by id.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{AlertCommandsExt, AlertMarker, AlertTemplate, AlertTemplates, AlertsPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new());
+/// app.world_mut()
+///     .resource_mut::<AlertTemplates<AlertMarker>>()
+///     .register("low_ammo", AlertTemplate::new("Low ammo: {0}"));
+/// app.add_systems(Update, |mut commands: Commands| {
+///     commands.alert_template("low_ammo", ["pistol"]);
+/// });
+/// ```
+#[derive(Debug, Resource)]
+pub struct AlertTemplates<M> {
+    pub(crate) templates: HashMap<Cow<'static, str>, AlertTemplate>,
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertTemplates<M> {
+    pub fn register(
+        &mut self,
+        id: impl Into<Cow<'static, str>>,
+        template: AlertTemplate,
+    ) -> &mut Self {
+        self.templates.insert(id.into(), template);
+        self
+    }
+
+    pub fn get(&self, id: &str) -> Option<&AlertTemplate> {
+        self.templates.get(id)
+    }
+
+    pub(crate) fn spawn(world: &mut World, id: &str, args: &[String]) -> Entity
+    where
+        M: Component,
+    {
+        let marker = world.resource::<MarkerFactory<M>>().make();
+        let template = world
+            .get_resource::<AlertTemplates<M>>()
+            .and_then(|templates| templates.get(id))
+            .cloned();
+        let Some(template) = template else {
+            return world
+                .spawn((
+                    Alert::bundle(format!(
+                        "Unknown alert template {id:?} ({})",
+                        args.join(", ")
+                    )),
+                    marker,
+                ))
+                .id();
+        };
+        let mut alert = world.spawn((Alert::bundle(template.message(args)), marker));
+        if let Some(level) = template.level {
+            alert.insert(level);
+        }
+        if let Some(lifetime) = template.lifetime {
+            alert.insert(AlertLifetimeOverride(lifetime));
+        }
+        if let Some(critical) = template.critical {
+            alert.insert(critical);
+        }
+        if let Some(root) = template.root {
+            alert.insert(root);
+        }
+        if let Some(details) = template.details {
+            alert.insert(AlertDetails(details));
+        }
+        if let Some(on_expire) = template.on_expire {
+            alert.insert(on_expire);
+        }
+        alert.id()
+    }
+}
+
+impl<M> Default for AlertTemplates<M> {
+    fn default() -> Self {
+        Self {
+            templates: HashMap::default(),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// Spawns alerts straight into a `World`, for exclusive systems.
+///
+/// The alert is picked up by the `PostUpdate` systems like any other. Its marker comes from the
+/// `MarkerFactory` of the `AlertsPlugin<M>`.
+pub trait WorldAlertExt {
+    fn spawn_alert<M: Component>(&mut self, message: impl Into<String>) -> Entity;
+}
+
+impl WorldAlertExt for World {
+    fn spawn_alert<M: Component>(&mut self, message: impl Into<String>) -> Entity {
+        let marker = self.resource::<MarkerFactory<M>>().make();
+        self.spawn((Alert::bundle(message), marker)).id()
+    }
+}
+
+/// A command that resolves an `UnresolvedAlert` into its outcome: the message becomes the `Ok`
+/// or `Err` text, the spinner goes away, and the alert's lifetime starts.
+///
+/// Failures are shown as `AlertLevel::Error` unless another level is given with `with_level`.
+/// Alerts that are gone or already resolved are ignored.
+pub struct ResolveAlert {
+    entity: Entity,
+    result: Result<String, String>,
+    level: Option<AlertLevel>,
+}
+
+impl ResolveAlert {
+    pub fn new(entity: Entity, result: Result<String, String>) -> Self {
+        Self {
+            entity,
+            result,
+            level: None,
+        }
+    }
+
+    /// Shows the resolved alert at this `AlertLevel`, whatever the outcome.
+    pub fn with_level(mut self, level: AlertLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+}
+
+impl Command for ResolveAlert {
+    fn apply(self, world: &mut World) {
+        let Some(mut alert) = world.get_entity_mut(self.entity) else {
+            return;
+        };
+        if alert.take::<UnresolvedAlert>().is_none() {
+            return;
+        }
+        let (message, level) = match self.result {
+            Ok(message) => (message, self.level),
+            Err(message) => (message, self.level.or(Some(AlertLevel::Error))),
+        };
+        if let Some(mut alert) = alert.get_mut::<Alert>() {
+            alert.set_message(message);
+        }
+        if let Some(level) = level {
+            alert.insert(level);
+        }
+    }
+}
+
+/// A command that sets the `AlertProgress` of an alert, found by entity or by `AlertKey`.
+///
+/// Alerts that are gone, or keys without a live alert, are ignored.
+pub struct SetAlertProgress {
+    target: AlertTarget,
+    fraction: f32,
+}
+
+enum AlertTarget {
+    Alert(Entity),
+    Key(String),
+}
+
+impl AlertTarget {
+    /// The alert, unless it is gone, or no live alert has the key.
+    fn find(self, world: &mut World) -> Option<Entity> {
+        match self {
+            AlertTarget::Alert(alert) => world.get_entity(alert).map(|_| alert),
+            AlertTarget::Key(key) => world
+                .query_filtered::<(Entity, &AlertKey), (With<Alert>, Without<AlertDismissReason>)>()
+                .iter(world)
+                .find(|(_, alert_key)| alert_key.0 == key)
+                .map(|(alert, _)| alert),
+        }
+    }
+}
+
+impl SetAlertProgress {
+    pub fn new(alert: Entity, fraction: f32) -> Self {
+        Self {
+            target: AlertTarget::Alert(alert),
+            fraction,
+        }
+    }
+
+    pub fn keyed(key: impl Into<String>, fraction: f32) -> Self {
+        Self {
+            target: AlertTarget::Key(key.into()),
+            fraction,
+        }
+    }
+}
+
+impl Command for SetAlertProgress {
+    fn apply(self, world: &mut World) {
+        if let Some(alert) = self.target.find(world) {
+            world.entity_mut(alert).insert(AlertProgress(self.fraction));
+        }
+    }
+}
+
+/// A command that raises the `AlertLevel` of an alert in place, found by entity or by `AlertKey`.
+///
+/// A shown alert is recolored for its new level without being spawned again, and keeps the time
+/// it has been alive unless `reset_timer` is set. Levels at or below the alert's own are ignored,
+/// as are alerts that are gone or leaving, and keys without a live alert.
+pub struct EscalateAlert {
+    target: AlertTarget,
+    level: AlertLevel,
+    lifetime: Option<Duration>,
+    persistent: bool,
+    reset_timer: bool,
+}
+
+impl EscalateAlert {
+    pub fn new(alert: Entity, level: AlertLevel) -> Self {
+        Self::with_target(AlertTarget::Alert(alert), level)
+    }
+
+    pub fn keyed(key: impl Into<String>, level: AlertLevel) -> Self {
+        Self::with_target(AlertTarget::Key(key.into()), level)
+    }
+
+    fn with_target(target: AlertTarget, level: AlertLevel) -> Self {
+        Self {
+            target,
+            level,
+            lifetime: None,
+            persistent: false,
+            reset_timer: false,
+        }
+    }
+
+    /// Replaces the alert's lifetime from now on. See `AlertLifetimeOverride`.
+    pub fn lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Keeps the escalated alert until it is dismissed or cleared. See `PersistentAlert`.
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+
+    /// Restarts the alert's lifetime as it escalates.
+    pub fn reset_timer(mut self) -> Self {
+        self.reset_timer = true;
+        self
+    }
+}
+
+impl Command for EscalateAlert {
+    fn apply(self, world: &mut World) {
+        let Some(alert) = self.target.find(world) else {
+            return;
+        };
+        let mut alert = world.entity_mut(alert);
+        if alert.contains::<AlertDismissReason>()
+            || alert.get::<AlertLevel>().copied().unwrap_or_default() >= self.level
+        {
+            return;
+        }
+        alert.insert(self.level);
+        if let Some(lifetime) = self.lifetime {
+            alert.insert(AlertLifetimeOverride(lifetime));
+        }
+        if self.persistent {
+            alert.insert(PersistentAlert);
+        }
+        if self.reset_timer {
+            if let Some(mut timer) = alert.get_mut::<AlertTimer>() {
+                timer.touch();
+            }
+        }
+    }
+}
+
+/// Spawns alerts without piping, from a message or from the registered `AlertTemplates`.
+///
+/// Markers come from the `MarkerFactory` of the `AlertsPlugin<M>`. An unknown template id spawns
+/// a fallback alert naming the id and arguments instead. On `EntityCommands`, the alert is tied to
+/// that entity with an `AlertSource`.
+pub trait AlertCommandsExt {
+    fn alert(&mut self, message: impl Into<String>) -> Entity {
+        self.alert_with_marker::<AlertMarker>(message)
+    }
+
+    fn alert_with_marker<M>(&mut self, message: impl Into<String>) -> Entity
+    where
+        M: Component + TypePath + Send + Sync + 'static;
+
+    /// Spawns related alerts that stay adjacent in the stack, in order, even when other alerts are
+    /// spawned in the same frame. Each is tagged with an `AlertBatch` but otherwise independent.
+    fn alert_batch<S: Into<String>>(
+        &mut self,
+        messages: impl IntoIterator<Item = S>,
+    ) -> Vec<Entity> {
+        self.alert_batch_with_marker::<AlertMarker, S>(messages)
+    }
+
+    fn alert_batch_with_marker<M, S: Into<String>>(
+        &mut self,
+        messages: impl IntoIterator<Item = S>,
+    ) -> Vec<Entity>
+    where
+        M: Component + TypePath + Send + Sync + 'static;
+
+    fn alert_template<S: ToString>(
+        &mut self,
+        id: impl Into<Cow<'static, str>>,
+        args: impl IntoIterator<Item = S>,
+    ) {
+        self.custom_alert_template::<AlertMarker, S>(id, args);
+    }
+
+    fn custom_alert_template<M, S: ToString>(
+        &mut self,
+        id: impl Into<Cow<'static, str>>,
+        args: impl IntoIterator<Item = S>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static;
+
+    /// Dismisses `alert` as if its dismiss button was pressed, such as when the condition that
+    /// raised it resolves itself. Entities that are gone or aren't displayed alerts are ignored.
+    fn dismiss_alert(&mut self, alert: Entity) {
+        self.dismiss_alert_with_marker::<AlertMarker>(alert);
+    }
+
+    fn dismiss_alert_with_marker<M>(&mut self, alert: Entity)
+    where
+        M: Component + TypePath + Send + Sync + 'static;
+
+    /// Shows `message` in the `AlertStatus` slot of this kind, replacing the current status.
+    fn set_status<M>(&mut self, message: impl Into<String>)
+    where
+        M: Component + TypePath + Send + Sync + 'static;
+
+    /// Removes the `AlertStatus` line of this kind.
+    fn clear_status<M>(&mut self)
+    where
+        M: Component + TypePath + Send + Sync + 'static;
+
+    /// Resolves an `UnresolvedAlert` into its outcome. See `ResolveAlert`.
+    fn resolve_alert(&mut self, alert: Entity, result: Result<String, String>);
+
+    /// Sets the `AlertProgress` of `alert`. See `SetAlertProgress`.
+    fn set_alert_progress(&mut self, alert: Entity, fraction: f32);
+
+    /// Sets the `AlertProgress` of the live alert with this `AlertKey`. See `SetAlertProgress`.
+    fn set_keyed_alert_progress(&mut self, key: impl Into<String>, fraction: f32);
+}
+
+impl AlertCommandsExt for Commands<'_, '_> {
+    fn alert_with_marker<M>(&mut self, message: impl Into<String>) -> Entity
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        MarkerFactory::<M>::spawn(self, Alert::bundle(message.into()))
+    }
+
+    fn alert_batch_with_marker<M, S: Into<String>>(
+        &mut self,
+        messages: impl IntoIterator<Item = S>,
+    ) -> Vec<Entity>
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        AlertBatch::spawn::<M>(self, messages.into_iter().map(Into::into).collect(), None)
+    }
+
+    fn custom_alert_template<M, S: ToString>(
+        &mut self,
+        id: impl Into<Cow<'static, str>>,
+        args: impl IntoIterator<Item = S>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        let id = id.into();
+        let args = args
+            .into_iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        self.add(move |world: &mut World| {
+            AlertTemplates::<M>::spawn(world, &id, &args);
+        });
+    }
+
+    fn dismiss_alert_with_marker<M>(&mut self, alert: Entity)
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        self.add(DismissAlert::<M>::new(alert, AlertDismissReason::Dismissed));
+    }
+
+    fn set_status<M>(&mut self, message: impl Into<String>)
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        let message = message.into();
+        self.add(move |world: &mut World| {
+            world.resource_mut::<AlertStatus<M>>().message = Some(message);
+        });
+    }
+
+    fn clear_status<M>(&mut self)
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        self.add(|world: &mut World| {
+            world.resource_mut::<AlertStatus<M>>().message = None;
+        });
+    }
+
+    fn resolve_alert(&mut self, alert: Entity, result: Result<String, String>) {
+        self.add(ResolveAlert::new(alert, result));
+    }
+
+    fn set_alert_progress(&mut self, alert: Entity, fraction: f32) {
+        self.add(SetAlertProgress::new(alert, fraction));
+    }
+
+    fn set_keyed_alert_progress(&mut self, key: impl Into<String>, fraction: f32) {
+        self.add(SetAlertProgress::keyed(key, fraction));
+    }
+}
+
+impl AlertCommandsExt for EntityCommands<'_> {
+    fn alert_with_marker<M>(&mut self, message: impl Into<String>) -> Entity
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        let source = AlertSource::new(self.id());
+        MarkerFactory::<M>::spawn(
+            &mut self.commands(),
+            (Alert::bundle(message.into()), source),
+        )
+    }
+
+    fn alert_batch_with_marker<M, S: Into<String>>(
+        &mut self,
+        messages: impl IntoIterator<Item = S>,
+    ) -> Vec<Entity>
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        let source = AlertSource::new(self.id());
+        AlertBatch::spawn::<M>(
+            &mut self.commands(),
+            messages.into_iter().map(Into::into).collect(),
+            Some(source),
+        )
+    }
+
+    fn custom_alert_template<M, S: ToString>(
+        &mut self,
+        id: impl Into<Cow<'static, str>>,
+        args: impl IntoIterator<Item = S>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        let source = AlertSource::new(self.id());
+        let id = id.into();
+        let args = args
+            .into_iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        self.commands().add(move |world: &mut World| {
+            let alert = AlertTemplates::<M>::spawn(world, &id, &args);
+            world.entity_mut(alert).insert(source);
+        });
+    }
+
+    fn dismiss_alert_with_marker<M>(&mut self, alert: Entity)
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        self.commands().dismiss_alert_with_marker::<M>(alert);
+    }
+
+    fn set_status<M>(&mut self, message: impl Into<String>)
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        self.commands().set_status::<M>(message);
+    }
+
+    fn clear_status<M>(&mut self)
+    where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        self.commands().clear_status::<M>();
+    }
+
+    fn resolve_alert(&mut self, alert: Entity, result: Result<String, String>) {
+        self.commands().resolve_alert(alert, result);
+    }
+
+    fn set_alert_progress(&mut self, alert: Entity, fraction: f32) {
+        self.commands().set_alert_progress(alert, fraction);
+    }
+
+    fn set_keyed_alert_progress(&mut self, key: impl Into<String>, fraction: f32) {
+        self.commands().set_keyed_alert_progress(key, fraction);
+    }
+}
+
+/// When an alert was raised, recorded on alerts of kinds with `AlertTimestamps`.
+///
+/// `wall_clock` is `None` where the platform has no system clock to read, such as the web.
+#[derive(Clone, Copy, Debug, Component)]
+pub struct AlertTimestamp {
+    /// The `Time::elapsed` when the alert was raised.
+    pub elapsed: Duration,
+    pub wall_clock: Option<SystemTime>,
+}
+
+impl AlertTimestamp {
+    pub fn now(time: &Time) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let wall_clock = Some(SystemTime::now());
+        #[cfg(target_arch = "wasm32")]
+        let wall_clock = None;
+        Self {
+            elapsed: time.elapsed(),
+            wall_clock,
+        }
+    }
+}
+
+/// Shows when each alert of this kind was raised in a small text at the right of its header,
+/// styled by `AlertElements::timestamp`. This resource is not inserted by default.
+///
+/// Alerts raised while it is present get an `AlertTimestamp`, rendered with `format` once they are
+/// shown.
+#[derive(Debug, Resource)]
+pub struct AlertTimestamps<M> {
+    format: fn(&AlertTimestamp) -> String,
+    marker: PhantomData<M>,
+}
+
+impl<M> AlertTimestamps<M> {
+    pub fn new(format: fn(&AlertTimestamp) -> String) -> Self {
+        Self {
+            format,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// The UTC time of day, like `14:03:27 UTC`, or the `elapsed_format` without a wall clock.
+    pub fn wall_clock_format(timestamp: &AlertTimestamp) -> String {
+        let since_epoch = timestamp
+            .wall_clock
+            .and_then(|wall_clock| wall_clock.duration_since(SystemTime::UNIX_EPOCH).ok());
+        let Some(since_epoch) = since_epoch else {
+            return Self::elapsed_format(timestamp);
+        };
+        let seconds = since_epoch.as_secs() % 86_400;
+        format!(
+            "{:02}:{:02}:{:02} UTC",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+
+    /// The time since startup, like `+02:05.3`.
+    pub fn elapsed_format(timestamp: &AlertTimestamp) -> String {
+        let seconds = timestamp.elapsed.as_secs();
+        format!(
+            "+{:02}:{:02}.{}",
+            seconds / 60,
+            seconds % 60,
+            timestamp.elapsed.subsec_millis() / 100
+        )
+    }
+
+    pub fn format(&self, timestamp: &AlertTimestamp) -> String {
+        (self.format)(timestamp)
+    }
+}
+
+impl<M> Default for AlertTimestamps<M> {
+    fn default() -> Self {
+        Self::new(Self::wall_clock_format)
+    }
+}
+
+/// A marker component for the timestamp text node in the header of the AlertUI node tree.
+#[derive(Component)]
+pub struct AlertTimestampText;
+
+/// The order alerts were queued in, which is the order they are promoted in.
+///
+/// Assigned when the `Alert` is added, unless one is already present.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Component, Reflect)]
+pub struct AlertSequence(pub u64);
+
+/// Hands out `AlertSequence` numbers.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct AlertSequences {
+    pub(crate) next: u64,
+}
+
+impl AlertSequences {
+    /// Reserves `count` consecutive sequence numbers, returning the first.
+    pub(crate) fn reserve(&mut self, count: u64) -> u64 {
+        let first = self.next;
+        self.next += count;
+        first
+    }
+}
+
+/// Marks an alert spawned with `AlertCommandsExt::alert_batch`. `group` is shared by the batch and
+/// `index` is the alert's position within it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub struct AlertBatch {
+    pub group: u64,
+    pub index: usize,
+}
+
+impl AlertBatch {
+    pub(crate) fn spawn<M>(
+        commands: &mut Commands,
+        messages: Vec<String>,
+        source: Option<AlertSource>,
+    ) -> Vec<Entity>
+    where
+        M: Component,
+    {
+        let entities = messages
+            .iter()
+            .map(|_| commands.spawn_empty().id())
+            .collect::<Vec<_>>();
+        let batch = entities.clone();
+        commands.add(move |world: &mut World| {
+            // the whole batch is numbered at once, so nothing can be queued between its alerts
+            let group = world
+                .get_resource_or_insert_with(AlertSequences::default)
+                .reserve(batch.len() as u64);
+            let factory = world.resource::<MarkerFactory<M>>().clone();
+            for (index, (entity, message)) in batch.into_iter().zip(messages).enumerate() {
+                let Some(mut entity) = world.get_entity_mut(entity) else {
+                    continue;
+                };
+                entity.insert((
+                    Alert::bundle(message),
+                    factory.make(),
+                    AlertSequence(group + index as u64),
+                    AlertBatch { group, index },
+                ));
+                if let Some(source) = source {
+                    entity.insert(source);
+                }
+            }
+        });
+        entities
+    }
+}
+
+/// A cap on the number of UI entities used by alerts of this kind.
+///
+/// Each alert counts the card node and all of its descendants, and pending alerts count as the
+/// card they would become. When the total goes over `max`, every alert is folded into a single
+/// `AlertCompacted` group card listing the most recent messages, and later alerts are folded into
+/// it as they arrive. Alerts resume getting their own card once the combined card leaves the UI.
+/// This resource is not inserted by default.
+#[derive(Debug, Resource)]
+pub struct AlertEntityBudget<M> {
+    pub max: usize,
+    pub max_rows: usize,
+    pub(crate) compacted: Option<Entity>,
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertEntityBudget<M> {
+    /// The entities in a plain card: the alert, its header, dismiss button and label, body and text.
+    pub(crate) const CARD_COST: usize = 6;
+    pub(crate) const TITLE: &'static str = "Recent alerts";
+
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            max_rows: 5,
+            compacted: None,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// The combined card, while alerts are compacted.
+    pub fn compacted(&self) -> Option<Entity> {
+        self.compacted
+    }
+}
+
+/// A `SystemParam` for spawning alerts of this kind from a system that returns something else.
+///
+/// Messages beyond `MaxAlerts` wait as pending alerts like any other.
+#[derive(SystemParam)]
+pub struct AlertWriter<'w, 's, M: Component + TypePath = AlertMarker> {
+    pub(crate) commands: Commands<'w, 's>,
+    pub(crate) factory: Res<'w, MarkerFactory<M>>,
+}
+
+impl<M> AlertWriter<'_, '_, M>
+where
+    M: Component + TypePath,
+{
+    pub fn send(&mut self, message: impl Into<String>) -> Entity {
+        self.commands
+            .spawn((Alert::bundle(message), self.factory.make()))
+            .id()
+    }
+
+    pub fn send_all(&mut self, messages: impl IntoIterator<Item = String>) {
+        for message in messages {
+            self.send(message);
+        }
+    }
+}
+
+impl<M> AlertsPlugin<M> {
+    /// A PipeableSystem that accepts a vector of alert messages and spawns `Alert`s for each of them.
+    ///
+    /// Use this if you want to specify your own `AlertMarker`.
+    pub fn custom_alert(
+        In(alerts): In<Vec<String>>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        for alert in alerts {
+            commands.spawn((AlertBundle::new(alert), factory.make()));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_from`, for your own `AlertMarker`.
+    pub fn custom_alert_from<T: Into<String> + Send + Sync + 'static>(
+        In(alerts): In<Vec<T>>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        for alert in alerts {
+            commands.spawn((AlertBundle::new(alert), factory.make()));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_keyed`, for your own `AlertMarker`.
+    pub fn custom_alert_keyed(
+        In(alerts): In<Vec<(String, String)>>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        for (key, message) in alerts {
+            commands.spawn((Alert::keyed(key, message), factory.make()));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_levels`, for your own `AlertMarker`.
+    pub fn custom_alert_levels(
+        In(alerts): In<Vec<(AlertLevel, String)>>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        for (level, message) in alerts {
+            commands.spawn((Alert::with_level(level, message), factory.make()));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_iter`, for your own `AlertMarker`.
+    pub fn custom_alert_iter<I: IntoIterator<Item = String> + Send + 'static>(
+        In(alerts): In<I>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        for alert in alerts {
+            commands.spawn((AlertBundle::new(alert), factory.make()));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_option`, for your own `AlertMarker`.
+    pub fn custom_alert_option(
+        In(alert): In<Option<String>>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        if let Some(alert) = alert {
+            commands.spawn((AlertBundle::new(alert), factory.make()));
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_result`, for your own `AlertMarker`.
+    pub fn custom_alert_result<E: std::fmt::Display>(
+        In(result): In<Result<(), E>>,
+        commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        if let Err(error) = result {
+            Self::custom_alert_errors(In(vec![error]), commands, factory);
+        }
+    }
+
+    /// Like `AlertsPlugin::alert_errors`, for your own `AlertMarker`.
+    pub fn custom_alert_errors<E: std::fmt::Display>(
+        In(errors): In<Vec<E>>,
+        mut commands: Commands,
+        factory: Res<MarkerFactory<M>>,
+    ) where
+        M: Component + TypePath + Send + Sync + 'static,
+    {
+        for error in errors {
+            commands.spawn((AlertBundle::new(error.to_string()), factory.make()));
+        }
+    }
+}
+
+/// The components of a queued alert that determine how its UI is built.
+#[derive(QueryData)]
+pub(crate) struct PendingAlert {
+    pub(crate) entity: Entity,
+    pub(crate) alert: &'static Alert,
+    pub(crate) is_group: Has<AlertGroup>,
+    pub(crate) has_details: Has<AlertDetails>,
+    #[cfg(feature = "clipboard")]
+    pub(crate) copyable: Has<CopyableAlert>,
+    pub(crate) root_key: Option<&'static AlertRootKey>,
+    pub(crate) sequence: Option<&'static AlertSequence>,
+    pub(crate) batch: Option<&'static AlertBatch>,
+    pub(crate) parent: Option<&'static AlertParent>,
+    pub(crate) level: Option<&'static AlertLevel>,
+    pub(crate) icon: Option<&'static AlertIcon>,
+    pub(crate) rich_text: Option<&'static AlertRichText>,
+    pub(crate) progress: Option<&'static AlertProgress>,
+    pub(crate) actions: Option<&'static AlertActions>,
+}
+
+impl<'w> PendingAlertItem<'w> {
+    pub(crate) fn key(&self) -> &'w AlertRootKey {
+        self.root_key.unwrap_or(&AlertRootKey::PRIMARY)
+    }
+
+    pub(crate) fn sequence(&self) -> u64 {
+        self.sequence.map_or(u64::MAX, |sequence| sequence.0)
+    }
+
+    pub(crate) fn level(&self) -> AlertLevel {
+        self.level.copied().unwrap_or_default()
+    }
+}
+
+/// Attaches an alert's card under this entity, such as a settings panel, instead of the shared
+/// root. The entity must already exist when the alert is spawned, otherwise the alert goes to the
+/// root as usual.
+///
+/// Critical alerts always take the root's priority slot and ignore this.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct AlertParent(pub Entity);
+
+/// How alerts with an `AlertParent` relate to the shared root of their kind.
+///
+/// By default they don't count toward `MaxAlerts` and are shown as soon as they are spawned.
+/// With `counts_toward_max_alerts`, they wait for a free slot in their root like any other alert.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertParentPolicy<M: TypePath> {
+    pub counts_toward_max_alerts: bool,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertParentPolicy<M>
+where
+    M: TypePath,
+{
+    pub fn new(counts_toward_max_alerts: bool) -> Self {
+        Self {
+            counts_toward_max_alerts,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// An event that spawns an alert of this kind, as an alternative to piping into
+/// `AlertsPlugin::alert`.
+///
+/// Alerts sent from any schedule are spawned in the next `AlertSystems` pass.
+#[derive(Event)]
+pub struct AlertEvent<M: Component = AlertMarker> {
+    pub message: String,
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertEvent<M>
+where
+    M: Component,
+{
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Marks the combined card that stands in for every alert while over an `AlertEntityBudget`.
+#[derive(Debug, Default, Component, Reflect)]
+pub struct AlertCompacted;
+
+#[cfg(test)]
+mod tests {
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_mod_try_system::TrySystemExt;
+
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_alert_queued_observer() {
+        #[derive(Component)]
+        struct Tagged;
+
+        let mut app = manual_app();
+        app.observe(
+            |trigger: Trigger<AlertQueued>,
+             mut commands: Commands,
+             ui: Query<(), With<AlertUi>>| {
+                assert!(ui.get(trigger.entity()).is_err());
+                commands.entity(trigger.entity()).insert(Tagged);
+            },
+        );
+        app.add_systems(
+            PostUpdate,
+            (|alerts: Query<Has<Tagged>, (With<Alert>, Without<AlertUi>)>| {
+                assert!(alerts.iter().all(|tagged| tagged));
+            })
+            .before(AlertSystems),
+        );
+        app.add_systems(
+            Update,
+            (|| vec!["Queued".to_string()]).pipe(AlertsPlugin::alert),
+        );
+        app.update();
+
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<Has<Tagged>, (With<Alert>, With<AlertUi>)>();
+        let tagged = alerts.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(tagged, vec![true]);
+    }
+
+    #[test]
+    fn test_long_message_preview() {
+        let mut app = manual_app();
+        let message = "0123456789".repeat(2048);
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle(message.clone()), AlertMarker))
+            .id();
+        app.update();
+
+        let limits = app.world().resource::<AlertTextLimits<AlertMarker>>();
+        let text = app.world().get::<AlertParts>(alert).unwrap().text();
+        let rendered = &app.world().get::<Text>(text).unwrap().sections[0].value;
+        assert!(rendered.chars().count() <= limits.preview_len + 1);
+        assert!(message.starts_with(rendered.trim_end_matches('…')));
+        assert_eq!(app.world().get::<Alert>(alert).unwrap().message, message);
+    }
+
+    #[test]
+    fn test_alert_templates() {
+        let mut app = manual_app();
+        app.world_mut()
+            .resource_mut::<AlertTemplates<AlertMarker>>()
+            .register(
+                "low_ammo",
+                AlertTemplate {
+                    level: Some(AlertLevel::Warning),
+                    lifetime: Some(Duration::from_secs(4)),
+                    ..AlertTemplate::new("Low ammo: {0} ({1} left)")
+                },
+            )
+            .register(
+                "disconnected",
+                AlertTemplate {
+                    critical: Some(CriticalAlert::default()),
+                    details: Some("socket closed".to_string()),
+                    ..AlertTemplate::new("Lost connection to {0}")
+                },
+            );
+        app.world_mut().run_system_once(|mut commands: Commands| {
+            commands.alert_template("low_ammo", ["pistol", "3"]);
+            commands.alert_template("disconnected", ["server"]);
+            commands.alert_template("missing", ["x"]);
+        });
+
+        let mut alerts = app.world_mut().query::<(
+            &Alert,
+            Has<CriticalAlert>,
+            Option<&AlertDetails>,
+            Option<&AlertLevel>,
+            Option<&AlertLifetimeOverride>,
+        )>();
+        let mut alerts = alerts
+            .iter(app.world())
+            .map(|(alert, critical, details, level, lifetime)| {
+                (
+                    alert.message().to_string(),
+                    critical,
+                    details.map(|details| details.0.clone()),
+                    level.copied(),
+                    lifetime.map(|lifetime| lifetime.0),
+                )
+            })
+            .collect::<Vec<_>>();
+        alerts.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            alerts,
+            [
+                (
+                    "Lost connection to server".to_string(),
+                    true,
+                    Some("socket closed".to_string()),
+                    None,
+                    None
+                ),
+                (
+                    "Low ammo: pistol (3 left)".to_string(),
+                    false,
+                    None,
+                    Some(AlertLevel::Warning),
+                    Some(Duration::from_secs(4))
+                ),
+                (
+                    r#"Unknown alert template "missing" (x)"#.to_string(),
+                    false,
+                    None,
+                    None,
+                    None
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_queue_events() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(1));
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_millis(
+            250,
+        )));
+        let mut promoted_reader = app
+            .world()
+            .resource::<Events<AlertPromoted<AlertMarker>>>()
+            .get_reader();
+        let mut changed_reader = app
+            .world()
+            .resource::<Events<AlertQueueChanged<AlertMarker>>>()
+            .get_reader();
+        let mut promoted = Vec::new();
+        let mut changed = Vec::new();
+        let mut update = |app: &mut App| {
+            app.update();
+            let events = app.world().resource::<Events<AlertPromoted<AlertMarker>>>();
+            promoted.extend(promoted_reader.read(events).map(|event| event.entity));
+            let events = app
+                .world()
+                .resource::<Events<AlertQueueChanged<AlertMarker>>>();
+            changed.extend(changed_reader.read(events).map(|event| event.len));
+        };
+
+        let first = app
+            .world_mut()
+            .spawn((Alert::bundle("first"), AlertMarker))
+            .id();
+        let second = app
+            .world_mut()
+            .spawn((Alert::bundle("second"), AlertMarker))
+            .id();
+        app.world_mut().spawn((Alert::bundle("third"), AlertMarker));
+        update(&mut app);
+        assert_eq!(app.world().resource::<PendingCount<AlertMarker>>().get(), 2);
+
+        app.world_mut()
+            .run_system_once(|mut pending: PendingAlerts<AlertMarker>| {
+                pending.cancel_pending_matching(|alert| alert.message() == "third");
+            });
+        for _ in 0..5 {
+            update(&mut app);
+        }
+
+        assert_eq!(promoted, [first, second]);
+        assert_eq!(changed, [2, 1, 0]);
+    }
+
+    #[test]
+    fn test_alert_events() {
+        let mut app = manual_app();
+        app.add_systems(Startup, |mut events: EventWriter<AlertEvent>| {
+            events.send(AlertEvent::new("sent at startup"));
+        });
+        app.add_systems(
+            Update,
+            (|| vec!["piped".to_string()]).pipe(AlertsPlugin::alert),
+        );
+        app.update();
+
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<&Alert, (With<AlertMarker>, With<AlertUi>)>();
+        let mut messages = alerts
+            .iter(app.world())
+            .map(|alert| alert.message().to_string())
+            .collect::<Vec<_>>();
+        messages.sort();
+        assert_eq!(messages, ["piped", "sent at startup"]);
+    }
+
+    #[test]
+    fn test_alert_commands() {
+        let mut app = manual_app();
+        let turret = app.world_mut().spawn_empty().id();
+        let (alert, sourced) = app
+            .world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                let alert = commands.alert("Save failed");
+                let sourced = commands.entity(turret).alert("Turret jammed");
+                (alert, sourced)
+            });
+        app.update();
+
+        let root = app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .unwrap();
+        for entity in [alert, sourced] {
+            assert!(app.world().get::<AlertUi>(entity).is_some());
+            assert_eq!(
+                app.world().get::<Parent>(entity).map(Parent::get),
+                Some(root)
+            );
+        }
+        assert!(app.world().get::<AlertSource>(alert).is_none());
+        assert_eq!(
+            app.world().get::<AlertSource>(sourced).unwrap().entity,
+            turret
+        );
+    }
+
+    #[test]
+    fn test_alert_writer() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(2));
+        app.add_systems(
+            Update,
+            (
+                |mut alerts: AlertWriter| {
+                    alerts.send("from the first system");
+                },
+                |mut alerts: AlertWriter| {
+                    alerts.send_all(["second".to_string(), "third".to_string()]);
+                    alerts.send("fourth");
+                },
+            ),
+        );
+        app.update();
+
+        let mut displayed = app
+            .world_mut()
+            .query_filtered::<(), (With<AlertMarker>, With<AlertUi>)>();
+        let mut pending = app
+            .world_mut()
+            .query_filtered::<(), (With<AlertMarker>, With<AlertPending>)>();
+        assert_eq!(displayed.iter(app.world()).count(), 2);
+        assert_eq!(pending.iter(app.world()).count(), 2);
+    }
+
+    #[test]
+    fn test_alert_bundle() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let seeded = app
+            .world_mut()
+            .spawn((
+                AlertBundle {
+                    name: Name::new("Seeded"),
+                    timer: AlertTimer::with_elapsed(Duration::from_millis(900)),
+                    ..AlertBundle::new("almost gone")
+                },
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Name>(seeded).unwrap().as_str(), "Seeded");
+        assert!(app.world().get::<AlertDismissReason>(seeded).is_none());
+
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(seeded),
+            Some(&AlertDismissReason::Expired)
+        );
+    }
+
+    #[test]
+    fn test_alert_batches() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(5));
+        app.world_mut()
+            .resource_mut::<AlertElements<AlertMarker>>()
+            .batch_connector = Some(NodeBundle::default());
+        let (before, batch, after) = app.world_mut().run_system_once(|mut commands: Commands| {
+            let before = commands.alert("before");
+            let batch = commands.alert_batch(["quest 1", "quest 2", "quest 3"]);
+            let after = commands.alert("after");
+            (before, batch, after)
+        });
+        app.update();
+
+        let root = app
+            .world()
+            .resource::<AlertRoots<AlertMarker>>()
+            .primary()
+            .unwrap();
+        let children = app.world().get::<Children>(root).unwrap().to_vec();
+        let position = |entity: Entity| children.iter().position(|child| *child == entity);
+        let positions = batch
+            .iter()
+            .map(|entity| position(*entity))
+            .collect::<Vec<_>>();
+        let first = positions[0].unwrap();
+        assert_eq!(positions, [Some(first), Some(first + 1), Some(first + 2)]);
+        assert!(position(before).is_some());
+        assert!(position(after).is_some());
+        assert_eq!(
+            app.world().get::<AlertBatch>(batch[2]),
+            Some(&AlertBatch {
+                group: app.world().get::<AlertBatch>(batch[0]).unwrap().group,
+                index: 2,
+            })
+        );
+
+        // only the later cards of a batch are joined to the one before
+        let connectors = |entity: Entity| {
+            app.world()
+                .get::<Children>(entity)
+                .unwrap()
+                .iter()
+                .filter(|child| {
+                    app.world()
+                        .get::<Name>(**child)
+                        .is_some_and(|name| name.as_str() == "Alert Batch Connector")
+                })
+                .count()
+        };
+        assert_eq!(connectors(batch[0]), 0);
+        assert_eq!(connectors(batch[1]), 1);
+        assert_eq!(connectors(before), 0);
+    }
+
+    #[test]
+    fn test_result_adapters() {
+        #[derive(Debug)]
+        struct SaveError(&'static str);
+
+        impl std::fmt::Display for SaveError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Could not save: {}", self.0)
+            }
+        }
+
+        let mut app = manual_app();
+        app.add_systems(
+            Update,
+            (
+                (|| Err(SaveError("disk full"))).pipe(AlertsPlugin::alert_result),
+                (|| Ok::<(), SaveError>(())).pipe(AlertsPlugin::alert_result),
+                (|| Err::<(), _>(vec![SaveError("a"), SaveError("b")]))
+                    .pipe_err(AlertsPlugin::alert_errors),
+            ),
+        );
+        app.update();
+
+        let mut alerts = app.world_mut().query::<&Alert>();
+        let mut messages = alerts
+            .iter(app.world())
+            .map(|alert| alert.message().to_string())
+            .collect::<Vec<_>>();
+        messages.sort();
+        assert_eq!(
+            messages,
+            [
+                "Could not save: a",
+                "Could not save: b",
+                "Could not save: disk full"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generic_pipes() {
+        struct Reason(u32);
+
+        impl From<Reason> for String {
+            fn from(reason: Reason) -> Self {
+                format!("Reason #{}", reason.0)
+            }
+        }
+
+        let mut app = manual_app();
+        app.add_plugins(AlertsPlugin::<MyAlert>::default());
+        app.add_systems(
+            Update,
+            (
+                (|| vec!["borrowed"]).pipe(AlertsPlugin::alert_from),
+                (|| vec![Reason(7)]).pipe(AlertsPlugin::<MyAlert>::custom_alert_from),
+            ),
+        );
+        app.update();
+
+        let mut default_alerts = app
+            .world_mut()
+            .query_filtered::<&Alert, With<AlertMarker>>();
+        let default_alerts = default_alerts
+            .iter(app.world())
+            .map(|alert| alert.message().to_string())
+            .collect::<Vec<_>>();
+        let mut custom_alerts = app.world_mut().query_filtered::<&Alert, With<MyAlert>>();
+        let custom_alerts = custom_alerts
+            .iter(app.world())
+            .map(|alert| alert.message().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(default_alerts, ["borrowed"]);
+        assert_eq!(custom_alerts, ["Reason #7"]);
+    }
+
+    #[test]
+    fn test_option_pipe() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(1));
+        app.add_systems(
+            Update,
+            (
+                (|| Some("first".to_string())).pipe(AlertsPlugin::alert_option),
+                (|| None).pipe(AlertsPlugin::alert_option),
+            ),
+        );
+        app.update();
+        app.update();
+
+        let mut alerts = app.world_mut().query::<(&Alert, Has<AlertPending>)>();
+        let alerts = alerts.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(alerts.len(), 2);
+        assert!(alerts.iter().all(|(alert, _)| alert.message() == "first"));
+        assert_eq!(alerts.iter().filter(|(_, pending)| *pending).count(), 1);
+        assert_eq!(app.world().resource::<PendingCount<AlertMarker>>().get(), 1);
+    }
+
+    #[test]
+    fn test_iter_pipe() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(5));
+        app.add_systems(
+            Update,
+            (|| (0..500).map(|index| format!("Mod #{index} failed to parse")))
+                .pipe(AlertsPlugin::alert_iter),
+        );
+        app.update();
+
+        let mut alerts = app.world_mut().query::<(&Alert, Has<AlertUi>)>();
+        let alerts = alerts.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(alerts.len(), 500);
+        assert_eq!(alerts.iter().filter(|(_, visible)| *visible).count(), 5);
+    }
+
+    #[test]
+    fn test_world_alerts() {
+        let mut app = manual_app();
+        app.add_systems(Update, |world: &mut World| {
+            if world.query::<&Alert>().iter(world).next().is_none() {
+                world.spawn_alert::<AlertMarker>("Scene failed to load");
+            }
+        });
+        app.update();
+
+        let mut alerts = app.world_mut().query::<(&Alert, &Parent)>();
+        let (alert, parent) = alerts.single(app.world());
+        assert_eq!(alert.message(), "Scene failed to load");
+        assert!(app.world().get::<AlertUiRoot>(parent.get()).is_some());
+    }
+
+    #[test]
+    fn test_alert_builder() {
+        let mut app = manual_app();
+        let built = app.world_mut().run_system_once(|mut commands: Commands| {
+            Alert::build("Connection lost")
+                .key("net")
+                .lifetime(Duration::from_secs(3))
+                .spawn(&mut commands)
+        });
+        let bundled = app
+            .world_mut()
+            .spawn(
+                Alert::build("Save data is corrupted")
+                    .persistent()
+                    .marker::<MyAlert>()
+                    .bundle(),
+            )
+            .id();
+        let plain = app.world_mut().spawn(Alert::build("Saved").bundle()).id();
+        app.world_mut().flush_commands();
+
+        let world = app.world();
+        let built = world.entity(built);
+        assert_eq!(built.get::<Alert>().unwrap().message(), "Connection lost");
+        assert!(built.contains::<AlertMarker>());
+        assert!(built.contains::<AlertTimer>());
+        assert_eq!(built.get::<AlertKey>(), Some(&AlertKey::new("net")));
+        assert_eq!(
+            built.get::<AlertLifetimeOverride>().unwrap().0,
+            Duration::from_secs(3)
+        );
+        assert!(!built.contains::<PersistentAlert>());
+
+        let bundled = world.entity(bundled);
+        assert!(bundled.contains::<MyAlert>());
+        assert!(!bundled.contains::<AlertMarker>());
+        assert!(bundled.contains::<PersistentAlert>());
+        assert!(!bundled.contains::<AlertOptions>());
+        assert!(!bundled.contains::<AlertKey>());
+
+        let plain_entity = world.entity(plain);
+        assert!(plain_entity.contains::<AlertMarker>());
+        assert!(!plain_entity.contains::<AlertOptions>());
+        assert!(!plain_entity.contains::<AlertLifetimeOverride>());
+
+        app.update();
+        assert!(app.world().get::<AlertUi>(plain).is_some());
+    }
+
+    #[test]
+    fn test_alert_title() {
+        let mut app = manual_app();
+        let titled = app
+            .world_mut()
+            .spawn((
+                Alert::titled("Connection lost", "Retrying in 5 seconds"),
+                AlertMarker,
+            ))
+            .id();
+        let untitled = app
+            .world_mut()
+            .spawn((Alert::bundle("Saved"), AlertMarker))
+            .id();
+        app.update();
+
+        let parts = *app.world().get::<AlertParts>(titled).unwrap();
+        let title = parts.title().unwrap();
+        let text = app.world().get::<Text>(title).unwrap();
+        assert_eq!(text.sections[0].value, "Connection lost");
+        assert_eq!(text.linebreak_behavior, bevy::text::BreakLineOn::NoWrap);
+        let body = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(body.sections[0].value, "Retrying in 5 seconds");
+
+        // the title sits left of the dismiss button, in a node that clips long titles
+        let clip = app.world().get::<Parent>(title).unwrap().get();
+        assert_eq!(
+            app.world().get::<Style>(clip).unwrap().overflow,
+            Overflow::clip()
+        );
+        let header = app.world().get::<Children>(parts.header()).unwrap();
+        assert_eq!(header.first(), Some(&clip));
+        assert_eq!(header.last(), Some(&parts.dismiss_button()));
+
+        let untitled_parts = app.world().get::<AlertParts>(untitled).unwrap();
+        assert!(untitled_parts.title().is_none());
+        let header = app
+            .world()
+            .get::<Children>(untitled_parts.header())
+            .unwrap();
+        assert_eq!(header.len(), 1);
+
+        app.world_mut()
+            .get_mut::<Alert>(titled)
+            .unwrap()
+            .set_title(Some("Connection restored".to_string()));
+        app.update();
+        let text = app.world().get::<Text>(title).unwrap();
+        assert_eq!(text.sections[0].value, "Connection restored");
+    }
+
+    #[test]
+    fn test_alert_timestamps() {
+        let mut app = manual_app();
+        let untimed = app
+            .world_mut()
+            .spawn((Alert::bundle("Before"), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+        app.insert_resource(AlertTimestamps::<AlertMarker>::new(
+            AlertTimestamps::<AlertMarker>::elapsed_format,
+        ));
+        let titled = app
+            .world_mut()
+            .spawn((Alert::titled("Autosave", "Saved"), AlertMarker))
+            .id();
+        app.update();
+
+        assert!(app.world().get::<AlertTimestamp>(untimed).is_none());
+        let parts = app.world().get::<AlertParts>(untimed).unwrap();
+        assert!(parts.timestamp().is_none());
+
+        let timestamp = app.world().get::<AlertTimestamp>(titled).unwrap();
+        assert_eq!(timestamp.elapsed, Duration::from_millis(500));
+        let parts = *app.world().get::<AlertParts>(titled).unwrap();
+        let text = parts.timestamp().unwrap();
+        assert_eq!(
+            app.world().get::<Text>(text).unwrap().sections[0].value,
+            "+00:00.5"
+        );
+        // right of the title, left of the dismiss button
+        let header = app.world().get::<Children>(parts.header()).unwrap();
+        assert_eq!(header[1], text);
+        assert_eq!(header.last(), Some(&parts.dismiss_button()));
+
+        // without a wall clock, the default format falls back to the elapsed time
+        let mut timestamp = AlertTimestamp {
+            elapsed: Duration::from_secs(125),
+            wall_clock: None,
+        };
+        let format = AlertTimestamps::<AlertMarker>::default();
+        assert_eq!(format.format(&timestamp), "+02:05.0");
+        timestamp.wall_clock = Some(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(86_400 * 3 + 14 * 3600 + 3 * 60 + 27),
+        );
+        assert_eq!(format.format(&timestamp), "14:03:27 UTC");
+    }
+
+    #[test]
+    fn test_alert_rich_text() {
+        let mut app = manual_app();
+        let bold = TextStyle {
+            font_size: 24.,
+            color: Color::srgb(1., 0.5, 0.),
+            ..Default::default()
+        };
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::rich(vec![
+                    ("Failed to load ".to_string(), TextStyle::default()),
+                    ("save_01.ron".to_string(), bold.clone()),
+                ]),
+                AlertMarker,
+            ))
+            .id();
+        let plain = app
+            .world_mut()
+            .spawn((Alert::bundle("Saved"), AlertMarker))
+            .id();
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Alert>(alert).unwrap().message(),
+            "Failed to load save_01.ron"
+        );
+        let parts = *app.world().get::<AlertParts>(alert).unwrap();
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections.len(), 2);
+        assert_eq!(text.sections[1].value, "save_01.ron");
+        assert_eq!(text.sections[1].style.font_size, bold.font_size);
+        assert_eq!(text.sections[1].style.color, bold.color);
+        let plain_parts = *app.world().get::<AlertParts>(plain).unwrap();
+        let text = app.world().get::<Text>(plain_parts.text()).unwrap();
+        assert_eq!(text.sections.len(), 1);
+
+        // restyles leave the sections alone, and a new message replaces them
+        app.world_mut()
+            .resource_mut::<AlertElements<AlertMarker>>()
+            .set_changed();
+        app.update();
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections[1].style.color, bold.color);
+        app.world_mut()
+            .get_mut::<Alert>(alert)
+            .unwrap()
+            .set_message("Loaded");
+        app.update();
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections.len(), 1);
+        assert_eq!(text.sections[0].value, "Loaded");
+    }
+
+    #[test]
+    fn test_alert_linebreaks() {
+        let mut app = manual_app();
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("First line\nSecond line\nThird line"),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+
+        let parts = *app.world().get::<AlertParts>(alert).unwrap();
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections.len(), 1);
+        assert_eq!(text.sections[0].value.lines().count(), 3);
+        assert_eq!(text.linebreak_behavior, BreakLineOn::WordBoundary);
+        // wrapped within the card rather than stretching it
+        let style = app.world().get::<Style>(parts.text()).unwrap();
+        assert_eq!(style.max_width, Val::Percent(100.));
+
+        app.world_mut()
+            .resource_mut::<AlertElements<AlertMarker>>()
+            .linebreak = BreakLineOn::AnyCharacter;
+        app.update();
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.linebreak_behavior, BreakLineOn::AnyCharacter);
+    }
+
+    #[test]
+    fn test_alert_progress() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::keyed("download", "Downloading assets…"),
+                AlertProgress(0.),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+
+        let bar = app
+            .world()
+            .get::<AlertParts>(alert)
+            .unwrap()
+            .progress_bar()
+            .unwrap();
+        let width = |app: &App| app.world().get::<Style>(bar).unwrap().width;
+        assert_eq!(width(&app), Val::Percent(0.));
+
+        // the lifetime waits for the download
+        for step in 1..=4 {
+            app.world_mut()
+                .commands()
+                .set_alert_progress(alert, step as f32 * 0.2);
+            app.world_mut().flush_commands();
+            app.update();
+            assert_eq!(width(&app), Val::Percent(step as f32 * 20.));
+        }
+        assert_eq!(
+            app.world().get::<AlertTimer>(alert).unwrap().elapsed(),
+            Duration::ZERO
+        );
+
+        app.world_mut()
+            .commands()
+            .set_keyed_alert_progress("download", 1.);
+        app.world_mut().flush_commands();
+        app.update();
+        assert_eq!(width(&app), Val::Percent(100.));
+        app.update();
+        assert!(app.world().get::<AlertDismissReason>(alert).is_none());
+        for _ in 0..8 {
+            app.update();
+        }
+        assert!(app.world().get_entity(alert).is_none());
+    }
+
+    #[test]
+    fn test_resolve_alert() {
+        let mut app = manual_app();
+        let saving = app
+            .world_mut()
+            .spawn((Alert::unresolved("Saving…"), AlertMarker))
+            .id();
+        let uploading = app
+            .world_mut()
+            .spawn((Alert::unresolved("Uploading…"), AlertMarker))
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let parts = *app.world().get::<AlertParts>(saving).unwrap();
+        let spinner = parts.spinner().unwrap();
+        let header = app.world().get::<Children>(parts.header()).unwrap();
+        assert_eq!(header[0], spinner);
+        assert!(AlertSpinner::FRAMES.contains(
+            &app.world().get::<Text>(spinner).unwrap().sections[0]
+                .value
+                .as_str()
+        ));
+        for alert in [saving, uploading] {
+            let timer = app.world().get::<AlertTimer>(alert).unwrap();
+            assert_eq!(timer.elapsed(), Duration::ZERO);
+        }
+
+        app.world_mut()
+            .commands()
+            .resolve_alert(saving, Ok("Saved!".to_string()));
+        app.world_mut()
+            .commands()
+            .resolve_alert(uploading, Err("Upload failed".to_string()));
+        app.world_mut().flush_commands();
+        app.update();
+
+        let alert = app.world().get::<Alert>(saving).unwrap();
+        assert_eq!(alert.message(), "Saved!");
+        assert!(app.world().get::<UnresolvedAlert>(saving).is_none());
+        assert!(app
+            .world()
+            .get::<AlertParts>(saving)
+            .unwrap()
+            .spinner()
+            .is_none());
+        assert!(app.world().get_entity(spinner).is_none());
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections[0].value, "Saved!");
+        assert!(app.world().get::<AlertLevel>(saving).is_none());
+        assert_eq!(
+            app.world().get::<AlertLevel>(uploading),
+            Some(&AlertLevel::Error)
+        );
+        assert_eq!(
+            app.world().get::<Alert>(uploading).unwrap().message(),
+            "Upload failed"
+        );
+        // the lifetime starts counting once resolved
+        for alert in [saving, uploading] {
+            let timer = app.world().get::<AlertTimer>(alert).unwrap();
+            assert_eq!(timer.elapsed(), Duration::from_millis(250));
+        }
+    }
+
+    #[test]
+    fn test_escalate_alert() {
+        let mut app = manual_app();
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::with_level(AlertLevel::Warning, "Disk space low"),
+                AlertKey::new("disk"),
+                AlertMarker,
+            ))
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+        let parts = *app.world().get::<AlertParts>(alert).unwrap();
+        let elapsed = app.world().get::<AlertTimer>(alert).unwrap().elapsed();
+        assert!(elapsed > Duration::ZERO);
+
+        // lower levels are ignored
+        EscalateAlert::keyed("disk", AlertLevel::Info).apply(app.world_mut());
+        assert_eq!(
+            app.world().get::<AlertLevel>(alert),
+            Some(&AlertLevel::Warning)
+        );
+
+        EscalateAlert::keyed("disk", AlertLevel::Error)
+            .persistent()
+            .apply(app.world_mut());
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertLevel>(alert),
+            Some(&AlertLevel::Error)
+        );
+        assert!(app.world().entity(alert).contains::<PersistentAlert>());
+        // the same card is recolored and the lifetime carries on
+        assert_eq!(
+            app.world().get::<AlertParts>(alert).unwrap().header(),
+            parts.header()
+        );
+        let error = *app
+            .world()
+            .resource::<AlertElements<AlertMarker>>()
+            .level_colors(AlertLevel::Error)
+            .unwrap();
+        assert_eq!(
+            app.world()
+                .get::<BackgroundColor>(parts.header())
+                .unwrap()
+                .0,
+            error.header
+        );
+        assert!(app.world().get::<AlertTimer>(alert).unwrap().elapsed() >= elapsed);
+
+        // the persistent alert outlives the kind's lifetime
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(Duration::from_secs(60));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(20)));
+        app.update();
+        app.update();
+        assert!(app.world().entity(alert).contains::<AlertUi>());
+    }
+
+    #[test]
+    fn test_alert_text_follows_message() {
+        let mut app = manual_app();
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("Downloading: 0%"), AlertMarker))
+            .id();
+        app.update();
+        let text = app.world().get::<AlertParts>(alert).unwrap().text();
+        assert!(app.world().get::<AlertText>(text).is_some());
+
+        app.world_mut()
+            .get_mut::<Alert>(alert)
+            .unwrap()
+            .set_message("Downloading: 42%");
+        app.update();
+        let sections = &app.world().get::<Text>(text).unwrap().sections;
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].value, "Downloading: 42%");
+        assert_eq!(
+            sections[0].style.font_size,
+            app.world()
+                .resource::<AlertElements<AlertMarker>>()
+                .text()
+                .font_size
+        );
+
+        // mid-reveal, the typewriter picks up the new message instead
+        let typed = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Connecting"),
+                TypewriterText(Duration::from_secs(10)),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        app.update();
+        app.world_mut()
+            .get_mut::<Alert>(typed)
+            .unwrap()
+            .set_message("Connected");
+        app.update();
+        let text = app.world().get::<AlertParts>(typed).unwrap().text();
+        let message = app
+            .world()
+            .get::<Text>(text)
+            .unwrap()
+            .sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect::<String>();
+        assert_eq!(message, "Connected");
+    }
+
+    #[test]
+    fn test_status_line() {
+        let set_status = |app: &mut App, message: &'static str| {
+            app.world_mut()
+                .run_system_once(move |mut commands: Commands| {
+                    commands.set_status::<AlertMarker>(message);
+                });
+        };
+        let status_text = |app: &App, line: Entity| {
+            let text = app.world().get::<Children>(line).unwrap()[0];
+            app.world().get::<Text>(text).unwrap().sections[0]
+                .value
+                .clone()
+        };
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+
+        // the status line brings up a root by itself
+        set_status(&mut app, "Connected to server · 42 ms");
+        app.update();
+        let root = app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .unwrap();
+        let line = app
+            .world()
+            .resource::<AlertStatus<AlertMarker>>()
+            .entity()
+            .unwrap();
+        assert_eq!(**app.world().get::<Children>(root).unwrap(), [line]);
+        assert_eq!(status_text(&app, line), "Connected to server · 42 ms");
+
+        // it takes none of the alert slots and stays below the stack
+        let alerts = (0..4)
+            .map(|_| {
+                app.world_mut()
+                    .spawn((Alert::bundle("Player joined"), AlertMarker))
+                    .id()
+            })
+            .collect::<Vec<_>>();
+        app.update();
+        let children = app.world().get::<Children>(root).unwrap().to_vec();
+        assert_eq!(children.len(), 4);
+        assert_eq!(children.last(), Some(&line));
+        assert!(app.world().get::<AlertPending>(alerts[3]).is_some());
+
+        // a new status replaces the text in place
+        set_status(&mut app, "Connected to server · 57 ms");
+        app.update();
+        assert_eq!(
+            app.world().resource::<AlertStatus<AlertMarker>>().entity(),
+            Some(line)
+        );
+        assert_eq!(status_text(&app, line), "Connected to server · 57 ms");
+        assert!(app.world().get::<AlertTransition>(line).is_none());
+
+        // the root outlives the alerts while the status is set
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(alerts
+            .iter()
+            .all(|alert| app.world().get_entity(*alert).is_none()));
+        assert_eq!(**app.world().get::<Children>(root).unwrap(), [line]);
+
+        app.world_mut().run_system_once(|mut commands: Commands| {
+            commands.clear_status::<AlertMarker>();
+        });
+        app.update();
+        assert!(app.world().get_entity(root).is_none());
+        assert!(app.world().get_entity(line).is_none());
+        assert_eq!(
+            app.world()
+                .resource::<CurrentAlertRoot<AlertMarker>>()
+                .get(),
+            None
+        );
+        assert_eq!(
+            app.world().resource::<AlertStatus<AlertMarker>>().entity(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_overflow_indicator() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(2));
+        for index in 0..5 {
+            app.world_mut()
+                .spawn((Alert::bundle(format!("Alert {index}")), AlertMarker));
+        }
+        app.update();
+
+        let mut indicators = app
+            .world_mut()
+            .query_filtered::<(Entity, &Parent, &Children), With<AlertOverflowIndicator>>();
+        let (indicator, root, children) = indicators.single(app.world());
+        let text = app.world().get::<Text>(children[0]).unwrap();
+        assert_eq!(text.sections[0].value, "+3 more");
+        // pinned below the alerts, without taking a slot
+        let root_children = app.world().get::<Children>(root.get()).unwrap();
+        assert_eq!(root_children.len(), 3);
+        assert_eq!(root_children.last(), Some(&indicator));
+
+        // the line goes away once the queue drains
+        **app.world_mut().resource_mut::<MaxAlerts<AlertMarker>>() = 5;
+        app.update();
+        let mut indicators = app
+            .world_mut()
+            .query_filtered::<(), With<AlertOverflowIndicator>>();
+        assert_eq!(indicators.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_entity_budget() {
+        #[derive(Default, Resource)]
+        struct Compactions(usize);
+
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(10));
+        app.insert_resource(AlertEntityBudget::<AlertMarker>::new(20));
+        app.init_resource::<Compactions>();
+        app.observe(
+            |trigger: Trigger<OnRemove, Alert>,
+             reasons: Query<&AlertDismissReason>,
+             mut compactions: ResMut<Compactions>| {
+                if reasons.get(trigger.entity()).ok() == Some(&AlertDismissReason::Compacted) {
+                    compactions.0 += 1;
+                }
+            },
+        );
+
+        app.world_mut()
+            .spawn((Alert::bundle("before the flood"), AlertMarker));
+        app.update();
+        for index in 0..5 {
+            app.world_mut()
+                .spawn((Alert::bundle(format!("flood #{index}")), AlertMarker));
+        }
+        app.update();
+        app.update();
+
+        let card = app
+            .world()
+            .resource::<AlertEntityBudget<AlertMarker>>()
+            .compacted()
+            .unwrap();
+        let mut alerts = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        assert_eq!(alerts.iter(app.world()).collect::<Vec<_>>(), [card]);
+        assert!(app.world().get::<AlertUi>(card).is_some());
+        let group = app.world().get::<AlertGroup>(card).unwrap();
+        assert_eq!(group.items().len(), 6);
+        assert_eq!(group.items()[0], "flood #4");
+        assert_eq!(group.items()[5], "before the flood");
+        assert_eq!(app.world().resource::<Compactions>().0, 6);
+
+        // later alerts join the combined card
+        app.world_mut()
+            .spawn((Alert::bundle("latecomer"), AlertMarker));
+        app.update();
+        let group = app.world().get::<AlertGroup>(card).unwrap();
+        assert_eq!(group.items().len(), 7);
+        assert_eq!(group.items()[0], "latecomer");
+
+        // once the combined card is gone, alerts get their own cards again
+        app.world_mut().entity_mut(card).despawn_recursive();
+        app.update();
+        assert!(app
+            .world()
+            .resource::<AlertEntityBudget<AlertMarker>>()
+            .compacted()
+            .is_none());
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("calm again"), AlertMarker))
+            .id();
+        app.update();
+        assert!(app.world().get::<AlertUi>(alert).is_some());
+        assert!(app.world().get::<AlertCompacted>(alert).is_none());
+        assert_eq!(app.world().resource::<Compactions>().0, 7);
+    }
+
+    #[test]
+    fn test_alert_parent() {
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        **app.world_mut().resource_mut::<MaxAlerts<AlertMarker>>() = 1;
+        let panel = app.world_mut().spawn(NodeBundle::default()).id();
+        let shared = app
+            .world_mut()
+            .spawn((Alert::bundle("Autosave failed"), AlertMarker))
+            .id();
+        let parented = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Invalid resolution"),
+                AlertMarker,
+                AlertParent(panel),
+            ))
+            .id();
+        app.update();
+
+        // the parented alert doesn't take the only slot in the root
+        let root = app
+            .world()
+            .resource::<CurrentAlertRoot<AlertMarker>>()
+            .get()
+            .unwrap();
+        assert_eq!(**app.world().get::<Children>(root).unwrap(), [shared]);
+        assert_eq!(**app.world().get::<Children>(panel).unwrap(), [parented]);
+        assert!(app.world().get::<AlertUi>(parented).is_some());
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(app.world().get_entity(shared).is_none());
+        assert!(app.world().get_entity(parented).is_none());
+        assert!(app.world().get_entity(root).is_none());
+        assert!(app.world().get_entity(panel).is_some());
+    }
+
+    #[test]
+    fn test_alert_levels() {
+        let mut app = manual_app();
+        app.add_systems(
+            Update,
+            (|| {
+                vec![
+                    (AlertLevel::Info, "Saved".to_string()),
+                    (AlertLevel::Warning, "Low disk space".to_string()),
+                    (AlertLevel::Error, "Save failed".to_string()),
+                ]
+            })
+            .pipe(AlertsPlugin::alert_levels)
+            .run_if(|mut fired: Local<bool>| !std::mem::replace(&mut *fired, true)),
+        );
+        app.update();
+
+        let mut alerts = app
+            .world_mut()
+            .query::<(Entity, &AlertLevel, &BackgroundColor, &AlertParts)>();
+        let mut alerts = alerts
+            .iter(app.world())
+            .map(|(entity, level, background, parts)| (*level, entity, background.0, *parts))
+            .collect::<Vec<_>>();
+        alerts.sort_by_key(|(level, ..)| *level as u8);
+        assert_eq!(alerts.len(), 3);
+        let elements = app.world().resource::<AlertElements<AlertMarker>>();
+        let warning = *elements.level_colors(AlertLevel::Warning).unwrap();
+        assert_eq!(alerts[0].2, elements.alert().background_color.0);
+        assert_ne!(alerts[0].2, alerts[1].2);
+        assert_ne!(alerts[1].2, alerts[2].2);
+        assert_ne!(alerts[0].2, alerts[2].2);
+        let header = |app: &App, parts: &AlertParts| {
+            app.world()
+                .get::<BackgroundColor>(parts.header())
+                .unwrap()
+                .0
+        };
+        assert_eq!(header(&app, &alerts[1].3), warning.header);
+
+        // changing the level of a shown alert recolors it
+        let (_, info, _, parts) = alerts[0];
+        *app.world_mut().get_mut::<AlertLevel>(info).unwrap() = AlertLevel::Warning;
+        app.update();
+        assert_eq!(
+            app.world().get::<BackgroundColor>(info).unwrap().0,
+            warning.background
+        );
+        assert_eq!(header(&app, &parts), warning.header);
+    }
+
+    #[test]
+    fn test_alert_icon() {
+        let mut app = manual_app();
+        let icon = Handle::<Image>::default();
+        let with_icon = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("Low disk space"),
+                AlertIcon(icon.clone()),
+                AlertMarker,
+            ))
+            .id();
+        let without_icon = app
+            .world_mut()
+            .spawn((Alert::bundle("Saved"), AlertMarker))
+            .id();
+        app.update();
+
+        let parts = *app.world().get::<AlertParts>(with_icon).unwrap();
+        let image = parts.icon().unwrap();
+        assert_eq!(app.world().get::<UiImage>(image).unwrap().texture, icon);
+        assert_eq!(
+            app.world().get::<Style>(image).unwrap().width,
+            app.world()
+                .resource::<AlertElements<AlertMarker>>()
+                .icon()
+                .width
+        );
+        // the icon comes before the text
+        let body = app.world().get::<Children>(parts.body()).unwrap();
+        assert_eq!(**body, [image, parts.text()]);
+
+        let parts = *app.world().get::<AlertParts>(without_icon).unwrap();
+        assert!(parts.icon().is_none());
+        let body = app.world().get::<Children>(parts.body()).unwrap();
+        assert_eq!(**body, [parts.text()]);
+    }
+
+    #[test]
+    fn test_alert_key() {
+        let mut app = manual_app();
+        app.add_systems(
+            Update,
+            (|| vec![("net".to_string(), "connection lost".to_string())])
+                .pipe(AlertsPlugin::alert_keyed)
+                .run_if(|mut fired: Local<u32>| {
+                    *fired += 1;
+                    *fired <= 3
+                }),
+        );
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let mut shown = app
+            .world_mut()
+            .query_filtered::<(&Alert, &AlertTimer, &AlertParts), With<AlertUi>>();
+        let shown = shown.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(shown.len(), 1);
+        let (alert, timer, parts) = shown[0];
+        assert_eq!(alert.message(), "connection lost");
+        // restarted by the third alert, so it is two updates old rather than three
+        assert_eq!(timer.elapsed(), Duration::from_millis(500));
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections[0].value, "connection lost");
+        let mut keyed = app.world_mut().query::<&AlertKey>();
+        assert_eq!(keyed.iter(app.world()).count(), 1);
+
+        // a new message replaces the text in place
+        let refreshed = app
+            .world_mut()
+            .spawn((Alert::keyed("net", "reconnecting"), AlertMarker))
+            .id();
+        app.update();
+        assert!(app.world().get_entity(refreshed).is_none());
+        let mut shown = app
+            .world_mut()
+            .query_filtered::<(&Alert, &AlertTimer, &AlertParts), With<AlertUi>>();
+        let (alert, timer, parts) = shown.single(app.world());
+        assert_eq!(alert.message(), "reconnecting");
+        assert_eq!(timer.elapsed(), Duration::from_millis(250));
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections[0].value, "reconnecting");
+
+        // other keys stack as usual
+        app.world_mut()
+            .spawn((Alert::keyed("disk", "disk full"), AlertMarker));
+        app.update();
+        let mut shown = app.world_mut().query_filtered::<(), With<AlertUi>>();
+        assert_eq!(shown.iter(app.world()).count(), 2);
+    }
+}
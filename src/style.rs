@@ -0,0 +1,1656 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn fall_back_from_missing_font(
+        mut commands: Commands,
+        alerts: Query<&AlertParts, With<AlertManaged<M>>>,
+        children: Query<&Children>,
+        mut texts: Query<&mut Text>,
+        alert_nodes: Res<AlertElements<M>>,
+        fonts: Option<Res<Assets<Font>>>,
+        asset_server: Option<Res<AssetServer>>,
+        mut fallback: ResMut<AlertFontFallback<M>>,
+        factory: Res<MarkerFactory<M>>,
+    ) {
+        let font = &alert_nodes.text().font;
+        // a font still loading gets the chance to arrive; one that failed or is not being loaded
+        // at all never will
+        let missing = *font != Handle::default()
+            && fonts.is_some_and(|fonts| !fonts.contains(font))
+            && asset_server.map_or(true, |server| {
+                !matches!(
+                    server.get_load_state(font.id()),
+                    Some(LoadState::Loading | LoadState::Loaded)
+                )
+            });
+        fallback.active = missing;
+        if !missing {
+            return;
+        }
+
+        if fallback.warned != Some(font.id()) {
+            fallback.warned = Some(font.id());
+            let message = format!("The alert font {font:?} is unavailable, using the default font");
+            warn!("{message}");
+            commands.spawn((Alert::bundle(message), factory.make()));
+        }
+
+        for parts in &alerts {
+            let rows = parts
+                .group_rows
+                .and_then(|rows| children.get(rows).ok())
+                .into_iter()
+                .flat_map(|rows| rows.iter().copied());
+            let header_texts = parts
+                .title
+                .into_iter()
+                .chain(parts.timestamp)
+                .chain(parts.repeat_count)
+                .chain(parts.spinner);
+            for text in std::iter::once(parts.text).chain(header_texts).chain(rows) {
+                let Ok(mut text) = texts.get_mut(text) else {
+                    continue;
+                };
+                if text
+                    .sections
+                    .iter()
+                    .any(|section| section.style.font == *font)
+                {
+                    for section in &mut text.sections {
+                        if section.style.font == *font {
+                            section.style.font = Handle::default();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reapplies the current `AlertElements` to the root and every visible alert, keeping the
+    /// slide offset and scale that transitions animate.
+    pub(crate) fn scale_alert_elements(
+        mut alert_nodes: ResMut<AlertElements<M>>,
+        mut scaling: ResMut<AlertScaling<M>>,
+        ui_scale: Option<Res<UiScale>>,
+        windows: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        // elements changed from outside are unscaled again, and not in the small window layout
+        if scaling.applied_tick != Some(alert_nodes.last_changed()) {
+            scaling.applied = 1.;
+            scaling.small_window_saved = None;
+        }
+        let ui_scale = ui_scale.map_or(1., |ui_scale| ui_scale.0);
+        let factor = match scaling.mode {
+            AlertScaleMode::Physical => 1.,
+            AlertScaleMode::ScaledWithUi => ui_scale,
+            AlertScaleMode::Automatic => {
+                ui_scale
+                    * windows
+                        .get_single()
+                        .map_or(1., |window| window.scale_factor())
+            }
+        };
+        if factor != scaling.applied {
+            alert_nodes.scale(factor / scaling.applied);
+            scaling.applied = factor;
+        }
+
+        let small = scaling
+            .small_window
+            .as_ref()
+            .zip(windows.get_single().ok())
+            .is_some_and(|(layout, window)| {
+                window.width() < layout.min_width || window.height() < layout.min_height
+            });
+        match (small, scaling.small_window_saved.take()) {
+            (true, None) => {
+                scaling.small_window_saved = Some((alert_nodes.clone(), scaling.applied));
+                if let Some(layout) = scaling.small_window.as_ref() {
+                    alert_nodes.apply_small_window(layout, scaling.applied);
+                }
+            }
+            (false, Some((mut saved, saved_factor))) => {
+                // the scale may have changed while the small window layout was shown
+                if saved_factor != scaling.applied {
+                    saved.scale(scaling.applied / saved_factor);
+                }
+                *alert_nodes = saved;
+            }
+            (_, saved) => scaling.small_window_saved = saved,
+        }
+        scaling.applied_tick = Some(alert_nodes.last_changed());
+    }
+
+    /// Recolors shown alerts whose `AlertLevel` changed.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn restyle_alert_levels(
+        alerts: Query<
+            (Entity, &AlertParts, &AlertLevel, Has<CriticalAlert>),
+            (With<AlertManaged<M>>, Changed<AlertLevel>),
+        >,
+        mut colors: Query<(&mut BackgroundColor, &mut BorderColor)>,
+        alert_nodes: Res<AlertElements<M>>,
+    ) {
+        for (entity, parts, level, is_critical) in &alerts {
+            let (card, header) = alert_nodes.leveled(*level, is_critical);
+            for (entity, node) in [(entity, &card), (parts.header, &header)] {
+                let Ok((mut background, mut border)) = colors.get_mut(entity) else {
+                    continue;
+                };
+                // keep the alpha, which fades with the card
+                background.0 = node.background_color.0.with_alpha(background.0.alpha());
+                border.0 = node.border_color.0.with_alpha(border.0.alpha());
+            }
+        }
+    }
+
+    pub(crate) fn restyle_alerts(
+        mut restyler: AlertRestyler<M>,
+        auto_restyle: Res<AutoRestyle<M>>,
+    ) {
+        if !auto_restyle.enabled || !restyler.alert_nodes.is_changed() {
+            return;
+        }
+        restyler.restyle();
+    }
+}
+
+/// Whether alerts of this kind are shown in the default font because the font in
+/// `AlertElements::text` failed to load.
+///
+/// Alerts spawned once the font is available use it again.
+#[derive(Debug, Resource)]
+pub struct AlertFontFallback<M> {
+    pub(crate) active: bool,
+    pub(crate) warned: Option<AssetId<Font>>,
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertFontFallback<M> {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl<M> Default for AlertFontFallback<M> {
+    fn default() -> Self {
+        Self {
+            active: false,
+            warned: None,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// Everything needed to reapply the current `AlertElements` to visible alerts.
+#[derive(SystemParam)]
+pub(crate) struct AlertRestyler<'w, 's, M>
+where
+    M: Component + TypePath,
+{
+    nodes: Query<'w, 's, NodeStyle>,
+    texts: Query<'w, 's, &'static mut Text>,
+    children: Query<'w, 's, &'static Children>,
+    roots:
+        Query<'w, 's, (Entity, &'static AlertRootKey), (With<AlertManaged<M>>, With<AlertUiRoot>)>,
+    #[allow(clippy::type_complexity)]
+    cards: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static AlertParts,
+            Option<&'static AlertJitterOffset>,
+            Option<&'static AlertLevel>,
+            Has<CriticalAlert>,
+            Has<AlertGroup>,
+            Has<AlertRichText>,
+        ),
+        (With<AlertManaged<M>>, With<AlertUi>),
+    >,
+    alert_nodes: Res<'w, AlertElements<M>>,
+    spillover: Res<'w, SpilloverAnchor<M>>,
+}
+
+impl<M> AlertRestyler<'_, '_, M>
+where
+    M: Component + TypePath,
+{
+    pub(crate) fn restyle(&mut self) {
+        for (root, key) in &self.roots {
+            if let Ok(mut node) = self.nodes.get_mut(root) {
+                node.apply(&self.spillover.root_node(key, &self.alert_nodes));
+            }
+        }
+
+        for (entity, parts, jitter_offset, level, is_critical, is_group, is_rich) in &self.cards {
+            let level = level.copied().unwrap_or_default();
+            let (card, header) = self.alert_nodes.leveled(level, is_critical);
+            if let Ok(mut node) = self.nodes.get_mut(entity) {
+                // the slide offset is owned by the transitions
+                let left = node.style.left;
+                node.apply(&card);
+                node.style.left = left;
+                if let Some(offset) = jitter_offset {
+                    node.style.margin.right = Val::Px(offset.0);
+                }
+            }
+            if let Ok(mut node) = self.nodes.get_mut(parts.header) {
+                node.apply(&header);
+            }
+            if let Ok(mut node) = self.nodes.get_mut(parts.dismiss_button) {
+                node.style.min_width = self.alert_nodes.header().style.height;
+            }
+            if let Ok(mut node) = self.nodes.get_mut(parts.body) {
+                node.apply(self.alert_nodes.body());
+                if is_group {
+                    node.style.flex_direction = FlexDirection::Column;
+                }
+            }
+
+            let rows = parts
+                .group_rows
+                .and_then(|rows| self.children.get(rows).ok())
+                .into_iter()
+                .flat_map(|rows| rows.iter().copied());
+            for text_node in std::iter::once(parts.text).chain(rows) {
+                if let Ok(mut text) = self.texts.get_mut(text_node) {
+                    text.linebreak_behavior = self.alert_nodes.linebreak;
+                    // rich text keeps the styles of its own sections
+                    if is_rich && text_node == parts.text {
+                        continue;
+                    }
+                    for section in &mut text.sections {
+                        section.style = self.alert_nodes.text().clone();
+                    }
+                }
+            }
+            let action_buttons = parts
+                .actions
+                .and_then(|footer| self.children.get(footer).ok())
+                .into_iter()
+                .flat_map(|buttons| buttons.iter().copied());
+            for button in action_buttons {
+                if let Ok(mut node) = self.nodes.get_mut(button) {
+                    node.apply(self.alert_nodes.action_button());
+                }
+                let labels = self.children.get(button).into_iter().flatten();
+                for label in labels {
+                    if let Ok(mut text) = self.texts.get_mut(*label) {
+                        for section in &mut text.sections {
+                            section.style = self.alert_nodes.action_text().clone();
+                        }
+                    }
+                }
+            }
+            if let Some(mut node) = parts
+                .actions
+                .and_then(|footer| self.nodes.get_mut(footer).ok())
+            {
+                node.apply(self.alert_nodes.actions());
+            }
+            if let Some(mut node) = parts
+                .progress_track
+                .and_then(|track| self.nodes.get_mut(track).ok())
+            {
+                node.apply(self.alert_nodes.progress_track());
+            }
+            if let Some(mut node) = parts
+                .progress_bar
+                .and_then(|bar| self.nodes.get_mut(bar).ok())
+            {
+                // the width follows the progress
+                let width = node.style.width;
+                node.apply(self.alert_nodes.progress_bar());
+                node.style.width = width;
+            }
+            if let Some(mut node) = parts.icon.and_then(|icon| self.nodes.get_mut(icon).ok()) {
+                *node.style = self.alert_nodes.icon().clone();
+            }
+            if let Some(mut text) = parts.title.and_then(|title| self.texts.get_mut(title).ok()) {
+                for section in &mut text.sections {
+                    section.style = self.alert_nodes.title().clone();
+                }
+            }
+            if let Some(mut text) = parts
+                .timestamp
+                .and_then(|timestamp| self.texts.get_mut(timestamp).ok())
+            {
+                for section in &mut text.sections {
+                    section.style = self.alert_nodes.timestamp().clone();
+                }
+            }
+            if let Some(mut text) = parts
+                .repeat_count
+                .and_then(|count| self.texts.get_mut(count).ok())
+            {
+                for section in &mut text.sections {
+                    section.style = self.alert_nodes.repeat_count_text().clone();
+                }
+            }
+            if let Some(mut text) = parts
+                .spinner
+                .and_then(|spinner| self.texts.get_mut(spinner).ok())
+            {
+                for section in &mut text.sections {
+                    section.style = self.alert_nodes.spinner().clone();
+                }
+            }
+        }
+    }
+}
+
+/// A command that reapplies the current `AlertElements<M>` to the root and every visible alert of
+/// this kind, whether or not `AutoRestyle` is enabled.
+///
+/// Use this after changes that `AlertElements` change detection cannot see. Like the automatic
+/// restyle, it leaves the slide offset of alerts that are mid-transition alone.
+pub struct RestyleAlerts<M>(PhantomData<M>);
+
+impl<M> RestyleAlerts<M> {
+    pub fn new() -> Self {
+        Self(PhantomData::<M>)
+    }
+}
+
+impl<M> Default for RestyleAlerts<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Command for RestyleAlerts<M>
+where
+    M: Component + TypePath,
+{
+    fn apply(self, world: &mut World) {
+        world.run_system_once(|mut restyler: AlertRestyler<M>| restyler.restyle());
+    }
+}
+
+/// The node components that `AlertElements` styles, used to restyle existing nodes in place.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub(crate) struct NodeStyle {
+    pub(crate) style: &'static mut Style,
+    pub(crate) background_color: &'static mut BackgroundColor,
+    pub(crate) border_color: &'static mut BorderColor,
+    pub(crate) z_index: &'static mut ZIndex,
+}
+
+impl NodeStyleItem<'_> {
+    pub(crate) fn apply(&mut self, node: &NodeBundle) {
+        *self.style = node.style.clone();
+        *self.background_color = node.background_color;
+        *self.border_color = node.border_color;
+        *self.z_index = node.z_index;
+    }
+}
+
+/// An optional emphasis for newly spawned alerts: the card starts at `scale` and settles back to
+/// its normal size while it slides in.
+///
+/// Only the `Transform` is scaled, so sibling layout is unaffected. Disabled by default.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertEmphasis<M: TypePath> {
+    pub(crate) scale: f32,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertEmphasis<M>
+where
+    M: TypePath,
+{
+    /// Builds a new `AlertEmphasis` that starts alerts at this scale, e.g. `1.06`.
+    pub fn new(scale: f32) -> Self {
+        Self {
+            scale,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Builds an `AlertEmphasis` that leaves the alert scale untouched.
+    pub fn disabled() -> Self {
+        Self::new(1.)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.scale != 1.
+    }
+}
+
+/// A screen corner to anchor an alerts root to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum AlertAnchor {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl AlertAnchor {
+    /// Moves a root `Style` laid out like `AlertElements::corner_popup` into this corner.
+    pub fn place(self, style: &mut Style) {
+        let (inset, span) = (Val::Px(24.), Val::Percent(70.));
+        match self {
+            AlertAnchor::TopLeft | AlertAnchor::BottomLeft => {
+                style.left = inset;
+                style.right = span;
+                style.align_items = AlignItems::FlexStart;
+            }
+            AlertAnchor::TopRight | AlertAnchor::BottomRight => {
+                style.left = span;
+                style.right = inset;
+                style.align_items = AlignItems::FlexEnd;
+            }
+        }
+        match self {
+            AlertAnchor::TopLeft | AlertAnchor::TopRight => {
+                style.top = inset;
+                style.bottom = Val::Auto;
+                style.justify_content = JustifyContent::FlexStart;
+            }
+            AlertAnchor::BottomLeft | AlertAnchor::BottomRight => {
+                style.top = Val::Auto;
+                style.bottom = inset;
+                style.justify_content = JustifyContent::FlexEnd;
+            }
+        }
+    }
+}
+
+/// Callbacks that decorate each alert of this kind as soon as its UI is built, for example to add a
+/// custom outline or glow.
+///
+/// Each decorator receives the alert entity's `EntityCommands`, the `Alert`, and its `AlertParts`.
+/// Decorators run in `spawn_alerts`, so whatever they add is present on the first visible frame.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_ui_mod_alerts::{AlertDecorators, AlertMarker};
+///
+/// #[derive(Component)]
+/// struct Glow;
+///
+/// let mut app = App::new();
+/// app.init_resource::<AlertDecorators<AlertMarker>>();
+/// app.world_mut()
+///     .resource_mut::<AlertDecorators<AlertMarker>>()
+///     .add(|alert, _, _| {
+///         alert.insert(Glow);
+///     });
+/// ```
+#[derive(Resource)]
+pub struct AlertDecorators<M> {
+    pub(crate) decorators: Vec<AlertDecorator>,
+    pub(crate) marker: PhantomData<M>,
+}
+
+pub(crate) type AlertDecorator =
+    Box<dyn Fn(&mut EntityCommands, &Alert, &AlertParts) + Send + Sync>;
+
+impl<M> AlertDecorators<M> {
+    pub fn add(
+        &mut self,
+        decorator: impl Fn(&mut EntityCommands, &Alert, &AlertParts) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.decorators.push(Box::new(decorator));
+        self
+    }
+}
+
+impl<M> Default for AlertDecorators<M> {
+    fn default() -> Self {
+        Self {
+            decorators: Vec::new(),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// Limits on how much of an alert's message is laid out as text.
+///
+/// Messages longer than `threshold` characters (a panic payload, a serialized state dump) are
+/// rendered as a preview of their first `preview_len` characters, so that spawning them doesn't
+/// stall a frame on glyph layout. The `Alert` component always keeps the full message, and a
+/// "Show more" button in the body expands the card to it. Lower the threshold to keep long
+/// messages from taking over the screen.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertTextLimits<M: TypePath> {
+    pub threshold: usize,
+    pub preview_len: usize,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertTextLimits<M>
+where
+    M: TypePath,
+{
+    pub fn new(threshold: usize, preview_len: usize) -> Self {
+        Self {
+            threshold,
+            preview_len,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    /// Whether `message` is rendered as a preview. Such alerts get a "Show more" button that
+    /// swaps in the full message.
+    pub fn is_truncated(&self, message: &str) -> bool {
+        message.chars().nth(self.threshold).is_some()
+    }
+
+    /// Returns the text that should be rendered for `message`.
+    pub fn display_text(&self, message: &str) -> String {
+        if !self.is_truncated(message) {
+            return message.to_string();
+        }
+        let mut preview = message.chars().take(self.preview_len).collect::<String>();
+        preview.push('…');
+        preview
+    }
+}
+
+impl<M> Default for AlertTextLimits<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self::new(2000, 280)
+    }
+}
+
+/// Whether changes to `AlertElements` are applied to the root and alerts that are already visible.
+///
+/// When enabled (the default), replacing or mutating the `AlertElements<M>` resource restyles the
+/// existing nodes in place, including alerts that are mid-transition. When disabled, only alerts
+/// spawned afterwards use the new elements.
+#[derive(Debug, Resource, Reflect)]
+pub struct AutoRestyle<M: TypePath> {
+    pub enabled: bool,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AutoRestyle<M>
+where
+    M: TypePath,
+{
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// How the pixel sizes in `AlertElements` respond to UI and window scaling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum AlertScaleMode {
+    /// Use the pixel values in `AlertElements` as they are.
+    #[default]
+    Physical,
+    /// Multiply pixel values by the `UiScale`.
+    ScaledWithUi,
+    /// Multiply pixel values by the `UiScale` and the primary window's scale factor.
+    Automatic,
+}
+
+/// Scales the pixel sizes and font sizes in `AlertElements<M>` according to `mode`.
+///
+/// Whenever the scale changes, the elements are rescaled, which restyles the visible alerts when
+/// `AutoRestyle` is enabled. Elements changed from outside are treated as unscaled, so prefer
+/// replacing `AlertElements` over mutating it in place while a scaling mode is active.
+///
+/// While the primary window is smaller than `small_window` allows, the elements switch to its
+/// layout and switch back once the window grows again. Set it to `None` to keep the regular layout
+/// at any size.
+#[derive(Debug, Resource)]
+pub struct AlertScaling<M> {
+    pub mode: AlertScaleMode,
+    pub small_window: Option<SmallWindowLayout>,
+    pub(crate) applied: f32,
+    pub(crate) applied_tick: Option<Tick>,
+    pub(crate) small_window_saved: Option<(AlertElements<M>, f32)>,
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertScaling<M> {
+    pub fn new(mode: AlertScaleMode) -> Self {
+        Self {
+            mode,
+            small_window: Some(SmallWindowLayout::default()),
+            applied: 1.,
+            applied_tick: None,
+            small_window_saved: None,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub fn with_small_window(mut self, small_window: Option<SmallWindowLayout>) -> Self {
+        self.small_window = small_window;
+        self
+    }
+
+    /// The factor currently applied to `AlertElements`.
+    pub fn factor(&self) -> f32 {
+        self.applied
+    }
+
+    /// Whether `AlertElements` currently use the `small_window` layout.
+    pub fn is_small_window(&self) -> bool {
+        self.small_window_saved.is_some()
+    }
+}
+
+/// The layout alerts fall back to in windows too small for the regular corner stack.
+///
+/// The root is replaced by `root`, a full-width banner along the top of the window by default,
+/// cards span the whole root in a single column, and headers and their buttons grow to
+/// `touch_target` pixels so they stay easy to hit.
+#[derive(Clone, Debug)]
+pub struct SmallWindowLayout {
+    /// Windows narrower than this many logical pixels use this layout.
+    pub min_width: f32,
+    /// Windows shorter than this many logical pixels use this layout.
+    pub min_height: f32,
+    /// The root node style used in place of `AlertElements::root`.
+    pub root: Style,
+    /// The height of headers and the minimum width of their buttons, before scaling.
+    pub touch_target: f32,
+}
+
+impl Default for SmallWindowLayout {
+    fn default() -> Self {
+        Self {
+            min_width: 300.,
+            min_height: 200.,
+            root: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.),
+                right: Val::Px(0.),
+                top: Val::Px(0.),
+                width: Val::Percent(100.),
+                max_height: Val::Percent(60.),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Stretch,
+                row_gap: Val::Px(2.),
+                ..Default::default()
+            },
+            touch_target: 44.,
+        }
+    }
+}
+
+impl<M> Default for AlertScaling<M> {
+    fn default() -> Self {
+        Self::new(AlertScaleMode::Physical)
+    }
+}
+
+/// The colors of the card and header of alerts of one `AlertLevel`. See
+/// `AlertElements::level_colors`.
+#[derive(Clone, Copy, Debug)]
+pub struct AlertLevelColors {
+    pub background: Color,
+    pub border: Color,
+    pub header: Color,
+}
+
+impl AlertLevelColors {
+    pub(crate) fn apply(&self, card: &mut NodeBundle, header: &mut NodeBundle) {
+        card.background_color = self.background.into();
+        card.border_color = self.border.into();
+        header.background_color = self.header.into();
+    }
+}
+
+/// A type collecting the UI styles and presentational logic of each possible alert UI element.
+///
+/// Override this resource to restyle the alert UI elements.
+#[derive(Debug, Resource)]
+pub struct AlertElements<M = AlertMarker> {
+    /// The UI root node specification. Use this to frame the layer.
+    ///
+    /// The default view is an inner crop of the window space.
+    /// The default ZIndex is 1000 to overlay all other content.
+    pub root: NodeBundle,
+    /// The alert node specification. This is the "card" for the alert.
+    pub alert: NodeBundle,
+    /// The header node specification for the alert, which also renders the dismiss button.
+    pub header: NodeBundle,
+    /// The body node specification for the alert, which has the text as child.
+    pub body: NodeBundle,
+    /// The style spec for the body text of the alert.
+    pub text: TextStyle,
+    /// Where the body text may break into lines to fit the card. Newlines in the message always
+    /// start a new line.
+    pub linebreak: BreakLineOn,
+    /// The style spec for the title of alerts that have one, shown in the header.
+    pub title: TextStyle,
+    /// The style spec for the header text of alerts shown with `AlertTimestamps`.
+    pub timestamp: TextStyle,
+    /// The style spec for the spinner in the header of an `UnresolvedAlert`.
+    pub spinner: TextStyle,
+    /// The header badge showing the `AlertRepeatCount` of repeated alerts.
+    pub repeat_count: NodeBundle,
+    /// The style spec for the count inside the repeat badge.
+    pub repeat_count_text: TextStyle,
+    /// The style of the image node of alerts with an `AlertIcon`, placed before the body text.
+    pub icon: Style,
+    /// The alert node specification used for the pinned `CriticalAlert` slot.
+    pub critical: NodeBundle,
+    /// The node specification for the header button shown on alerts with `AlertDetails`.
+    pub details_button: NodeBundle,
+    /// The node specification for the body button that expands a truncated message. See
+    /// `AlertTextLimits`.
+    pub show_more_button: NodeBundle,
+    /// The node specification for the header button that copies the alert message.
+    #[cfg(feature = "clipboard")]
+    pub copy_button: NodeBundle,
+    /// The badge node shown in place of the stack when it is collapsed by `AutoCollapse`.
+    pub badge: NodeBundle,
+    /// The style spec for the unread count inside the collapse badge.
+    pub badge_text: TextStyle,
+    /// The node of the dismiss hint shown in place of the dismiss button in gamepad mode. Only its
+    /// style and border are used. See `AlertInputMode`.
+    pub gamepad_dismiss: NodeBundle,
+    /// The style spec for the label of the gamepad dismiss hint.
+    pub gamepad_dismiss_text: TextStyle,
+    /// The label of the gamepad dismiss hint, naming the button bound in `AlertInputMode`.
+    pub gamepad_dismiss_label: String,
+    /// The node of the `AlertStatus` line, shown below the stack.
+    pub status: NodeBundle,
+    /// The style spec for the text of the `AlertStatus` line.
+    pub status_text: TextStyle,
+    /// The footer row below the body of alerts with `AlertActions`.
+    pub actions: NodeBundle,
+    /// The node specification for each button in the `actions` footer.
+    pub action_button: NodeBundle,
+    /// The style spec for the labels of the action buttons.
+    pub action_text: TextStyle,
+    /// The slim track below the body of alerts with `AlertProgress`.
+    pub progress_track: NodeBundle,
+    /// The filled part of the progress track. Its width is set from the `AlertProgress`.
+    pub progress_bar: NodeBundle,
+    /// The node of the `AlertTooltip` showing the full message of a hovered, truncated alert. It is
+    /// positioned below the card, and always lets the pointer through.
+    pub tooltip: NodeBundle,
+    /// The style spec for the text of the `AlertTooltip`.
+    pub tooltip_text: TextStyle,
+    /// The node of the "+N more" line shown at the bottom of a root while alerts wait for a slot.
+    pub overflow: NodeBundle,
+    /// The style spec for the text of the "+N more" line.
+    pub overflow_text: TextStyle,
+    /// An optional node placed at the top of each card of an `AlertBatch` after the first, to join
+    /// it visually to the card before it.
+    pub batch_connector: Option<NodeBundle>,
+    /// The colors of cards of each `AlertLevel`. Levels without an entry use the `alert` and
+    /// `critical` nodes as they are.
+    pub level_colors: HashMap<AlertLevel, AlertLevelColors>,
+    /// A marker for supporting multiple alert styles.
+    pub marker: PhantomData<M>,
+}
+
+impl<M> Clone for AlertElements<M> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            alert: self.alert.clone(),
+            header: self.header.clone(),
+            body: self.body.clone(),
+            text: self.text.clone(),
+            linebreak: self.linebreak,
+            title: self.title.clone(),
+            timestamp: self.timestamp.clone(),
+            spinner: self.spinner.clone(),
+            repeat_count: self.repeat_count.clone(),
+            repeat_count_text: self.repeat_count_text.clone(),
+            icon: self.icon.clone(),
+            critical: self.critical.clone(),
+            details_button: self.details_button.clone(),
+            show_more_button: self.show_more_button.clone(),
+            #[cfg(feature = "clipboard")]
+            copy_button: self.copy_button.clone(),
+            badge: self.badge.clone(),
+            badge_text: self.badge_text.clone(),
+            gamepad_dismiss: self.gamepad_dismiss.clone(),
+            gamepad_dismiss_text: self.gamepad_dismiss_text.clone(),
+            gamepad_dismiss_label: self.gamepad_dismiss_label.clone(),
+            status: self.status.clone(),
+            status_text: self.status_text.clone(),
+            actions: self.actions.clone(),
+            action_button: self.action_button.clone(),
+            action_text: self.action_text.clone(),
+            progress_track: self.progress_track.clone(),
+            progress_bar: self.progress_bar.clone(),
+            tooltip: self.tooltip.clone(),
+            tooltip_text: self.tooltip_text.clone(),
+            overflow: self.overflow.clone(),
+            overflow_text: self.overflow_text.clone(),
+            batch_connector: self.batch_connector.clone(),
+            level_colors: self.level_colors.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl AlertElements<AlertMarker> {
+    pub fn new() -> Self {
+        Self::corner_popup(AlertDefaults::ALERT_HEIGHT)
+    }
+}
+
+impl<M> AlertElements<M> {
+    pub fn new_custom() -> Self {
+        Self::corner_popup(AlertDefaults::ALERT_HEIGHT)
+    }
+
+    /// Builds an AlertElements that styles the alerts like a typical corner "toast" pop-up.
+    pub fn corner_popup(alert_height: f32) -> Self {
+        AlertElements {
+            root: NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(70.),
+                    right: Val::Px(24.),
+                    bottom: Val::Px(24.),
+                    max_height: Val::Percent(60.),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::FlexEnd,
+                    align_items: AlignItems::FlexEnd,
+                    row_gap: Val::Px(8.),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.).into(),
+                z_index: ZIndex::Local(AlertDefaults::Z_INDEX),
+                ..Default::default()
+            },
+            alert: NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexStart,
+                    justify_content: JustifyContent::FlexStart,
+                    width: Val::Percent(80.),
+                    min_height: Val::Px(alert_height),
+                    border: UiRect::all(Val::Px(2.)),
+                    ..Default::default()
+                },
+                background_color: Color::Srgba(bevy::color::palettes::css::ALICE_BLUE).into(),
+                border_color: Color::Srgba(bevy::color::palettes::css::DARK_GRAY).into(),
+                ..Default::default()
+            },
+            critical: NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexStart,
+                    justify_content: JustifyContent::FlexStart,
+                    width: Val::Percent(80.),
+                    min_height: Val::Px(alert_height),
+                    border: UiRect::all(Val::Px(4.)),
+                    ..Default::default()
+                },
+                background_color: Color::Srgba(bevy::color::palettes::css::MISTY_ROSE).into(),
+                border_color: Color::Srgba(bevy::color::palettes::css::CRIMSON).into(),
+                ..Default::default()
+            },
+            header: NodeBundle {
+                style: Style {
+                    justify_content: JustifyContent::FlexEnd,
+                    width: Val::Percent(100.),
+                    height: Val::Px(AlertDefaults::HEADER_HEIGHT),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0.8, 0.8, 0.8).into(),
+                ..Default::default()
+            },
+            body: NodeBundle {
+                style: Style {
+                    flex_grow: 1.,
+                    padding: UiRect::all(Val::Px(4.)),
+                    width: Val::Percent(100.),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: TextStyle {
+                font_size: 24.,
+                color: Color::BLACK,
+                ..Default::default()
+            },
+            linebreak: BreakLineOn::WordBoundary,
+            title: TextStyle {
+                font_size: 14.,
+                color: Color::BLACK,
+                ..Default::default()
+            },
+            timestamp: TextStyle {
+                font_size: 12.,
+                color: Color::srgba(0., 0., 0., 0.7),
+                ..Default::default()
+            },
+            spinner: TextStyle {
+                font_size: 16.,
+                color: Color::BLACK,
+                ..Default::default()
+            },
+            repeat_count: NodeBundle {
+                style: Style {
+                    align_self: AlignSelf::Center,
+                    flex_shrink: 0.,
+                    padding: UiRect::horizontal(Val::Px(4.)),
+                    margin: UiRect::horizontal(Val::Px(2.)),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.6).into(),
+                ..Default::default()
+            },
+            repeat_count_text: TextStyle {
+                font_size: 12.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            icon: Style {
+                width: Val::Px(24.),
+                height: Val::Px(24.),
+                flex_shrink: 0.,
+                margin: UiRect::right(Val::Px(4.)),
+                ..Default::default()
+            },
+            details_button: NodeBundle {
+                style: Style {
+                    height: Val::Percent(100.),
+                    padding: UiRect::horizontal(Val::Px(4.)),
+                    margin: UiRect::right(Val::Px(2.)),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: AlertDefaults::BUTTON_COLOR.into(),
+                ..Default::default()
+            },
+            show_more_button: NodeBundle {
+                style: Style {
+                    align_self: AlignSelf::FlexEnd,
+                    flex_shrink: 0.,
+                    padding: UiRect::axes(Val::Px(4.), Val::Px(2.)),
+                    margin: UiRect::left(Val::Px(4.)),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: AlertDefaults::BUTTON_COLOR.into(),
+                ..Default::default()
+            },
+            #[cfg(feature = "clipboard")]
+            copy_button: NodeBundle {
+                style: Style {
+                    height: Val::Percent(100.),
+                    padding: UiRect::horizontal(Val::Px(4.)),
+                    margin: UiRect::right(Val::Px(2.)),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: AlertDefaults::BUTTON_COLOR.into(),
+                ..Default::default()
+            },
+            badge: NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(24.),
+                    bottom: Val::Px(24.),
+                    width: Val::Px(40.),
+                    height: Val::Px(40.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.)),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0.8, 0.8, 0.8).into(),
+                border_color: Color::Srgba(bevy::color::palettes::css::DARK_GRAY).into(),
+                z_index: ZIndex::Local(AlertDefaults::Z_INDEX),
+                ..Default::default()
+            },
+            badge_text: TextStyle {
+                font_size: 20.,
+                color: Color::BLACK,
+                ..Default::default()
+            },
+            gamepad_dismiss: NodeBundle {
+                style: Style {
+                    height: Val::Percent(100.),
+                    padding: UiRect::horizontal(Val::Px(6.)),
+                    align_self: AlignSelf::FlexEnd,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            gamepad_dismiss_text: TextStyle {
+                font_size: 14.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            gamepad_dismiss_label: "[B] Dismiss".to_string(),
+            status: NodeBundle {
+                style: Style {
+                    width: Val::Percent(80.),
+                    padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.6).into(),
+                ..Default::default()
+            },
+            status_text: TextStyle {
+                font_size: 16.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            actions: NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    justify_content: JustifyContent::FlexEnd,
+                    column_gap: Val::Px(4.),
+                    padding: UiRect::all(Val::Px(4.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            action_button: NodeBundle {
+                style: Style {
+                    padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                },
+                background_color: AlertDefaults::BUTTON_COLOR.into(),
+                ..Default::default()
+            },
+            action_text: TextStyle {
+                font_size: 16.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            progress_track: NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Px(4.),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.15).into(),
+                ..Default::default()
+            },
+            progress_bar: NodeBundle {
+                style: Style {
+                    height: Val::Percent(100.),
+                    ..Default::default()
+                },
+                background_color: Color::Srgba(bevy::color::palettes::css::STEEL_BLUE).into(),
+                ..Default::default()
+            },
+            tooltip: NodeBundle {
+                style: Style {
+                    max_width: Val::Percent(80.),
+                    padding: UiRect::all(Val::Px(6.)),
+                    border: UiRect::all(Val::Px(1.)),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.1, 0.95).into(),
+                border_color: Color::Srgba(bevy::color::palettes::css::DARK_GRAY).into(),
+                ..Default::default()
+            },
+            tooltip_text: TextStyle {
+                font_size: 16.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            overflow: NodeBundle {
+                style: Style {
+                    align_self: AlignSelf::FlexEnd,
+                    padding: UiRect::axes(Val::Px(8.), Val::Px(2.)),
+                    ..Default::default()
+                },
+                background_color: Color::srgba(0., 0., 0., 0.4).into(),
+                ..Default::default()
+            },
+            overflow_text: TextStyle {
+                font_size: 14.,
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            level_colors: HashMap::from_iter([
+                (
+                    AlertLevel::Warning,
+                    AlertLevelColors {
+                        background: Color::Srgba(bevy::color::palettes::css::LIGHT_YELLOW),
+                        border: Color::Srgba(bevy::color::palettes::css::GOLDENROD),
+                        header: Color::srgba(0.9, 0.65, 0., 0.8),
+                    },
+                ),
+                (
+                    AlertLevel::Error,
+                    AlertLevelColors {
+                        background: Color::Srgba(bevy::color::palettes::css::MISTY_ROSE),
+                        border: Color::Srgba(bevy::color::palettes::css::FIREBRICK),
+                        header: Color::srgba(0.8, 0.1, 0.1, 0.8),
+                    },
+                ),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    /// Sizes each card to fit its content, clamped between `min_width` and `max_width` pixels.
+    ///
+    /// Cards stay flush with the root's anchor edge, the header shrinks with the card instead of
+    /// forcing it to full width, and text wraps once a card reaches `max_width`.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_ui_mod_alerts::AlertElements;
+    ///
+    /// let mut app = App::new();
+    /// app.insert_resource(AlertElements::new().with_fit_content(120., 360.));
+    /// ```
+    pub fn with_fit_content(mut self, min_width: f32, max_width: f32) -> Self {
+        self.root.style.align_items = AlignItems::FlexEnd;
+        for card in [&mut self.alert, &mut self.critical] {
+            card.style.width = Val::Auto;
+            card.style.min_width = Val::Px(min_width);
+            card.style.max_width = Val::Px(max_width);
+        }
+        for node in [&mut self.header, &mut self.body] {
+            node.style.width = Val::Auto;
+            node.style.align_self = AlignSelf::Stretch;
+        }
+        self
+    }
+
+    /// Multiplies every pixel size and font size by `factor`.
+    pub fn scale(&mut self, factor: f32) {
+        fn scale_val(val: &mut Val, factor: f32) {
+            if let Val::Px(px) = val {
+                *px *= factor;
+            }
+        }
+        fn scale_rect(rect: &mut UiRect, factor: f32) {
+            for val in [
+                &mut rect.left,
+                &mut rect.right,
+                &mut rect.top,
+                &mut rect.bottom,
+            ] {
+                scale_val(val, factor);
+            }
+        }
+
+        for node in [
+            &mut self.root,
+            &mut self.alert,
+            &mut self.header,
+            &mut self.body,
+            &mut self.critical,
+            &mut self.details_button,
+            &mut self.show_more_button,
+            #[cfg(feature = "clipboard")]
+            &mut self.copy_button,
+            &mut self.badge,
+            &mut self.repeat_count,
+            &mut self.actions,
+            &mut self.action_button,
+            &mut self.progress_track,
+        ]
+        .into_iter()
+        .chain(self.batch_connector.as_mut())
+        .map(|node| &mut node.style)
+        .chain([&mut self.icon])
+        {
+            for val in [
+                &mut style.left,
+                &mut style.right,
+                &mut style.top,
+                &mut style.bottom,
+                &mut style.width,
+                &mut style.height,
+                &mut style.min_width,
+                &mut style.min_height,
+                &mut style.max_width,
+                &mut style.max_height,
+                &mut style.flex_basis,
+                &mut style.row_gap,
+                &mut style.column_gap,
+            ] {
+                scale_val(val, factor);
+            }
+            for rect in [&mut style.margin, &mut style.padding, &mut style.border] {
+                scale_rect(rect, factor);
+            }
+        }
+        for text in [
+            &mut self.text,
+            &mut self.title,
+            &mut self.timestamp,
+            &mut self.spinner,
+            &mut self.action_text,
+            &mut self.repeat_count_text,
+            &mut self.badge_text,
+        ] {
+            text.font_size *= factor;
+        }
+    }
+
+    pub(crate) fn apply_small_window(&mut self, layout: &SmallWindowLayout, factor: f32) {
+        let touch_target = Val::Px(layout.touch_target * factor);
+        self.root.style = layout.root.clone();
+        for card in [&mut self.alert, &mut self.critical] {
+            card.style.width = Val::Percent(100.);
+        }
+        self.header.style.height = touch_target;
+        for button in [
+            &mut self.details_button,
+            #[cfg(feature = "clipboard")]
+            &mut self.copy_button,
+        ] {
+            button.style.min_width = touch_target;
+        }
+    }
+
+    pub fn root(&self) -> &NodeBundle {
+        &self.root
+    }
+
+    pub fn alert(&self) -> &NodeBundle {
+        &self.alert
+    }
+
+    pub fn header(&self) -> &NodeBundle {
+        &self.header
+    }
+
+    pub fn body(&self) -> &NodeBundle {
+        &self.body
+    }
+
+    pub fn text(&self) -> &TextStyle {
+        &self.text
+    }
+
+    pub fn title(&self) -> &TextStyle {
+        &self.title
+    }
+
+    pub fn timestamp(&self) -> &TextStyle {
+        &self.timestamp
+    }
+
+    pub fn spinner(&self) -> &TextStyle {
+        &self.spinner
+    }
+
+    pub fn repeat_count(&self) -> &NodeBundle {
+        &self.repeat_count
+    }
+
+    pub fn repeat_count_text(&self) -> &TextStyle {
+        &self.repeat_count_text
+    }
+
+    pub fn icon(&self) -> &Style {
+        &self.icon
+    }
+
+    pub fn critical(&self) -> &NodeBundle {
+        &self.critical
+    }
+
+    pub fn details_button(&self) -> &NodeBundle {
+        &self.details_button
+    }
+
+    pub fn show_more_button(&self) -> &NodeBundle {
+        &self.show_more_button
+    }
+
+    #[cfg(feature = "clipboard")]
+    pub fn copy_button(&self) -> &NodeBundle {
+        &self.copy_button
+    }
+
+    pub fn badge(&self) -> &NodeBundle {
+        &self.badge
+    }
+
+    pub fn badge_text(&self) -> &TextStyle {
+        &self.badge_text
+    }
+
+    pub fn gamepad_dismiss(&self) -> &NodeBundle {
+        &self.gamepad_dismiss
+    }
+
+    pub fn gamepad_dismiss_text(&self) -> &TextStyle {
+        &self.gamepad_dismiss_text
+    }
+
+    pub fn status(&self) -> &NodeBundle {
+        &self.status
+    }
+
+    pub fn status_text(&self) -> &TextStyle {
+        &self.status_text
+    }
+
+    pub fn actions(&self) -> &NodeBundle {
+        &self.actions
+    }
+
+    pub fn action_button(&self) -> &NodeBundle {
+        &self.action_button
+    }
+
+    pub fn action_text(&self) -> &TextStyle {
+        &self.action_text
+    }
+
+    pub fn progress_track(&self) -> &NodeBundle {
+        &self.progress_track
+    }
+
+    pub fn progress_bar(&self) -> &NodeBundle {
+        &self.progress_bar
+    }
+
+    pub fn tooltip(&self) -> &NodeBundle {
+        &self.tooltip
+    }
+
+    pub fn tooltip_text(&self) -> &TextStyle {
+        &self.tooltip_text
+    }
+
+    pub fn overflow(&self) -> &NodeBundle {
+        &self.overflow
+    }
+
+    pub fn overflow_text(&self) -> &TextStyle {
+        &self.overflow_text
+    }
+
+    pub fn level_colors(&self, level: AlertLevel) -> Option<&AlertLevelColors> {
+        self.level_colors.get(&level)
+    }
+
+    /// The card and header nodes of an alert of this level, with its `level_colors` applied.
+    pub fn leveled(&self, level: AlertLevel, critical: bool) -> (NodeBundle, NodeBundle) {
+        let mut card = if critical {
+            self.critical.clone()
+        } else {
+            self.alert.clone()
+        };
+        let mut header = self.header.clone();
+        if let Some(colors) = self.level_colors(level) {
+            colors.apply(&mut card, &mut header);
+        }
+        (card, header)
+    }
+}
+
+impl<M> Default for AlertElements<M> {
+    fn default() -> Self {
+        Self {
+            root: Default::default(),
+            alert: Default::default(),
+            header: Default::default(),
+            body: Default::default(),
+            text: Default::default(),
+            linebreak: Default::default(),
+            title: Default::default(),
+            timestamp: Default::default(),
+            spinner: Default::default(),
+            repeat_count: Default::default(),
+            repeat_count_text: Default::default(),
+            icon: Default::default(),
+            critical: Default::default(),
+            details_button: Default::default(),
+            show_more_button: Default::default(),
+            #[cfg(feature = "clipboard")]
+            copy_button: Default::default(),
+            badge: Default::default(),
+            badge_text: Default::default(),
+            gamepad_dismiss: Default::default(),
+            gamepad_dismiss_text: Default::default(),
+            gamepad_dismiss_label: Default::default(),
+            status: Default::default(),
+            status_text: Default::default(),
+            actions: Default::default(),
+            action_button: Default::default(),
+            action_text: Default::default(),
+            progress_track: Default::default(),
+            progress_bar: Default::default(),
+            tooltip: Default::default(),
+            tooltip_text: Default::default(),
+            overflow: Default::default(),
+            overflow_text: Default::default(),
+            batch_connector: Default::default(),
+            level_colors: Default::default(),
+            marker: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_restyle_on_elements_change() {
+        let mut app = manual_app();
+        let alerts = [
+            app.world_mut()
+                .spawn((Alert::bundle("First"), AlertMarker))
+                .id(),
+            app.world_mut()
+                .spawn((Alert::bundle("Second"), AlertMarker))
+                .id(),
+        ];
+        app.update();
+
+        let mut elements = AlertElements::<AlertMarker>::new();
+        elements.alert.background_color = Color::BLACK.into();
+        elements.header.background_color = Color::WHITE.into();
+        elements.text.font_size = 12.;
+        app.insert_resource(elements);
+        app.update();
+
+        for alert in alerts {
+            let parts = *app.world().get::<AlertParts>(alert).unwrap();
+            assert_eq!(
+                app.world().get::<BackgroundColor>(alert).unwrap().0,
+                Color::BLACK
+            );
+            assert_eq!(
+                app.world()
+                    .get::<BackgroundColor>(parts.header())
+                    .unwrap()
+                    .0,
+                Color::WHITE
+            );
+            assert_eq!(
+                app.world().get::<Text>(parts.text()).unwrap().sections[0]
+                    .style
+                    .font_size,
+                12.
+            );
+        }
+        // the in-flight slide was not reset by the restyle
+        assert!(matches!(
+            app.world().get::<AlertTransition>(alerts[0]),
+            Some(AlertTransition::FadeIn)
+        ));
+        assert!(matches!(
+            app.world().get::<Style>(alerts[0]).unwrap().left,
+            Val::Percent(_)
+        ));
+    }
+
+    #[test]
+    fn test_fit_content_elements() {
+        let elements = AlertElements::new().with_fit_content(120., 360.);
+        assert_eq!(elements.root.style.align_items, AlignItems::FlexEnd);
+        for card in [&elements.alert, &elements.critical] {
+            assert_eq!(card.style.width, Val::Auto);
+            assert_eq!(card.style.min_width, Val::Px(120.));
+            assert_eq!(card.style.max_width, Val::Px(360.));
+        }
+        assert_eq!(elements.header.style.width, Val::Auto);
+        assert_eq!(elements.header.style.align_self, AlignSelf::Stretch);
+    }
+
+    #[test]
+    fn test_alert_decorators() {
+        #[derive(Component)]
+        struct Glow(Entity);
+
+        let mut app = manual_app();
+        app.world_mut()
+            .resource_mut::<AlertDecorators<AlertMarker>>()
+            .add(|alert, _, parts| {
+                alert.insert(Glow(parts.text()));
+            });
+        let alerts = ["first", "second"].map(|message| {
+            app.world_mut()
+                .spawn((Alert::bundle(message), AlertMarker))
+                .id()
+        });
+        app.update();
+
+        for alert in alerts {
+            assert!(app.world().get::<AlertUi>(alert).is_some());
+            let glow = app.world().get::<Glow>(alert).unwrap();
+            assert_eq!(glow.0, app.world().get::<AlertParts>(alert).unwrap().text());
+        }
+    }
+
+    #[test]
+    fn test_restyle_command() {
+        let mut app = manual_app();
+        app.insert_resource(AutoRestyle::<AlertMarker>::new(false));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("restyled"), AlertMarker))
+            .id();
+        app.update();
+        app.update();
+        let left = app.world().get::<Style>(alert).unwrap().left;
+        assert!(app.world().get::<AlertTransition>(alert).is_some());
+
+        app.world_mut()
+            .resource_mut::<AlertElements<AlertMarker>>()
+            .text
+            .font_size = 30.;
+        RestyleAlerts::<AlertMarker>::new().apply(app.world_mut());
+
+        let parts = *app.world().get::<AlertParts>(alert).unwrap();
+        let text = app.world().get::<Text>(parts.text()).unwrap();
+        assert_eq!(text.sections[0].style.font_size, 30.);
+        assert_eq!(app.world().get::<Style>(alert).unwrap().left, left);
+    }
+
+    #[test]
+    fn test_scaling_modes() {
+        use bevy::window::WindowResolution;
+
+        let mut app = manual_app();
+        app.insert_resource(UiScale(1.));
+        app.insert_resource(AlertScaling::<AlertMarker>::new(
+            AlertScaleMode::ScaledWithUi,
+        ));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("scaled"), AlertMarker))
+            .id();
+        app.update();
+
+        let elements = app.world().resource::<AlertElements<AlertMarker>>();
+        assert_eq!(elements.text().font_size, 24.);
+        assert_eq!(elements.root().style.right, Val::Px(24.));
+
+        app.insert_resource(UiScale(2.));
+        app.update();
+        let elements = app.world().resource::<AlertElements<AlertMarker>>();
+        assert_eq!(elements.text().font_size, 48.);
+        assert_eq!(elements.root().style.right, Val::Px(48.));
+        assert_eq!(elements.alert().style.min_height, Val::Px(160.));
+        assert_eq!(elements.root().style.left, Val::Percent(70.));
+        let text = app.world().get::<AlertParts>(alert).unwrap().text();
+        let text = app.world().get::<Text>(text).unwrap();
+        assert_eq!(text.sections[0].style.font_size, 48.);
+
+        // automatic scaling also follows the window's scale factor
+        let mut resolution = WindowResolution::default();
+        resolution.set_scale_factor_override(Some(1.5));
+        app.world_mut().spawn((
+            Window {
+                resolution,
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ));
+        app.world_mut()
+            .resource_mut::<AlertScaling<AlertMarker>>()
+            .mode = AlertScaleMode::Automatic;
+        app.update();
+        let elements = app.world().resource::<AlertElements<AlertMarker>>();
+        assert_eq!(elements.text().font_size, 72.);
+        assert_eq!(
+            app.world().resource::<AlertScaling<AlertMarker>>().factor(),
+            3.
+        );
+    }
+
+    #[test]
+    fn test_missing_font_fallback() {
+        let mut app = manual_app();
+        app.add_plugins(AssetPlugin::default());
+        app.init_asset::<Font>();
+        let missing = Handle::<Font>::weak_from_u128(0x5eed);
+        app.world_mut()
+            .resource_mut::<AlertElements<AlertMarker>>()
+            .text
+            .font = missing.clone();
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("unreadable"), AlertMarker))
+            .id();
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert!(app
+            .world()
+            .resource::<AlertFontFallback<AlertMarker>>()
+            .is_active());
+        let text = app.world().get::<AlertParts>(alert).unwrap().text();
+        let text = app.world().get::<Text>(text).unwrap();
+        assert_eq!(text.sections[0].style.font, Handle::default());
+
+        let mut alerts = app.world_mut().query::<&Alert>();
+        let warnings = alerts
+            .iter(app.world())
+            .filter(|alert| alert.message().contains("unavailable"))
+            .count();
+        assert_eq!(warnings, 1);
+    }
+}
@@ -0,0 +1,940 @@
+use super::*;
+
+impl<M> AlertsPlugin<M>
+where
+    M: Component + TypePath,
+{
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn limit_fade_outs(
+        mut commands: Commands,
+        alerts: Query<
+            (Entity, Option<&AlertTransition>, Has<AlertFadeQueued>),
+            (With<AlertManaged<M>>, With<AlertUi>),
+        >,
+        mut limit: ResMut<MaxConcurrentTransitions<M>>,
+    ) {
+        let limit = &mut *limit;
+        let Some(max) = limit.max else {
+            // lifting the limit releases everything still waiting
+            for entity in limit.queue.drain(..) {
+                commands
+                    .entity(entity)
+                    .remove::<(AlertFadeQueued, TransitionTimer)>()
+                    .insert(AlertTransition::FadeOut);
+            }
+            limit.active.clear();
+            return;
+        };
+        limit.active.retain(|entity| {
+            alerts
+                .get(*entity)
+                .is_ok_and(|(_, transition, _)| transition.is_some_and(AlertTransition::is_leaving))
+        });
+        limit.queue.retain(|entity| alerts.contains(*entity));
+
+        for (entity, transition, queued) in &alerts {
+            if !transition.is_some_and(AlertTransition::is_leaving)
+                || limit.active.contains(&entity)
+            {
+                continue;
+            }
+            // queued alerts may be asked to fade out again, but keep their place in line, and
+            // slide out once released
+            commands.entity(entity).remove::<AlertTransition>();
+            if !queued {
+                commands.entity(entity).insert(AlertFadeQueued);
+                limit.queue.push_back(entity);
+            }
+        }
+
+        while limit.active.len() < max {
+            let Some(entity) = limit.queue.pop_front() else {
+                break;
+            };
+            commands
+                .entity(entity)
+                .remove::<(AlertFadeQueued, TransitionTimer)>()
+                .insert(AlertTransition::FadeOut);
+            limit.active.insert(entity);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn tick_transitions(
+        mut commands: Commands,
+        mut alert_nodes: Query<
+            (
+                Entity,
+                &mut Style,
+                &mut Transform,
+                &AlertTransition,
+                Option<&mut TransitionTimer>,
+                Option<&AlertTimeScaleOverride>,
+            ),
+            (With<AlertManaged<M>>, With<AlertUi>),
+        >,
+        children: Query<&Children>,
+        mut backgrounds: Query<&mut BackgroundColor>,
+        mut borders: Query<&mut BorderColor>,
+        mut texts: Query<&mut Text>,
+        mut images: Query<&mut UiImage>,
+        emphasis: Res<AlertEmphasis<M>>,
+        reduced_motion: Res<ReducedMotion<M>>,
+        time_scale: Res<AlertTimeScale<M>>,
+        time: Res<Time>,
+    ) {
+        #[cfg(feature = "trace")]
+        let _span = info_span!("tick_transitions", marker = M::short_type_path()).entered();
+        for (entity, mut style, mut transform, transition, timer, scale_override) in
+            &mut alert_nodes
+        {
+            let closing = matches!(transition, AlertTransition::FadeOutToward(_));
+            let mut delta = time_scale.scale_delta(time.delta(), scale_override);
+            if closing && reduced_motion.enabled {
+                // a quick fade in place stands in for the motion
+                delta *= 2;
+            }
+            let (previous, time) = if reduced_motion.enabled && !closing {
+                // alerts appear and leave in place
+                (0., 1.)
+            } else if let Some(mut timer) = timer {
+                let previous = timer.completion();
+                timer.tick(delta);
+                (previous, timer.completion())
+            } else {
+                let mut timer = TransitionTimer::default();
+                timer.tick(delta);
+                let time = timer.completion();
+                commands.entity(entity).insert(timer);
+                (0., time)
+            };
+
+            style.left = Val::Percent(transition.offset(time) * 100.);
+
+            if let AlertTransition::FadeOutToward(anchor) = transition {
+                let progress = easing::sine_in_out(time);
+                if !reduced_motion.enabled {
+                    style.left = Val::Px(anchor.x * progress);
+                    style.top = Val::Px(anchor.y * progress);
+                    let scale = 1. - (1. - AlertDefaults::DISMISS_SCALE) * progress;
+                    transform.scale = Vec2::splat(scale).extend(1.);
+                }
+                // scale whatever alpha each node had last frame, so styled alphas fade evenly
+                let fade = (1. - progress) / (1. - easing::sine_in_out(previous));
+                for node in std::iter::once(entity).chain(children.iter_descendants(entity)) {
+                    if let Ok(mut background) = backgrounds.get_mut(node) {
+                        let alpha = background.0.alpha() * fade;
+                        background.0.set_alpha(alpha);
+                    }
+                    if let Ok(mut border) = borders.get_mut(node) {
+                        let alpha = border.0.alpha() * fade;
+                        border.0.set_alpha(alpha);
+                    }
+                    if let Ok(mut text) = texts.get_mut(node) {
+                        for section in &mut text.sections {
+                            let alpha = section.style.color.alpha() * fade;
+                            section.style.color.set_alpha(alpha);
+                        }
+                    }
+                    if let Ok(mut image) = images.get_mut(node) {
+                        let alpha = image.color.alpha() * fade;
+                        image.color.set_alpha(alpha);
+                    }
+                }
+            }
+
+            if matches!(transition, AlertTransition::FadeIn) && emphasis.is_enabled() {
+                // settle from the emphasized scale back to 1 over the same timer as the slide
+                let settle = 1. - easing::sine_in_out(time);
+                let scale = 1. + (emphasis.scale - 1.) * settle;
+                transform.scale = Vec2::splat(scale).extend(1.);
+            }
+
+            if time >= 1. {
+                match transition {
+                    AlertTransition::FadeIn => {
+                        commands
+                            .entity(entity)
+                            .remove::<(AlertTransition, TransitionTimer)>();
+                    }
+                    AlertTransition::FadeOut | AlertTransition::FadeOutToward(_) => {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Advances the `AlertRepeatPulse` of alerts, scaling their repeat badge up and back down.
+    pub(crate) fn pulse_repeat_counts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                &AlertParts,
+                &mut AlertRepeatPulse,
+                Option<&AlertTimeScaleOverride>,
+            ),
+            With<AlertManaged<M>>,
+        >,
+        parents: Query<&Parent>,
+        mut transforms: Query<&mut Transform>,
+        time_scale: Res<AlertTimeScale<M>>,
+        time: Res<Time>,
+    ) {
+        for (entity, parts, mut pulse, scale_override) in &mut alerts {
+            pulse
+                .0
+                .tick(time_scale.scale_delta(time.delta(), scale_override));
+            let time = pulse.0.completion();
+            let badge = parts
+                .repeat_count
+                .and_then(|text| parents.get(text).ok())
+                .map(Parent::get);
+            if let Some(mut transform) = badge.and_then(|badge| transforms.get_mut(badge).ok()) {
+                let scale = if time < 1. {
+                    1. + (AlertDefaults::REPEAT_PULSE_SCALE - 1.)
+                        * (std::f32::consts::PI * time).sin()
+                } else {
+                    1.
+                };
+                transform.scale = Vec2::splat(scale).extend(1.);
+            }
+            if time >= 1. {
+                commands.entity(entity).remove::<AlertRepeatPulse>();
+            }
+        }
+    }
+
+    /// Advances the `AlertTextFade` of alerts, then drops the old message once it faded out.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn cross_fade_alert_texts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                &AlertParts,
+                &mut AlertTextFade,
+                Option<&AlertTimeScaleOverride>,
+            ),
+            With<AlertManaged<M>>,
+        >,
+        mut texts: Query<(&mut Text, Option<&TextLayoutInfo>, &mut Style)>,
+        alert_nodes: Res<AlertElements<M>>,
+        time_scale: Res<AlertTimeScale<M>>,
+        time: Res<Time>,
+    ) {
+        let alpha = alert_nodes.text().color.alpha();
+        for (entity, parts, mut fade, scale_override) in &mut alerts {
+            fade.timer
+                .tick(time_scale.scale_delta(time.delta(), scale_override));
+            let time = fade.timer.completion();
+            let progress = easing::sine_in_out(time);
+            if let Ok((mut text, ..)) = texts.get_mut(fade.outgoing) {
+                for section in &mut text.sections {
+                    section.style.color.set_alpha(alpha * (1. - progress));
+                }
+            }
+            if let Ok((mut text, layout, mut style)) = texts.get_mut(parts.text) {
+                for section in &mut text.sections {
+                    section.style.color.set_alpha(alpha * progress);
+                }
+                // the card eases to the height of the new text instead of jumping to it
+                match layout.map(|layout| layout.logical_size.y) {
+                    Some(to) if time < 1. && to > 0. => {
+                        let height = Val::Px(fade.from_height + (to - fade.from_height) * progress);
+                        style.min_height = height;
+                        style.max_height = height;
+                    }
+                    _ => {
+                        style.min_height = Val::Auto;
+                        style.max_height = Val::Auto;
+                    }
+                }
+            }
+            if time >= 1. {
+                commands.entity(fade.outgoing).despawn_recursive();
+                commands.entity(entity).remove::<AlertTextFade>();
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn reveal_typewriter_text(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                &TypewriterText,
+                &AlertParts,
+                &mut AlertTimer,
+                Option<&mut TypewriterReveal>,
+                Option<&AlertTimeScaleOverride>,
+            ),
+            (With<AlertManaged<M>>, With<AlertUi>),
+        >,
+        mut texts: Query<&mut Text>,
+        reduced_motion: Res<ReducedMotion<M>>,
+        time_scale: Res<AlertTimeScale<M>>,
+        time: Res<Time>,
+    ) {
+        for (entity, typewriter, parts, mut timer, reveal, scale_override) in &mut alerts {
+            if reveal.as_ref().is_some_and(|reveal| reveal.done) {
+                continue;
+            }
+            let Ok(mut text) = texts.get_mut(parts.text) else {
+                continue;
+            };
+            let mut reveal = match reveal {
+                Some(reveal) => reveal,
+                None => {
+                    let message = text
+                        .sections
+                        .iter()
+                        .map(|section| section.value.as_str())
+                        .collect::<String>();
+                    // the reveal starts next frame, with the text hidden until then
+                    TypewriterReveal::render(&mut text, &message, 0);
+                    commands.entity(entity).insert(TypewriterReveal {
+                        message,
+                        elapsed: Stopwatch::new(),
+                        done: false,
+                    });
+                    timer.touch();
+                    continue;
+                }
+            };
+
+            reveal
+                .elapsed
+                .tick(time_scale.scale_delta(time.delta(), scale_override));
+            let completion = if reduced_motion.enabled || typewriter.0.is_zero() {
+                1.
+            } else {
+                reveal.elapsed.elapsed_secs() / typewriter.0.as_secs_f32()
+            };
+            let num_chars = reveal.message.chars().count();
+            let shown = (num_chars as f32 * completion.min(1.)).floor() as usize;
+            TypewriterReveal::render(&mut text, &reveal.message, shown);
+            // the lifetime only starts counting once the whole message is shown
+            timer.touch();
+            reveal.done = shown >= num_chars;
+        }
+    }
+}
+
+/// The most alerts of this kind that may fade out at once.
+///
+/// Further fade-outs wait in line as `AlertFadeQueued` and start as earlier ones finish, so
+/// clearing many alerts ripples through the stack. To clear alerts without any animation,
+/// despawn them directly.
+#[derive(Debug, Resource, Reflect)]
+pub struct MaxConcurrentTransitions<M: TypePath> {
+    pub(crate) max: Option<usize>,
+    #[reflect(ignore)]
+    pub(crate) queue: VecDeque<Entity>,
+    #[reflect(ignore)]
+    pub(crate) active: HashSet<Entity>,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> MaxConcurrentTransitions<M>
+where
+    M: TypePath,
+{
+    pub fn new(max: usize) -> Self {
+        Self {
+            max: Some(max.max(1)),
+            queue: VecDeque::new(),
+            active: HashSet::new(),
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self {
+            max: None,
+            ..Self::new(1)
+        }
+    }
+
+    /// The number of alerts waiting for their turn to fade out.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+/// A timer that tracks the current lifetime
+#[derive(Debug, Component, Reflect)]
+pub struct AlertTimer {
+    pub(crate) time_alive: Stopwatch,
+    pub(crate) fixed_ticks: u32,
+}
+
+impl Default for AlertTimer {
+    fn default() -> Self {
+        Self::with_elapsed(Duration::ZERO)
+    }
+}
+
+impl AlertTimer {
+    /// A timer that starts `elapsed` into the alert's lifetime.
+    pub fn with_elapsed(elapsed: Duration) -> Self {
+        let mut time_alive = Stopwatch::new();
+        time_alive.set_elapsed(elapsed);
+        Self {
+            time_alive,
+            fixed_ticks: 0,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.time_alive.elapsed()
+    }
+
+    /// The fixed ticks counted so far when the alert's `AlertClock` is `FixedTicks`.
+    pub fn fixed_ticks(&self) -> u32 {
+        self.fixed_ticks
+    }
+
+    /// Restarts the alert's lifetime without replaying its entrance, so a system can keep a
+    /// relevant alert alive by touching it every frame.
+    pub fn touch(&mut self) {
+        self.time_alive.reset();
+        self.fixed_ticks = 0;
+    }
+}
+
+/// What an alert's lifetime is measured in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum AlertClock {
+    /// Expire after `AlertLifetime`, measured in frame time.
+    #[default]
+    Time,
+    /// Expire after this many `FixedUpdate` steps, ignoring `AlertLifetime`. Useful when alerts
+    /// track fixed-step gameplay. Transitions still run on frame time.
+    FixedTicks(u32),
+}
+
+/// Selects the `AlertClock` used by alerts with marker `M`.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertTiming<M: TypePath> {
+    pub clock: AlertClock,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertTiming<M>
+where
+    M: TypePath,
+{
+    pub fn new(clock: AlertClock) -> Self {
+        Self {
+            clock,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M> Default for AlertTiming<M>
+where
+    M: TypePath,
+{
+    fn default() -> Self {
+        Self::new(AlertClock::Time)
+    }
+}
+
+/// Multiplies the time that passes for alerts of this kind, on top of `Time<Virtual>`'s own
+/// relative speed.
+///
+/// Lifetimes and transitions both accumulate scaled time, so changing the scale mid-life only
+/// changes the pace from then on. To keep alerts at their usual pace during slow motion, set this
+/// to the inverse of the virtual clock's speed.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertTimeScale<M: TypePath> {
+    pub scale: f32,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertTimeScale<M>
+where
+    M: TypePath,
+{
+    pub fn new(scale: f32) -> Self {
+        Self {
+            scale,
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub(crate) fn scale_delta(
+        &self,
+        delta: Duration,
+        scale_override: Option<&AlertTimeScaleOverride>,
+    ) -> Duration {
+        let scale = self.scale * scale_override.map_or(1., |scale_override| scale_override.0);
+        delta.mul_f32(scale.max(0.))
+    }
+}
+
+/// A time scale for a single alert, multiplied with its kind's `AlertTimeScale`.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct AlertTimeScaleOverride(pub f32);
+
+/// Reveals an alert's message one character at a time over the given duration once it is shown.
+///
+/// The hidden rest of the message is laid out transparently from the start, so the card keeps its
+/// final size throughout. The alert's lifetime starts once the whole message is revealed.
+#[derive(Clone, Copy, Debug, Component, Reflect)]
+pub struct TypewriterText(pub Duration);
+
+#[derive(Component)]
+pub(crate) struct TypewriterReveal {
+    pub(crate) message: String,
+    elapsed: Stopwatch,
+    pub(crate) done: bool,
+}
+
+impl TypewriterReveal {
+    fn render(text: &mut Text, message: &str, shown: usize) {
+        let style = text
+            .sections
+            .first()
+            .map(|section| section.style.clone())
+            .unwrap_or_default();
+        let split = message
+            .char_indices()
+            .nth(shown)
+            .map_or(message.len(), |(index, _)| index);
+        let (revealed, hidden) = message.split_at(split);
+        text.sections = vec![TextSection::new(revealed, style.clone())];
+        if !hidden.is_empty() {
+            text.sections.push(TextSection::new(
+                hidden,
+                TextStyle {
+                    color: Color::NONE,
+                    ..style
+                },
+            ));
+        }
+    }
+}
+
+/// Whether alerts of this kind skip their animations.
+///
+/// When enabled, alerts appear and leave without sliding or scaling, and `TypewriterText` shows
+/// the whole message at once. Disabled by default.
+#[derive(Debug, Resource, Reflect)]
+pub struct ReducedMotion<M: TypePath> {
+    pub enabled: bool,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> ReducedMotion<M>
+where
+    M: TypePath,
+{
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// Whether a shown alert whose message changes, such as a keyed "Downloading… 40%" that is
+/// refreshed, cross-fades from the old message to the new one. Disabled by default.
+///
+/// The old text fades out over the new one as it fades in, while the card eases to the height of
+/// the new text. `ReducedMotion` swaps the text at once.
+#[derive(Debug, Resource, Reflect)]
+pub struct AlertCrossFade<M: TypePath> {
+    pub duration: Option<Duration>,
+    #[reflect(ignore)]
+    pub(crate) marker: PhantomData<M>,
+}
+
+impl<M> AlertCrossFade<M>
+where
+    M: TypePath,
+{
+    /// Cross-fades over `duration`, e.g. `AlertDefaults::CROSS_FADE`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration: Some(duration),
+            marker: PhantomData::<M>,
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            duration: None,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+/// A cross-fade in progress from the `AlertFadingText` of an alert to its new message.
+#[derive(Component)]
+pub(crate) struct AlertTextFade {
+    pub(crate) timer: TransitionTimer,
+    pub(crate) outgoing: Entity,
+    pub(crate) from_height: f32,
+}
+
+/// A flag that determines how the Alert transitions in and out of the UI.
+#[derive(Clone, Debug, Component, Reflect)]
+pub enum AlertTransition {
+    FadeIn,
+    FadeOut,
+    /// Shrinks and fades the card toward this point, relative to its center. Used when the user
+    /// dismisses the alert, so it reads as closed rather than as having left on its own.
+    FadeOutToward(Vec2),
+}
+
+impl AlertTransition {
+    /// Whether the card is on its way out of the UI.
+    pub fn is_leaving(&self) -> bool {
+        matches!(
+            self,
+            AlertTransition::FadeOut | AlertTransition::FadeOutToward(_)
+        )
+    }
+
+    /// The card's slide offset, as a fraction of its width, at this completion of the transition.
+    pub(crate) fn offset(&self, completion: f32) -> f32 {
+        easing::sine_in_out(match self {
+            AlertTransition::FadeIn => 1. - completion,
+            AlertTransition::FadeOut => completion,
+            AlertTransition::FadeOutToward(_) => 0.,
+        })
+    }
+}
+
+/// Marks an alert waiting for its turn to fade out under `MaxConcurrentTransitions`.
+///
+/// The alert stays visible, but its buttons no longer respond.
+#[derive(Debug, Component, Reflect)]
+pub struct AlertFadeQueued;
+
+/// A timer for AlertTransitions.
+#[derive(Clone, Debug, Component, Reflect)]
+pub struct TransitionTimer {
+    pub(crate) time_alive: Stopwatch,
+    pub(crate) duration: Duration,
+}
+
+impl TransitionTimer {
+    pub const DURATION: Duration = AlertDefaults::TRANSITION;
+
+    /// Builds a timer that completes after `duration` instead of `DURATION`.
+    pub fn with_duration(duration: Duration) -> Self {
+        Self {
+            time_alive: Stopwatch::new(),
+            duration,
+        }
+    }
+
+    /// How far through the transition this timer is, from 0 to 1.
+    pub fn completion(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.;
+        }
+        (self.time_alive.elapsed().as_secs_f32() / self.duration.as_secs_f32()).clamp(0., 1.)
+    }
+
+    pub(crate) fn tick(&mut self, delta: Duration) {
+        self.time_alive.tick(delta);
+    }
+}
+
+impl Default for TransitionTimer {
+    fn default() -> Self {
+        Self::with_duration(Self::DURATION)
+    }
+}
+
+/// The easing curves used by the built-in transitions, for custom animations that should match
+/// them. Inputs are clamped to `0..=1`.
+pub mod easing {
+    use std::f32::consts::PI;
+
+    /// Eases in and out along a half cosine wave. This drives the card slide.
+    pub fn sine_in_out(t: f32) -> f32 {
+        0.5 * (1. - (PI * t.clamp(0., 1.)).cos())
+    }
+
+    pub fn linear(t: f32) -> f32 {
+        t.clamp(0., 1.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_entrance_emphasis() {
+        let mut app = manual_app();
+        app.insert_resource(AlertEmphasis::<AlertMarker>::new(1.06));
+        let alert = app
+            .world_mut()
+            .spawn((Alert::bundle("Emphasized"), AlertMarker))
+            .id();
+        let scale = |app: &App| app.world().get::<Transform>(alert).unwrap().scale.x;
+        // t: 0s, the alert is spawned at the emphasized scale
+        app.update();
+        assert!((scale(&app) - 1.06).abs() < 1e-4);
+        // t: 0.25s, halfway through the entrance
+        app.update();
+        assert!((scale(&app) - 1.03).abs() < 1e-4);
+        // t: 0.5s, the entrance is complete
+        app.update();
+        assert!((scale(&app) - 1.).abs() < 1e-4);
+        assert!(app.world().get::<AlertTransition>(alert).is_none());
+    }
+
+    #[test]
+    fn test_easing_curves() {
+        let pin = |curve: fn(f32) -> f32, expected: [f32; 5]| {
+            for (t, expected) in [0., 0.25, 0.5, 0.75, 1.].into_iter().zip(expected) {
+                assert!((curve(t) - expected).abs() < 1e-6, "{t}: {}", curve(t));
+            }
+        };
+        pin(easing::sine_in_out, [0., 0.146_446_6, 0.5, 0.853_553_4, 1.]);
+        pin(easing::linear, [0., 0.25, 0.5, 0.75, 1.]);
+        assert_eq!(easing::sine_in_out(-1.), 0.);
+        assert_eq!(easing::sine_in_out(2.), 1.);
+
+        let mut timer = TransitionTimer::with_duration(Duration::from_secs(2));
+        timer.tick(Duration::from_millis(500));
+        assert_eq!(timer.completion(), 0.25);
+        timer.tick(Duration::from_secs(5));
+        assert_eq!(timer.completion(), 1.);
+        assert_eq!(
+            TransitionTimer::with_duration(Duration::ZERO).completion(),
+            1.
+        );
+    }
+
+    #[test]
+    fn test_max_concurrent_transitions() {
+        let mut app = manual_app();
+        app.insert_resource(MaxAlerts::<AlertMarker>::new(10));
+        app.insert_resource(MaxConcurrentTransitions::<AlertMarker>::new(3));
+        let alerts = (0..10)
+            .map(|index| {
+                app.world_mut()
+                    .spawn((Alert::bundle(format!("alert {index}")), AlertMarker))
+                    .id()
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..4 {
+            app.update();
+        }
+        for alert in &alerts {
+            app.world_mut()
+                .entity_mut(*alert)
+                .insert((AlertTransition::FadeOut, AlertDismissReason::Dismissed));
+        }
+
+        let mut max_fading = 0;
+        for _ in 0..40 {
+            app.update();
+            let mut transitions = app.world_mut().query::<&AlertTransition>();
+            let fading = transitions
+                .iter(app.world())
+                .filter(|transition| matches!(transition, AlertTransition::FadeOut))
+                .count();
+            assert!(fading <= 3);
+            max_fading = max_fading.max(fading);
+        }
+        assert_eq!(max_fading, 3);
+        assert!(alerts
+            .iter()
+            .all(|alert| app.world().get_entity(*alert).is_none()));
+    }
+
+    #[test]
+    fn test_typewriter_text() {
+        fn shown(app: &App, alert: Entity) -> usize {
+            let text = app.world().get::<AlertParts>(alert).unwrap().text();
+            let text = app.world().get::<Text>(text).unwrap();
+            assert_eq!(
+                text.sections
+                    .iter()
+                    .map(|section| section.value.as_str())
+                    .collect::<String>(),
+                "abcdefgh"
+            );
+            text.sections[0].value.chars().count()
+        }
+
+        let mut app = manual_app();
+        app.insert_resource(AlertLifetime::<AlertMarker>::new(Duration::from_secs(1)));
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("abcdefgh"),
+                TypewriterText(Duration::from_secs(1)),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        assert_eq!(shown(&app, alert), 0);
+        app.update();
+        assert_eq!(shown(&app, alert), 2);
+        app.update();
+        assert_eq!(shown(&app, alert), 4);
+        app.update();
+        app.update();
+        assert_eq!(shown(&app, alert), 8);
+
+        // the full lifetime is still ahead once the reveal completes
+        for _ in 0..4 {
+            app.update();
+            assert!(app.world().get::<AlertDismissReason>(alert).is_none());
+        }
+        app.update();
+        assert_eq!(
+            app.world().get::<AlertDismissReason>(alert),
+            Some(&AlertDismissReason::Expired)
+        );
+
+        app.insert_resource(ReducedMotion::<AlertMarker>::new(true));
+        let alert = app
+            .world_mut()
+            .spawn((
+                Alert::bundle("abcdefgh"),
+                TypewriterText(Duration::from_secs(1)),
+                AlertMarker,
+            ))
+            .id();
+        app.update();
+        app.update();
+        assert_eq!(shown(&app, alert), 8);
+    }
+
+    #[test]
+    fn test_repeat_pulse() {
+        for reduced_motion in [false, true] {
+            let mut app = manual_app();
+            app.insert_resource(DedupAlerts::<AlertMarker>::new(true))
+                .insert_resource(ReducedMotion::<AlertMarker>::new(reduced_motion));
+            let alert = app
+                .world_mut()
+                .spawn((Alert::bundle("disk full"), AlertMarker))
+                .id();
+            app.update();
+            app.update();
+
+            app.world_mut()
+                .spawn((Alert::bundle("disk full"), AlertMarker));
+            app.update();
+            let parts = *app.world().get::<AlertParts>(alert).unwrap();
+            let text = parts.repeat_count().unwrap();
+            assert_eq!(
+                app.world().get::<Text>(text).unwrap().sections[0].value,
+                "x2"
+            );
+            let badge = app.world().get::<Parent>(text).unwrap().get();
+            let scale = |app: &App| app.world().get::<Transform>(badge).unwrap().scale.x;
+            let pulsing = |app: &App| app.world().entity(alert).contains::<AlertRepeatPulse>();
+            assert_eq!(pulsing(&app), !reduced_motion);
+            assert_eq!(scale(&app) > 1., !reduced_motion);
+
+            // the pulse clears, and a further repeat pulses again
+            app.update();
+            assert!(!pulsing(&app));
+            assert_eq!(scale(&app), 1.);
+            let elapsed = app.world().get::<AlertTimer>(alert).unwrap().elapsed();
+            assert!(elapsed > Duration::ZERO);
+
+            app.world_mut()
+                .spawn((Alert::bundle("disk full"), AlertMarker));
+            app.update();
+            assert_eq!(
+                app.world().get::<Text>(text).unwrap().sections[0].value,
+                "x3"
+            );
+            assert_eq!(pulsing(&app), !reduced_motion);
+        }
+    }
+
+    #[test]
+    fn test_cross_fade() {
+        for reduced_motion in [false, true] {
+            let mut app = manual_app();
+            app.insert_resource(AlertCrossFade::<AlertMarker>::new(Duration::from_millis(
+                400,
+            )))
+            .insert_resource(ReducedMotion::<AlertMarker>::new(reduced_motion));
+            let alert = app
+                .world_mut()
+                .spawn((
+                    Alert::bundle("Downloading… 40%"),
+                    AlertKey::new("download"),
+                    AlertMarker,
+                ))
+                .id();
+            app.update();
+            app.update();
+            let messages = |app: &mut App| {
+                let mut texts = app.world_mut().query::<&Text>();
+                let mut messages = texts
+                    .iter(app.world())
+                    .filter_map(|text| text.sections.first())
+                    .map(|section| section.value.clone())
+                    .filter(|message| message.starts_with("Downloading"))
+                    .collect::<Vec<_>>();
+                messages.sort();
+                messages
+            };
+            assert_eq!(messages(&mut app), ["Downloading… 40%"]);
+
+            app.world_mut().spawn((
+                Alert::bundle("Downloading… 60%"),
+                AlertKey::new("download"),
+                AlertMarker,
+            ));
+            app.update();
+            if reduced_motion {
+                assert_eq!(messages(&mut app), ["Downloading… 60%"]);
+                assert!(app.world().get::<AlertTextFade>(alert).is_none());
+                continue;
+            }
+            assert_eq!(messages(&mut app), ["Downloading… 40%", "Downloading… 60%"]);
+            let fade = app.world().get::<AlertTextFade>(alert).unwrap();
+            let outgoing = fade.outgoing;
+            let text = app.world().get::<AlertParts>(alert).unwrap().text();
+            let alpha = |app: &App, entity: Entity| {
+                app.world().get::<Text>(entity).unwrap().sections[0]
+                    .style
+                    .color
+                    .alpha()
+            };
+            assert!(alpha(&app, outgoing) < alpha(&app, text));
+            assert_eq!(
+                app.world().get::<Parent>(outgoing).map(Parent::get),
+                app.world().get::<Parent>(text).map(Parent::get)
+            );
+
+            app.update();
+            assert_eq!(messages(&mut app), ["Downloading… 60%"]);
+            assert!(app.world().get::<AlertTextFade>(alert).is_none());
+            assert_eq!(
+                alpha(&app, text),
+                app.world()
+                    .resource::<AlertElements<AlertMarker>>()
+                    .text()
+                    .color
+                    .alpha()
+            );
+        }
+    }
+}